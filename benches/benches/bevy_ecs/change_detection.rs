@@ -3,8 +3,10 @@ use core::hint::black_box;
 use bevy_ecs::{
     component::{Component, Mutable},
     entity::Entity,
-    prelude::{Added, Changed, EntityWorldMut, QueryState},
+    prelude::{Added, Changed, EntityWorldMut, IntoScheduleConfigs, QueryState},
     query::QueryFilter,
+    schedule::Schedule,
+    system::Query,
     world::World,
 };
 use criterion::{criterion_group, Criterion};
@@ -17,7 +19,9 @@ criterion_group!(
     all_changed_detection,
     few_changed_detection,
     none_changed_detection,
-    multiple_archetype_none_changed_detection
+    multiple_archetype_none_changed_detection,
+    multiple_archetype_one_changed_detection,
+    multiple_archetype_unused_mut_access_detection
 );
 
 macro_rules! modify {
@@ -376,3 +380,128 @@ fn multiple_archetype_none_changed_detection(criterion: &mut Criterion) {
         }
     }
 }
+
+fn multiple_archetype_one_changed_detection_generic<
+    T: Component<Mutability = Mutable> + Default + BenchModify,
+>(
+    group: &mut BenchGroup,
+    archetype_count: u16,
+    entity_count: u32,
+) {
+    group.bench_function(
+        format!(
+            "{}_archetypes_{}_entities_{}",
+            archetype_count,
+            entity_count,
+            core::any::type_name::<T>()
+        ),
+        |bencher| {
+            bencher.iter_batched_ref(
+                || {
+                    let mut world = World::new();
+                    add_archetypes_entities::<T>(&mut world, archetype_count, entity_count);
+                    world.clear_trackers();
+                    // change T on a single entity, leaving every other table unchanged
+                    let mut query = world.query::<&mut T>();
+                    let mut component = query.iter_mut(&mut world).next().unwrap();
+                    black_box(component.bench_modify());
+                    let query = generic_filter_query::<Changed<T>>(&mut world);
+                    (world, query)
+                },
+                |(world, query)| {
+                    let mut count = 0;
+                    for entity in query.iter(world) {
+                        black_box(entity);
+                        count += 1;
+                    }
+                    assert_eq!(1, count);
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        },
+    );
+}
+
+fn multiple_archetype_one_changed_detection(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("multiple_archetypes_one_changed_detection");
+    group.warm_up_time(core::time::Duration::from_millis(800));
+    group.measurement_time(core::time::Duration::from_secs(8));
+    for archetype_count in [5, 20, 100] {
+        for entity_count in [10, 100, 1000, 10000] {
+            multiple_archetype_one_changed_detection_generic::<Table>(
+                &mut group,
+                archetype_count,
+                entity_count,
+            );
+            multiple_archetype_one_changed_detection_generic::<Sparse>(
+                &mut group,
+                archetype_count,
+                entity_count,
+            );
+        }
+    }
+}
+
+/// Runs a schedule where a system accesses `T` without changing it, before a system looking for
+/// changes of `T`. Granting mutable access to `T` marks every table as changed, so the change-filtered
+/// query can't skip any table, unlike with read-only access.
+fn multiple_archetype_unused_mut_access_detection_generic<const MUTABLE: bool>(
+    group: &mut BenchGroup,
+    archetype_count: u16,
+    entity_count: u32,
+) {
+    group.bench_function(
+        format!(
+            "{}_archetypes_{}_entities_{}",
+            archetype_count,
+            entity_count,
+            if MUTABLE { "mut" } else { "ref" }
+        ),
+        |bencher| {
+            let mut world = World::new();
+            add_archetypes_entities::<Table>(&mut world, archetype_count, entity_count);
+            let mut schedule = Schedule::default();
+            let read = |query: Query<&Table>| {
+                for component in &query {
+                    black_box(component.0);
+                }
+            };
+            let read_mut = |query: Query<&mut Table>| {
+                for component in &query {
+                    black_box(component.0);
+                }
+            };
+            let changed = |query: Query<Entity, Changed<Table>>| {
+                black_box(query.iter().count());
+            };
+            if MUTABLE {
+                schedule.add_systems((read_mut, changed).chain());
+            } else {
+                schedule.add_systems((read, changed).chain());
+            }
+            // the first run initializes the systems and sees every component as changed
+            schedule.run(&mut world);
+            bencher.iter(|| schedule.run(&mut world));
+        },
+    );
+}
+
+fn multiple_archetype_unused_mut_access_detection(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("multiple_archetypes_unused_mut_access_detection");
+    group.warm_up_time(core::time::Duration::from_millis(800));
+    group.measurement_time(core::time::Duration::from_secs(8));
+    for archetype_count in [5, 20, 100] {
+        for entity_count in [10, 100, 1000, 10000] {
+            multiple_archetype_unused_mut_access_detection_generic::<false>(
+                &mut group,
+                archetype_count,
+                entity_count,
+            );
+            multiple_archetype_unused_mut_access_detection_generic::<true>(
+                &mut group,
+                archetype_count,
+                entity_count,
+            );
+        }
+    }
+}
@@ -9,11 +9,14 @@ use alloc::{
 };
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
-    component::RequiredComponentsError,
+    component::{Immutable, RequiredComponentsError},
     event::{event_update_system, EventCursor},
     intern::Interned,
     prelude::*,
-    schedule::{InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{
+        InternedScheduleLabel, InternedSystemSet, ScheduleBuildError, ScheduleBuildSettings,
+        ScheduleGraphFormat, ScheduleGraphSettings, ScheduleLabel,
+    },
     system::{IntoObserverSystem, ScheduleSystem, SystemId, SystemInput},
 };
 use bevy_platform::collections::HashMap;
@@ -1273,6 +1276,39 @@ impl App {
         self
     }
 
+    /// Exports the graph of every schedule of the main [`SubApp`] in the given `format`, sorted by label.
+    ///
+    /// Schedules are initialized first, so that the exported graphs include the sync points
+    /// inserted when building them. See [`Schedule::export_graph`] for what is drawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schedule fails to build.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::schedule::{ScheduleGraphFormat, ScheduleGraphSettings};
+    /// let mut app = App::new();
+    /// app.add_systems(Update, || {});
+    ///
+    /// for (label, dot) in app
+    ///     .export_schedule_graphs(ScheduleGraphFormat::Dot, &ScheduleGraphSettings::default())
+    ///     .unwrap()
+    /// {
+    ///     // Write `dot` to a file named after `label`, render it with Graphviz...
+    ///     assert!(dot.starts_with("digraph"));
+    /// }
+    /// ```
+    pub fn export_schedule_graphs(
+        &mut self,
+        format: ScheduleGraphFormat,
+        settings: &ScheduleGraphSettings,
+    ) -> Result<Vec<(InternedScheduleLabel, String)>, ScheduleBuildError> {
+        self.main_mut().export_schedule_graphs(format, settings)
+    }
+
     /// Attempts to determine if an [`AppExit`] was raised since the last update.
     ///
     /// Will attempt to return the first [`Error`](AppExit::Error) it encounters.
@@ -1334,6 +1370,34 @@ impl App {
         self.world_mut().add_observer(observer);
         self
     }
+
+    /// Starts maintaining an index of the values of the immutable component `C`, so that entities can be
+    /// looked up by value with a [`QueryByIndex`](bevy_ecs::index::QueryByIndex).
+    ///
+    /// See [`World::add_index`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::index::QueryByIndex;
+    /// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+    /// #[component(immutable)]
+    /// struct Team(u32);
+    ///
+    /// fn count_team(query: QueryByIndex<Team, Entity>) {
+    ///     println!("{} entities in team 3", query.iter(&Team(3)).count());
+    /// }
+    ///
+    /// App::new().add_index::<Team>().add_systems(Update, count_team);
+    /// ```
+    pub fn add_index<C: Component<Mutability = Immutable> + Eq + core::hash::Hash + Clone>(
+        &mut self,
+    ) -> &mut Self {
+        self.world_mut().add_index::<C>();
+        self
+    }
 }
 
 type RunnerFn = Box<dyn FnOnce(App) -> AppExit>;
@@ -1422,6 +1486,7 @@ impl Termination for AppExit {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{format, vec::Vec};
     use core::marker::PhantomData;
     use std::sync::Mutex;
 
@@ -1433,7 +1498,7 @@ mod tests {
         query::With,
         removal_detection::RemovedComponents,
         resource::Resource,
-        schedule::{IntoScheduleConfigs, ScheduleLabel},
+        schedule::{IntoScheduleConfigs, ScheduleGraphFormat, ScheduleLabel},
         system::{Commands, Query},
         world::{FromWorld, World},
     };
@@ -1744,6 +1809,37 @@ mod tests {
             .run();
     }
 
+    #[test]
+    fn export_schedule_graphs_of_all_schedules() {
+        fn spawn(mut commands: Commands) {
+            commands.spawn_empty();
+        }
+        fn read(_query: Query<Entity>) {}
+
+        let mut app = App::new();
+        app.add_systems(Update, (spawn, read).chain());
+
+        let graphs = app
+            .export_schedule_graphs(ScheduleGraphFormat::Mermaid, &Default::default())
+            .unwrap();
+        let labels: Vec<_> = graphs
+            .iter()
+            .map(|(label, _)| format!("{label:?}"))
+            .collect();
+        let mut sorted = labels.clone();
+        sorted.sort();
+        assert_eq!(labels, sorted);
+
+        let update = &graphs
+            .iter()
+            .find(|(label, _)| *label == Update.intern())
+            .unwrap()
+            .1;
+        assert!(update.starts_with("---\ntitle: Update\n---\nflowchart LR\n"));
+        assert!(update.contains("{{\"apply_deferred\"}}"));
+        assert_eq!(update.matches(" --> ").count(), 2);
+    }
+
     #[test]
     fn app_exit_size() {
         // There wont be many of them so the size isn't an issue but
@@ -1,9 +1,12 @@
 use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
-use alloc::{boxed::Box, string::String, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use bevy_ecs::{
     event::EventRegistry,
     prelude::*,
-    schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{
+        InternedScheduleLabel, InternedSystemSet, ScheduleBuildError, ScheduleBuildSettings,
+        ScheduleGraphFormat, ScheduleGraphSettings, ScheduleLabel,
+    },
     system::{ScheduleSystem, SystemId, SystemInput},
 };
 use bevy_platform::collections::{HashMap, HashSet};
@@ -335,6 +338,36 @@ impl SubApp {
         self
     }
 
+    /// See [`App::export_schedule_graphs`].
+    pub fn export_schedule_graphs(
+        &mut self,
+        format: ScheduleGraphFormat,
+        settings: &ScheduleGraphSettings,
+    ) -> Result<Vec<(InternedScheduleLabel, String)>, ScheduleBuildError> {
+        let mut labels: Vec<InternedScheduleLabel> = self
+            .world
+            .resource::<Schedules>()
+            .iter()
+            .map(|(_, schedule)| schedule.label())
+            .collect();
+        labels.sort_by_cached_key(|label| format!("{label:?}"));
+
+        let mut graphs = Vec::with_capacity(labels.len());
+        for label in labels {
+            let graph = self.world.schedule_scope(
+                label,
+                |world, schedule| -> Result<_, ScheduleBuildError> {
+                    schedule.initialize(world)?;
+                    Ok(schedule
+                        .export_graph(format, settings)
+                        .expect("the schedule was just initialized"))
+                },
+            )?;
+            graphs.push((label, graph));
+        }
+        Ok(graphs)
+    }
+
     /// See [`App::add_event`].
     pub fn add_event<T>(&mut self) -> &mut Self
     where
@@ -1,5 +1,8 @@
 use crate::{App, Plugin};
 
+#[cfg(any(feature = "std", not(all(target_arch = "wasm32", feature = "web"))))]
+use crate::Last;
+
 use alloc::string::ToString;
 use bevy_platform::sync::Arc;
 use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
@@ -8,7 +11,7 @@ use log::trace;
 
 cfg_if::cfg_if! {
     if #[cfg(not(all(target_arch = "wasm32", feature = "web")))] {
-        use {crate::Last, bevy_tasks::tick_global_task_pools_on_main_thread};
+        use bevy_tasks::tick_global_task_pools_on_main_thread;
         use bevy_ecs::system::NonSendMarker;
 
         /// A system used to check and advanced our task pools.
@@ -35,6 +38,9 @@ impl Plugin for TaskPoolPlugin {
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         _app.add_systems(Last, tick_global_task_pools);
+
+        #[cfg(feature = "std")]
+        _app.add_systems(Last, bevy_ecs::system::run_async_systems);
     }
 }
 
@@ -1,5 +1,8 @@
 use crate::asset_changed::AssetChanges;
-use crate::{Asset, AssetEvent, AssetHandleProvider, AssetId, AssetServer, Handle, UntypedHandle};
+use crate::{
+    Asset, AssetBudget, AssetEvent, AssetHandleProvider, AssetId, AssetResidency, AssetRetention,
+    AssetServer, AssetSize, Handle, UntypedHandle,
+};
 use alloc::{sync::Arc, vec::Vec};
 use bevy_ecs::{
     prelude::EventWriter,
@@ -292,6 +295,9 @@ pub struct Assets<A: Asset> {
     /// Assets managed by the `Assets` struct with live strong `Handle`s
     /// originating from `get_strong_handle`.
     duplicate_handles: HashMap<AssetId<A>, u16>,
+    /// The sizes of the assets and the unused assets kept loaded, if this collection tracks the sizes of
+    /// its assets.
+    residency: Option<AssetResidency<A>>,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -305,6 +311,7 @@ impl<A: Asset> Default for Assets<A> {
             hash_map: Default::default(),
             queued_events: Default::default(),
             duplicate_handles: Default::default(),
+            residency: None,
         }
     }
 }
@@ -356,6 +363,9 @@ impl<A: Asset> Assets<A> {
     }
 
     pub(crate) fn insert_with_uuid(&mut self, uuid: Uuid, asset: A) -> Option<A> {
+        if let Some(residency) = &mut self.residency {
+            residency.update(uuid.into(), &asset);
+        }
         let result = self.hash_map.insert(uuid, asset);
         if result.is_some() {
             self.queued_events
@@ -371,7 +381,14 @@ impl<A: Asset> Assets<A> {
         index: AssetIndex,
         asset: A,
     ) -> Result<bool, InvalidGenerationError> {
+        let size = self
+            .residency
+            .as_ref()
+            .map(|residency| residency.size_of(&asset));
         let replaced = self.dense_storage.insert(index, asset)?;
+        if let (Some(residency), Some(size)) = (&mut self.residency, size) {
+            residency.set_size(index.into(), size);
+        }
         if replaced {
             self.queued_events
                 .push(AssetEvent::Modified { id: index.into() });
@@ -403,6 +420,10 @@ impl<A: Asset> Assets<A> {
             return None;
         }
         *self.duplicate_handles.entry(id).or_insert(0) += 1;
+        // an unused asset kept loaded is in use again until this handle is dropped
+        if let Some(residency) = &mut self.residency {
+            residency.uncache(id);
+        }
         let index = match id {
             AssetId::Index { index, .. } => index.into(),
             AssetId::Uuid { uuid } => uuid.into(),
@@ -453,6 +474,9 @@ impl<A: Asset> Assets<A> {
     pub fn remove_untracked(&mut self, id: impl Into<AssetId<A>>) -> Option<A> {
         let id: AssetId<A> = id.into();
         self.duplicate_handles.remove(&id);
+        if let Some(residency) = &mut self.residency {
+            residency.remove(id);
+        }
         match id {
             AssetId::Index { index, .. } => self.dense_storage.remove_still_alive(index),
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid),
@@ -476,6 +500,9 @@ impl<A: Asset> Assets<A> {
             AssetId::Index { index, .. } => self.dense_storage.remove_dropped(index).is_some(),
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid).is_some(),
         };
+        if let Some(residency) = &mut self.residency {
+            residency.remove(id);
+        }
 
         self.queued_events.push(AssetEvent::Unused { id });
         if existed {
@@ -483,6 +510,73 @@ impl<A: Asset> Assets<A> {
         }
     }
 
+    /// Sets the [`AssetBudget`] of this collection, estimating the size of its assets with [`AssetSize`].
+    ///
+    /// The budget is enforced by the [`track_assets`](Self::track_assets) system, which evicts the
+    /// unused assets kept loaded by [`AssetRetention::Cache`] until the budget is met.
+    pub fn set_budget(&mut self, budget: AssetBudget)
+    where
+        A: AssetSize,
+    {
+        self.track_sizes();
+        if let Some(residency) = &mut self.residency {
+            residency.budget = Some(budget);
+        }
+    }
+
+    /// Returns the [`AssetBudget`] of this collection, if it has one.
+    pub fn budget(&self) -> Option<AssetBudget> {
+        self.residency
+            .as_ref()
+            .and_then(|residency| residency.budget)
+    }
+
+    /// Starts estimating the size of the assets in this collection with [`AssetSize`], so that it is
+    /// reported by [`resident_bytes`](Self::resident_bytes) even without an [`AssetBudget`].
+    ///
+    /// Setting a budget with [`set_budget`](Self::set_budget) also tracks sizes.
+    pub fn track_sizes(&mut self)
+    where
+        A: AssetSize,
+    {
+        if self.residency.is_some() {
+            return;
+        }
+        let mut residency = AssetResidency::new();
+        for (id, asset) in self.iter() {
+            residency.update(id, asset);
+        }
+        self.residency = Some(residency);
+    }
+
+    /// Returns `true` if the sizes of the assets in this collection are estimated, see
+    /// [`track_sizes`](Self::track_sizes).
+    pub fn tracks_sizes(&self) -> bool {
+        self.residency.is_some()
+    }
+
+    /// Returns the estimated number of bytes used by the assets in this collection, if it
+    /// [tracks their sizes](Self::track_sizes).
+    ///
+    /// This includes the unused assets kept loaded by [`AssetRetention::Cache`].
+    pub fn resident_bytes(&self) -> Option<usize> {
+        self.residency.as_ref().map(AssetResidency::resident_bytes)
+    }
+
+    /// Returns the estimated number of bytes used by the unused assets kept loaded by [`AssetRetention::Cache`].
+    pub fn cached_bytes(&self) -> usize {
+        self.residency.as_ref().map_or(0, |residency| {
+            residency.cached().filter_map(|id| residency.size(id)).sum()
+        })
+    }
+
+    /// Returns the number of unused assets kept loaded by [`AssetRetention::Cache`].
+    pub fn cached_len(&self) -> usize {
+        self.residency
+            .as_ref()
+            .map_or(0, |residency| residency.cached().count())
+    }
+
     /// Returns `true` if there are no assets in this collection.
     pub fn is_empty(&self) -> bool {
         self.dense_storage.is_empty() && self.hash_map.is_empty()
@@ -537,6 +631,22 @@ impl<A: Asset> Assets<A> {
         }
     }
 
+    /// Keeps the unused asset with the given `id` loaded, unless handles returned by [`Self::get_strong_handle`]
+    /// are still alive.
+    fn cache_if_unused(&mut self, id: AssetId<A>) {
+        if self
+            .duplicate_handles
+            .get(&id)
+            .is_some_and(|count| *count > 0)
+        {
+            return;
+        }
+        self.duplicate_handles.remove(&id);
+        if let Some(residency) = &mut self.residency {
+            residency.cache(id);
+        }
+    }
+
     /// A system that synchronizes the state of assets in this collection with the [`AssetServer`]. This manages
     /// [`Handle`] drop events.
     pub fn track_assets(mut assets: ResMut<Self>, asset_server: Res<AssetServer>) {
@@ -546,21 +656,63 @@ impl<A: Asset> Assets<A> {
         // re-loads are kicked off appropriately. This function must be "transactional" relative
         // to other asset info operations
         let mut infos = asset_server.data.infos.write();
+        let retention = assets.budget().map(|budget| budget.retention);
         while let Ok(drop_event) = assets.handle_provider.drop_receiver.try_recv() {
             let id = drop_event.id.typed();
 
             if drop_event.asset_server_managed {
                 let untyped_id = id.untyped();
 
+                // unused assets are kept loaded until the budget is exceeded, as long as they can be
+                // found again by path
+                if retention == Some(AssetRetention::Cache)
+                    && assets.contains(id)
+                    && infos.retain_dropped_handle(untyped_id)
+                {
+                    assets.cache_if_unused(id);
+                    continue;
+                }
+
                 // the process_handle_drop call checks whether new handles have been created since the drop event was fired, before removing the asset
                 if !infos.process_handle_drop(untyped_id) {
                     // a new handle has been created, or the asset doesn't exist
                     continue;
                 }
+            } else if retention == Some(AssetRetention::Cache) && infos.is_retained(id.untyped()) {
+                // a handle returned by `get_strong_handle` for an asset whose other handles were dropped
+                if let Some(count) = assets.duplicate_handles.get_mut(&id) {
+                    *count = count.saturating_sub(1);
+                }
+                assets.cache_if_unused(id);
+                continue;
             }
 
             assets.remove_dropped(id);
         }
+
+        let Some(residency) = &assets.residency else {
+            return;
+        };
+        let unretained = residency
+            .cached()
+            .filter(|id| !infos.is_retained(id.untyped()))
+            .collect::<Vec<_>>();
+        for id in unretained {
+            if infos.contains_key(id.untyped()) {
+                // cached assets that were loaded again are in use
+                if let Some(residency) = &mut assets.residency {
+                    residency.uncache(id);
+                }
+            } else {
+                // the asset server forgot the asset, so it can't be used again
+                assets.remove_dropped(id);
+            }
+        }
+        while let Some(id) = assets.residency.as_mut().and_then(AssetResidency::evict) {
+            if infos.process_handle_drop(id.untyped()) {
+                assets.remove_dropped(id);
+            }
+        }
     }
 
     /// A system that applies accumulated asset change events to the [`Events`] resource.
@@ -574,6 +726,18 @@ impl<A: Asset> Assets<A> {
     ) {
         use AssetEvent::{Added, LoadedWithDependencies, Modified, Removed};
 
+        // mutable access to an asset may have changed its size
+        if let Some(mut residency) = assets.residency.take() {
+            for event in &assets.queued_events {
+                if let Modified { id } = event {
+                    if let Some(asset) = assets.get(*id) {
+                        residency.update(*id, asset);
+                    }
+                }
+            }
+            assets.residency = Some(residency);
+        }
+
         if let Some(mut asset_changes) = asset_changes {
             for new_event in &assets.queued_events {
                 match new_event {
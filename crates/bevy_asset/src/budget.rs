@@ -0,0 +1,165 @@
+use crate::{Asset, AssetId};
+use alloc::collections::BTreeMap;
+use bevy_platform::collections::HashMap;
+
+/// Estimates the memory used by an [`Asset`], so that it can be given an [`AssetBudget`].
+///
+/// ```
+/// # use bevy_asset::{Asset, AssetSize};
+/// # use bevy_reflect::TypePath;
+/// #[derive(Asset, TypePath)]
+/// struct Heightmap {
+///     heights: Vec<f32>,
+/// }
+///
+/// impl AssetSize for Heightmap {
+///     fn estimated_size(&self) -> usize {
+///         size_of::<Self>() + self.heights.capacity() * size_of::<f32>()
+///     }
+/// }
+/// ```
+pub trait AssetSize {
+    /// Returns an estimate of the number of bytes used by this asset, including its heap allocations.
+    fn estimated_size(&self) -> usize;
+}
+
+/// Limits the memory used by the assets of a type, set with [`Assets::set_budget`](crate::Assets::set_budget)
+/// or [`AssetApp::set_asset_budget`](crate::AssetApp::set_asset_budget).
+///
+/// The size of each asset is estimated with [`AssetSize`], and the total number of bytes resident in
+/// the [`Assets`](crate::Assets) collection is reported by [`Assets::resident_bytes`](crate::Assets::resident_bytes).
+/// Assets still referenced by a strong [`Handle`](crate::Handle) are never evicted, so the budget can only be
+/// enforced by evicting unused assets kept loaded with [`AssetRetention::Cache`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetBudget {
+    /// The number of bytes the assets of this type should fit in.
+    pub max_bytes: usize,
+    /// What happens to the assets of this type once they are unused.
+    pub retention: AssetRetention,
+}
+
+impl AssetBudget {
+    /// Creates a budget of `max_bytes`, which removes assets as soon as they are unused.
+    pub const fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            retention: AssetRetention::Release,
+        }
+    }
+
+    /// Sets what happens to the assets of this type once they are unused.
+    pub const fn with_retention(mut self, retention: AssetRetention) -> Self {
+        self.retention = retention;
+        self
+    }
+}
+
+/// What happens to an asset once its last strong [`Handle`](crate::Handle) is dropped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AssetRetention {
+    /// The asset is removed, as if it had no [`AssetBudget`].
+    #[default]
+    Release,
+    /// If the asset was loaded by the [`AssetServer`](crate::AssetServer), it stays loaded, and loading
+    /// its path again returns a handle to it without reloading it.
+    ///
+    /// Unused assets are evicted when the [`AssetBudget`] is exceeded, least recently used first.
+    /// [`AssetEvent::Unused`](crate::AssetEvent::Unused) is only sent when an asset is evicted.
+    Cache,
+}
+
+/// The memory accounting of an [`Assets`](crate::Assets) collection that tracks the sizes of its
+/// assets, and its [`AssetBudget`] if it has one.
+pub(crate) struct AssetResidency<A: Asset> {
+    pub(crate) budget: Option<AssetBudget>,
+    size_of: fn(&A) -> usize,
+    sizes: HashMap<AssetId<A>, usize>,
+    resident_bytes: usize,
+    /// The unused assets kept loaded, by the order in which they became unused.
+    cached: BTreeMap<u64, AssetId<A>>,
+    cached_order: HashMap<AssetId<A>, u64>,
+    next_order: u64,
+}
+
+impl<A: Asset> AssetResidency<A> {
+    pub(crate) fn new() -> Self
+    where
+        A: AssetSize,
+    {
+        Self {
+            budget: None,
+            size_of: A::estimated_size,
+            sizes: HashMap::default(),
+            resident_bytes: 0,
+            cached: BTreeMap::new(),
+            cached_order: HashMap::default(),
+            next_order: 0,
+        }
+    }
+
+    pub(crate) fn resident_bytes(&self) -> usize {
+        self.resident_bytes
+    }
+
+    /// Returns the size of the asset with the given `id`, if it is stored.
+    pub(crate) fn size(&self, id: AssetId<A>) -> Option<usize> {
+        self.sizes.get(&id).copied()
+    }
+
+    /// Estimates the size of `asset`.
+    pub(crate) fn size_of(&self, asset: &A) -> usize {
+        (self.size_of)(asset)
+    }
+
+    /// Records the size of the asset stored for `id`.
+    pub(crate) fn update(&mut self, id: AssetId<A>, asset: &A) {
+        self.set_size(id, self.size_of(asset));
+    }
+
+    /// Records `size` as the size of the asset stored for `id`.
+    pub(crate) fn set_size(&mut self, id: AssetId<A>, size: usize) {
+        let previous = self.sizes.insert(id, size).unwrap_or(0);
+        self.resident_bytes = self.resident_bytes - previous + size;
+    }
+
+    /// Forgets the asset stored for `id`, which was removed.
+    pub(crate) fn remove(&mut self, id: AssetId<A>) {
+        if let Some(size) = self.sizes.remove(&id) {
+            self.resident_bytes -= size;
+        }
+        self.uncache(id);
+    }
+
+    /// Marks the asset stored for `id` as unused, making it the most recently used cached asset.
+    pub(crate) fn cache(&mut self, id: AssetId<A>) {
+        self.uncache(id);
+        self.cached.insert(self.next_order, id);
+        self.cached_order.insert(id, self.next_order);
+        self.next_order += 1;
+    }
+
+    /// Marks the asset stored for `id` as used again.
+    pub(crate) fn uncache(&mut self, id: AssetId<A>) {
+        if let Some(order) = self.cached_order.remove(&id) {
+            self.cached.remove(&order);
+        }
+    }
+
+    /// Removes and returns the least recently used cached asset, if it should be evicted because the budget
+    /// is exceeded or unused assets are no longer cached.
+    pub(crate) fn evict(&mut self) -> Option<AssetId<A>> {
+        if self.budget.is_some_and(|budget| {
+            self.resident_bytes <= budget.max_bytes && budget.retention == AssetRetention::Cache
+        }) {
+            return None;
+        }
+        let (_, id) = self.cached.pop_first()?;
+        self.cached_order.remove(&id);
+        Some(id)
+    }
+
+    /// Returns the unused assets kept loaded, from least to most recently used.
+    pub(crate) fn cached(&self) -> impl Iterator<Item = AssetId<A>> + '_ {
+        self.cached.values().copied()
+    }
+}
@@ -0,0 +1,474 @@
+//! Assets downloaded over HTTP(S), optionally kept in a cache on disk.
+//!
+//! An [`HttpAssetReader`] fetches each asset and its meta file from a base URL, and can back an
+//! [`AssetSource`](crate::io::AssetSource):
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::{AssetSourceBuilder, http::HttpAssetReader}};
+//! let reader = HttpAssetReader::new("https://cdn.example.com/content").with_cache("content_cache");
+//! App::new().register_asset_source("remote", AssetSourceBuilder::http(reader));
+//! // `remote://levels/1.scn.ron` is now fetched from `https://cdn.example.com/content/levels/1.scn.ron`.
+//! ```
+//!
+//! When a cache directory is set, downloaded files are stored there along with their `ETag` and
+//! `Last-Modified` headers. Later requests for the same file are sent with `If-None-Match` and
+//! `If-Modified-Since`, so the server can answer `304 Not Modified` instead of sending it again,
+//! and the cached copy is used if the server cannot be reached at all.
+//!
+//! HTTPS requires the `https` feature.
+
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder, PathStream,
+    Reader, VecReader,
+};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::String, sync::Arc, vec::Vec};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+};
+use tracing::{error, warn};
+use ureq::{http::StatusCode, Agent};
+
+/// The default time after which a request is abandoned.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An [`AssetReader`] fetching assets over HTTP(S) from a base URL, with an optional disk cache.
+///
+/// The asset at `path` is fetched from `{base_url}/{path}`, and its meta from `{base_url}/{path}.meta`.
+/// Reading directories is not supported.
+///
+/// See the [module documentation](self) for details on caching.
+#[derive(Clone)]
+pub struct HttpAssetReader {
+    base_url: Arc<str>,
+    cache: Option<Arc<Path>>,
+    agent: Agent,
+}
+
+impl HttpAssetReader {
+    /// Creates a new [`HttpAssetReader`] fetching assets from `base_url`, without caching them.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        Self {
+            base_url: base_url.into(),
+            cache: None,
+            agent: Self::agent(DEFAULT_TIMEOUT),
+        }
+    }
+
+    /// Caches the downloaded assets in the directory at `path`, which is created if needed.
+    pub fn with_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache = Some(path.into().into());
+        self
+    }
+
+    /// Sets the time after which a request is abandoned, 30 seconds by default.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.agent = Self::agent(timeout);
+        self
+    }
+
+    /// Returns the URL assets are fetched from.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the directory downloaded assets are cached in, if any.
+    pub fn cache_path(&self) -> Option<&Path> {
+        self.cache.as_deref()
+    }
+
+    /// Returns the URL the file at `path` is fetched from.
+    pub fn url(&self, path: &Path) -> String {
+        let mut url = String::from(&*self.base_url);
+        for component in path.components() {
+            if let Component::Normal(segment) = component {
+                url.push('/');
+                encode_segment(&segment.to_string_lossy(), &mut url);
+            }
+        }
+        url
+    }
+
+    fn agent(timeout: Duration) -> Agent {
+        Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(timeout))
+            .build()
+            .into()
+    }
+
+    async fn fetch(&self, path: PathBuf) -> Result<VecReader, AssetReaderError> {
+        let agent = self.agent.clone();
+        let url = self.url(&path);
+        let cache = self.cache.as_deref().map(|dir| CacheEntry::new(dir, &url));
+        let bytes = blocking::unblock(move || fetch_blocking(&agent, &url, &path, cache)).await?;
+        Ok(VecReader::new(bytes))
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for an [`AssetSource`] reading its assets with the given [`HttpAssetReader`].
+    pub fn http(reader: HttpAssetReader) -> Self {
+        AssetSource::build().with_reader(move || Box::new(reader.clone()))
+    }
+}
+
+impl AssetReader for HttpAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.fetch(path.to_owned()).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.fetch(get_meta_path(path)).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn is_directory<'a>(&'a self, _path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(false)
+    }
+}
+
+/// Fetches `url`, revalidating and updating the cached copy of the file if there is one.
+fn fetch_blocking(
+    agent: &Agent,
+    url: &str,
+    path: &Path,
+    cache: Option<CacheEntry>,
+) -> Result<Vec<u8>, AssetReaderError> {
+    let cached = cache.as_ref().and_then(CacheEntry::load);
+
+    let mut request = agent.get(url);
+    if let Some((headers, _)) = &cached {
+        if let Some(etag) = &headers.etag {
+            request = request.header("If-None-Match", etag);
+        }
+        if let Some(last_modified) = &headers.last_modified {
+            request = request.header("If-Modified-Since", last_modified);
+        }
+    }
+
+    let mut response = match request.call() {
+        Ok(response) => response,
+        Err(err) => {
+            return match cached {
+                Some((_, bytes)) => {
+                    warn!("Failed to fetch {url}, using its cached copy: {err}");
+                    Ok(bytes)
+                }
+                None => Err(AssetReaderError::Io(Arc::new(io::Error::other(err)))),
+            };
+        }
+    };
+
+    match (response.status(), cached) {
+        (StatusCode::OK, _) => {
+            let bytes = response
+                .body_mut()
+                .with_config()
+                .limit(u64::MAX)
+                .read_to_vec()
+                .map_err(|err| AssetReaderError::Io(Arc::new(io::Error::other(err))))?;
+            if let Some(cache) = &cache {
+                let header = |name| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(ToOwned::to_owned)
+                };
+                let headers = CachedHeaders {
+                    etag: header("etag"),
+                    last_modified: header("last-modified"),
+                };
+                if let Err(err) = cache.store(&headers, &bytes) {
+                    warn!("Failed to cache {url}: {err}");
+                }
+            }
+            Ok(bytes)
+        }
+        (StatusCode::NOT_MODIFIED, Some((_, bytes))) => Ok(bytes),
+        (StatusCode::NOT_FOUND, _) => Err(AssetReaderError::NotFound(path.to_owned())),
+        (status, _) => Err(AssetReaderError::HttpError(status.as_u16())),
+    }
+}
+
+/// The response headers stored alongside a cached file, used to revalidate it.
+#[derive(Serialize, Deserialize, Default)]
+struct CachedHeaders {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// The location in the cache directory of the cached copy of a URL.
+struct CacheEntry {
+    data: PathBuf,
+    headers: PathBuf,
+}
+
+impl CacheEntry {
+    fn new(dir: &Path, url: &str) -> Self {
+        let name = blake3::hash(url.as_bytes()).to_hex();
+        Self {
+            data: dir.join(name.as_str()),
+            headers: dir.join(format!("{name}.headers")),
+        }
+    }
+
+    fn load(&self) -> Option<(CachedHeaders, Vec<u8>)> {
+        let headers = std::fs::read(&self.headers).ok()?;
+        let headers = ron::de::from_bytes(&headers).ok()?;
+        let bytes = std::fs::read(&self.data).ok()?;
+        Some((headers, bytes))
+    }
+
+    fn store(&self, headers: &CachedHeaders, bytes: &[u8]) -> io::Result<()> {
+        if let Some(dir) = self.data.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Remove the headers first, so an interrupted write never leaves them next to a stale copy.
+        match std::fs::remove_file(&self.headers) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        std::fs::write(&self.data, bytes)?;
+        let headers = ron::ser::to_string(headers).map_err(io::Error::other)?;
+        std::fs::write(&self.headers, headers)
+    }
+}
+
+/// Appends `segment` to `url`, percent-encoding the characters that are not allowed in a path segment.
+fn encode_segment(segment: &str, url: &mut String) {
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{byte:02X}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpAssetReader;
+    use crate::io::{AssetReader, AssetReaderError};
+    use alloc::{borrow::ToOwned, format, string::String, sync::Arc, vec::Vec};
+    use bevy_tasks::block_on;
+    use futures_lite::AsyncReadExt;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{TcpListener, TcpStream},
+        path::Path,
+        sync::Mutex,
+        thread::JoinHandle,
+    };
+
+    /// The `Last-Modified` header served by the [`TestServer`].
+    const LAST_MODIFIED: &str = "Wed, 21 Oct 2015 07:28:00 GMT";
+
+    /// A request received by the [`TestServer`], with its conditional headers.
+    #[derive(Debug, Default, PartialEq)]
+    struct Request {
+        path: String,
+        if_none_match: Option<String>,
+        if_modified_since: Option<String>,
+    }
+
+    impl Request {
+        fn new(path: &str) -> Self {
+            Self {
+                path: path.to_owned(),
+                ..Default::default()
+            }
+        }
+
+        fn revalidating(path: &str) -> Self {
+            Self {
+                path: path.to_owned(),
+                if_none_match: Some("\"v1\"".to_owned()),
+                if_modified_since: Some(LAST_MODIFIED.to_owned()),
+            }
+        }
+    }
+
+    /// A minimal HTTP server, serving `hello` with an `ETag` and a `Last-Modified` date at
+    /// `/hello.txt`, and recording the conditional headers of each request.
+    struct TestServer {
+        url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl TestServer {
+        fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/assets", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let thread_requests = requests.clone();
+            let thread = std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if !Self::respond(stream.unwrap(), &thread_requests) {
+                        break;
+                    }
+                }
+            });
+            Self {
+                url,
+                requests,
+                thread: Some(thread),
+            }
+        }
+
+        /// Answers a request, returning `false` when asked to shut down.
+        fn respond(mut stream: TcpStream, requests: &Mutex<Vec<Request>>) -> bool {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let path = line.split(' ').nth(1).unwrap_or_default().to_owned();
+            let mut request = Request::new(&path);
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                let (name, value) = header.split_once(':').unwrap();
+                if name.eq_ignore_ascii_case("if-none-match") {
+                    request.if_none_match = Some(value.trim().to_owned());
+                } else if name.eq_ignore_ascii_case("if-modified-since") {
+                    request.if_modified_since = Some(value.trim().to_owned());
+                }
+            }
+            if path == "/shutdown" {
+                return false;
+            }
+            let revalidated = request.if_none_match.as_deref() == Some("\"v1\"");
+            requests.lock().unwrap().push(request);
+
+            let (status, body) = match path.as_str() {
+                "/assets/hello.txt" if revalidated => ("304 Not Modified", ""),
+                "/assets/hello.txt" => ("200 OK", "hello"),
+                "/assets/hello.txt.meta" => ("200 OK", "meta"),
+                "/assets/broken.txt" => ("500 Internal Server Error", "oops"),
+                _ => ("404 Not Found", ""),
+            };
+            let response = format!(
+                "HTTP/1.1 {status}\r\nETag: \"v1\"\r\nLast-Modified: {LAST_MODIFIED}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            true
+        }
+
+        fn requests(&self) -> Vec<Request> {
+            core::mem::take(&mut *self.requests.lock().unwrap())
+        }
+
+        fn stop(&mut self) {
+            let address = self.url.trim_start_matches("http://").split('/').next();
+            let mut stream = TcpStream::connect(address.unwrap()).unwrap();
+            stream.write_all(b"GET /shutdown HTTP/1.1\r\n\r\n").unwrap();
+            self.thread.take().unwrap().join().unwrap();
+        }
+    }
+
+    impl Drop for TestServer {
+        fn drop(&mut self) {
+            if self.thread.is_some() {
+                self.stop();
+            }
+        }
+    }
+
+    fn read(reader: &HttpAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            let mut reader = reader.read(Path::new(path)).await?;
+            reader.read_to_end(&mut bytes).await.unwrap();
+            Ok(String::from_utf8(bytes).unwrap())
+        })
+    }
+
+    #[test]
+    fn fetch_and_report_errors() {
+        let server = TestServer::start();
+        let reader = HttpAssetReader::new(format!("{}/", server.url));
+
+        assert_eq!(read(&reader, "hello.txt").unwrap(), "hello");
+        let meta = block_on(async {
+            let mut bytes = Vec::new();
+            let mut meta = reader.read_meta(Path::new("hello.txt")).await.unwrap();
+            meta.read_to_end(&mut bytes).await.unwrap();
+            bytes
+        });
+        assert_eq!(meta, b"meta");
+        assert!(matches!(
+            read(&reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(path)) if path == Path::new("missing.txt")
+        ));
+        assert_eq!(
+            read(&reader, "broken.txt"),
+            Err(AssetReaderError::HttpError(500))
+        );
+        // Without a cache, requests are never conditional.
+        assert_eq!(read(&reader, "hello.txt").unwrap(), "hello");
+        assert!(server.requests().iter().all(|request| {
+            request.if_none_match.is_none() && request.if_modified_since.is_none()
+        }));
+
+        assert_eq!(
+            reader.url(Path::new("a dir/b#1.png")),
+            format!("{}/a%20dir/b%231.png", server.url)
+        );
+    }
+
+    #[test]
+    fn revalidate_cached_assets() {
+        let cache =
+            std::env::temp_dir().join(format!("bevy_asset_http_cache_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache);
+        let mut server = TestServer::start();
+        let reader = HttpAssetReader::new(&server.url).with_cache(&cache);
+
+        assert_eq!(read(&reader, "hello.txt").unwrap(), "hello");
+        assert_eq!(server.requests(), [Request::new("/assets/hello.txt")]);
+
+        // The cached copy is revalidated, and served when the server answers `304 Not Modified`,
+        // including by a new reader using the same cache.
+        let new_reader = HttpAssetReader::new(&server.url).with_cache(&cache);
+        for reader in [&reader, &new_reader] {
+            assert_eq!(read(reader, "hello.txt").unwrap(), "hello");
+        }
+        assert_eq!(
+            server.requests(),
+            [
+                Request::revalidating("/assets/hello.txt"),
+                Request::revalidating("/assets/hello.txt"),
+            ]
+        );
+
+        // The cached copy is used when the server cannot be reached.
+        server.stop();
+        assert_eq!(read(&reader, "hello.txt").unwrap(), "hello");
+        assert!(matches!(
+            read(&reader, "missing.txt"),
+            Err(AssetReaderError::Io(_))
+        ));
+
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+}
@@ -0,0 +1,360 @@
+//! Asset sources made of several layers, where the assets of a layer override those of the layers below it.
+//!
+//! This is typically used to let DLCs and mods replace assets of the base game by providing a file at the same path:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::{AssetSourceBuilder, AssetSourceId}};
+//! App::new().register_asset_source(
+//!     AssetSourceId::Default,
+//!     AssetSourceBuilder::layered([
+//!         AssetSourceBuilder::platform_default("mods/my_mod", None),
+//!         AssetSourceBuilder::platform_default("dlc", None),
+//!         AssetSourceBuilder::platform_default("assets", None),
+//!     ]),
+//! );
+//! ```
+
+use crate::io::{
+    AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder, AssetSourceEvent, AssetWatcher,
+    ErasedAssetReader, ErasedAssetWriter, PathStream, Reader,
+};
+use alloc::{borrow::ToOwned, boxed::Box, sync::Arc, vec::Vec};
+use bevy_platform::collections::HashSet;
+use futures_lite::{stream, StreamExt};
+use parking_lot::Mutex;
+use std::path::Path;
+
+/// An [`AssetReader`] reading from a list of layers, in order of priority.
+///
+/// * Assets and meta files are read from the first layer that contains them.
+///   A meta file is looked up on its own, so a layer overriding an asset whose meta file
+///   does not apply to it should override the meta file too.
+/// * Directory listings contain the paths found in any of the layers.
+/// * A path is a directory if it is one in any of the layers.
+#[derive(Default)]
+pub struct LayeredAssetReader {
+    layers: Vec<Box<dyn ErasedAssetReader>>,
+}
+
+impl LayeredAssetReader {
+    /// Creates a new [`LayeredAssetReader`] with the given `layers`, from highest to lowest priority.
+    pub fn new(layers: Vec<Box<dyn ErasedAssetReader>>) -> Self {
+        Self { layers }
+    }
+
+    /// Adds a layer with a lower priority than the existing ones.
+    pub fn with_layer(mut self, reader: impl AssetReader) -> Self {
+        self.layers.push(Box::new(reader));
+        self
+    }
+
+    /// Returns the layers of this reader, from highest to lowest priority.
+    pub fn layers(&self) -> &[Box<dyn ErasedAssetReader>] {
+        &self.layers
+    }
+}
+
+impl AssetReader for LayeredAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read_meta(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let mut found = false;
+        let mut seen = <HashSet<_>>::default();
+        let mut paths = Vec::new();
+        for layer in &self.layers {
+            match layer.read_directory(path).await {
+                Ok(mut stream) => {
+                    found = true;
+                    while let Some(path) = stream.next().await {
+                        if seen.insert(path.clone()) {
+                            paths.push(path);
+                        }
+                    }
+                }
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        if !found {
+            return Err(AssetReaderError::NotFound(path.to_owned()));
+        }
+        let stream: Box<PathStream> = Box::new(stream::iter(paths));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let mut found = false;
+        for layer in &self.layers {
+            match layer.is_directory(path).await {
+                Ok(true) => return Ok(true),
+                Ok(false) => found = true,
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        if found {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_owned()))
+        }
+    }
+}
+
+/// An [`AssetWatcher`] keeping the watchers of each layer of a layered source alive.
+struct LayeredAssetWatcher {
+    _watchers: Vec<Box<dyn AssetWatcher>>,
+}
+
+impl AssetWatcher for LayeredAssetWatcher {}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for an [`AssetSource`] made of the given `layers`, from highest to lowest priority.
+    ///
+    /// * Its readers are [`LayeredAssetReader`]s over the readers of the layers.
+    ///   The processed reader is only set if a layer has one.
+    /// * Its writers are the writers of the first layer that has one.
+    /// * Its watchers send the [`AssetSourceEvent`]s of every layer that has a watcher.
+    pub fn layered(layers: impl IntoIterator<Item = AssetSourceBuilder>) -> Self {
+        let layers: Vec<_> = layers.into_iter().collect();
+        let watch_warning = layers.iter().find_map(|layer| layer.watch_warning);
+        let processed_watch_warning = layers
+            .iter()
+            .find_map(|layer| layer.processed_watch_warning);
+        let has_processed_reader = layers.iter().any(|layer| layer.processed_reader.is_some());
+        let layers = Arc::new(Mutex::new(layers));
+
+        let mut builder = AssetSource::build()
+            .with_reader({
+                let layers = layers.clone();
+                move || {
+                    let readers = layers
+                        .lock()
+                        .iter_mut()
+                        .filter_map(|layer| layer.reader.as_mut().map(|reader| reader()))
+                        .collect();
+                    Box::new(LayeredAssetReader::new(readers))
+                }
+            })
+            .with_writer({
+                let layers = layers.clone();
+                move |create_root| first_writer(&layers, create_root, |layer| &mut layer.writer)
+            })
+            .with_processed_writer({
+                let layers = layers.clone();
+                move |create_root| {
+                    first_writer(&layers, create_root, |layer| &mut layer.processed_writer)
+                }
+            })
+            .with_watcher({
+                let layers = layers.clone();
+                move |sender| layered_watcher(&layers, sender, |layer| &mut layer.watcher)
+            })
+            .with_processed_watcher({
+                let layers = layers.clone();
+                move |sender| layered_watcher(&layers, sender, |layer| &mut layer.processed_watcher)
+            });
+        if has_processed_reader {
+            builder = builder.with_processed_reader(move || {
+                let readers = layers
+                    .lock()
+                    .iter_mut()
+                    .filter_map(|layer| layer.processed_reader.as_mut().map(|reader| reader()))
+                    .collect();
+                Box::new(LayeredAssetReader::new(readers))
+            });
+        }
+        builder.watch_warning = watch_warning;
+        builder.processed_watch_warning = processed_watch_warning;
+        builder
+    }
+}
+
+type WriterBuilder = Box<dyn FnMut(bool) -> Option<Box<dyn ErasedAssetWriter>> + Send + Sync>;
+
+type WatcherBuilder = Box<
+    dyn FnMut(crossbeam_channel::Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>>
+        + Send
+        + Sync,
+>;
+
+/// Returns the first writer built by one of the `layers`.
+fn first_writer(
+    layers: &Mutex<Vec<AssetSourceBuilder>>,
+    create_root: bool,
+    writer: impl Fn(&mut AssetSourceBuilder) -> &mut Option<WriterBuilder>,
+) -> Option<Box<dyn ErasedAssetWriter>> {
+    layers.lock().iter_mut().find_map(|layer| {
+        writer(layer)
+            .as_mut()
+            .and_then(|writer| writer(create_root))
+    })
+}
+
+/// Returns a watcher made of the watchers built by the `layers`, if any of them built one.
+fn layered_watcher(
+    layers: &Mutex<Vec<AssetSourceBuilder>>,
+    sender: crossbeam_channel::Sender<AssetSourceEvent>,
+    watcher: impl Fn(&mut AssetSourceBuilder) -> &mut Option<WatcherBuilder>,
+) -> Option<Box<dyn AssetWatcher>> {
+    let watchers: Vec<_> = layers
+        .lock()
+        .iter_mut()
+        .filter_map(|layer| {
+            watcher(layer)
+                .as_mut()
+                .and_then(|watcher| watcher(sender.clone()))
+        })
+        .collect();
+    if watchers.is_empty() {
+        None
+    } else {
+        Some(Box::new(LayeredAssetWatcher {
+            _watchers: watchers,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayeredAssetReader;
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetReader, AssetReaderError, AssetSourceBuilder, AssetSourceEvent, AssetSourceId,
+        AssetWatcher,
+    };
+    use alloc::{boxed::Box, string::String, vec, vec::Vec};
+    use bevy_tasks::block_on;
+    use futures_lite::{AsyncReadExt, StreamExt};
+    use std::path::{Path, PathBuf};
+
+    struct TestWatcher;
+
+    impl AssetWatcher for TestWatcher {}
+
+    fn read(reader: &LayeredAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut text = String::new();
+            let mut reader = reader.read(Path::new(path)).await?;
+            reader.read_to_string(&mut text).await.unwrap();
+            Ok(text)
+        })
+    }
+
+    fn memory_reader(assets: &[(&str, &str)]) -> MemoryAssetReader {
+        let root = Dir::default();
+        for (path, text) in assets {
+            root.insert_asset_text(Path::new(path), text);
+        }
+        MemoryAssetReader { root }
+    }
+
+    #[test]
+    fn first_layer_wins() {
+        let reader = LayeredAssetReader::default()
+            .with_layer(memory_reader(&[("a.txt", "mod a"), ("dir/c.txt", "mod c")]))
+            .with_layer(memory_reader(&[
+                ("a.txt", "base a"),
+                ("b.txt", "base b"),
+                ("dir/d.txt", "base d"),
+            ]));
+
+        assert_eq!(read(&reader, "a.txt").unwrap(), "mod a");
+        assert_eq!(read(&reader, "b.txt").unwrap(), "base b");
+        assert!(matches!(
+            read(&reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(path)) if path == Path::new("missing.txt")
+        ));
+
+        block_on(async {
+            let mut paths: Vec<PathBuf> = reader
+                .read_directory(Path::new(""))
+                .await
+                .unwrap()
+                .collect()
+                .await;
+            paths.sort();
+            assert_eq!(paths, ["a.txt", "b.txt", "dir"].map(PathBuf::from));
+
+            let mut paths: Vec<PathBuf> = reader
+                .read_directory(Path::new("dir"))
+                .await
+                .unwrap()
+                .collect()
+                .await;
+            paths.sort();
+            assert_eq!(paths, ["dir/c.txt", "dir/d.txt"].map(PathBuf::from));
+
+            assert!(reader.is_directory(Path::new("dir")).await.unwrap());
+            assert!(!reader.is_directory(Path::new("a.txt")).await.unwrap());
+            assert!(reader.read_directory(Path::new("missing")).await.is_err());
+        });
+    }
+
+    #[test]
+    fn layered_source_merges_watchers() {
+        let (mod_dir, base_dir) = (Dir::default(), Dir::default());
+        let (mod_reader, base_reader) = (mod_dir.clone(), base_dir.clone());
+        base_dir.insert_asset_text(Path::new("a.txt"), "base a");
+
+        let layer = |dir: Dir, path: &'static str| {
+            AssetSourceBuilder::default()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() }))
+                .with_watcher(move |sender| {
+                    sender
+                        .send(AssetSourceEvent::ModifiedAsset(path.into()))
+                        .unwrap();
+                    Some(Box::new(TestWatcher))
+                })
+        };
+        let mut builder = AssetSourceBuilder::layered(vec![
+            layer(mod_reader, "mod.txt"),
+            AssetSourceBuilder::default().with_reader(|| Box::new(MemoryAssetReader::default())),
+            layer(base_reader, "base.txt"),
+        ]);
+        let source = builder.build(AssetSourceId::Default, true, false).unwrap();
+
+        let events: Vec<_> = source.event_receiver().unwrap().try_iter().collect();
+        assert_eq!(
+            events,
+            vec![
+                AssetSourceEvent::ModifiedAsset("mod.txt".into()),
+                AssetSourceEvent::ModifiedAsset("base.txt".into()),
+            ]
+        );
+        assert!(source.processed_reader().is_err());
+
+        let text = |source: &crate::io::AssetSource| {
+            block_on(async {
+                let mut text = String::new();
+                let mut reader = source.reader().read(Path::new("a.txt")).await.unwrap();
+                reader.read_to_string(&mut text).await.unwrap();
+                text
+            })
+        };
+        assert_eq!(text(&source), "base a");
+        mod_dir.insert_asset_text(Path::new("a.txt"), "mod a");
+        assert_eq!(text(&source), "mod a");
+    }
+}
@@ -0,0 +1,660 @@
+//! Assets shipped in a single packed archive, with an index giving random access to each of them.
+//!
+//! An archive is built with an [`AssetPackBuilder`], usually from the processed assets written by the
+//! [`AssetProcessor`](crate::processor::AssetProcessor) to `imported_assets/Default`, and read with a
+//! [`PackedAssetReader`], which can back an [`AssetSource`](crate::io::AssetSource):
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::{AssetSource, pack::{AssetPackBuilder, PackedAssetReader}}};
+//! # fn build() -> Result<(), bevy_asset::io::pack::AssetPackError> {
+//! // At build time, pack the processed assets.
+//! let mut builder = AssetPackBuilder::default();
+//! builder.insert_directory("imported_assets/Default")?;
+//! builder.write_to_file("assets.pak")?;
+//!
+//! // At run time, read them from the archive.
+//! let reader = PackedAssetReader::open("assets.pak")?;
+//! App::new().register_asset_source(
+//!     "pack",
+//!     AssetSource::build().with_reader(move || Box::new(reader.clone())),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Format
+//!
+//! All integers are little-endian.
+//!
+//! | Field       | Size     | Description                                               |
+//! |-------------|----------|-----------------------------------------------------------|
+//! | magic       | 8 bytes  | `BEVYPACK`                                                |
+//! | version     | 4 bytes  | The format version, currently `1`                         |
+//! | entry count | 4 bytes  | The number of entries in the index                        |
+//! | index       | variable | The entries, see below                                    |
+//! | data        | variable | The contents of the entries, one after the other          |
+//!
+//! Each entry of the index is made of the length of its path (4 bytes), its path as UTF-8 using `/` as separator,
+//! its kind (1 byte, `0` for an asset and `1` for its meta), then the offset of its contents from the start of
+//! the data (8 bytes) and their length (8 bytes).
+
+use crate::io::{
+    AssetReader, AssetReaderError, AsyncSeekForward, PathStream, Reader, SliceReader,
+    STACK_FUTURE_SIZE,
+};
+use alloc::{
+    borrow::ToOwned, boxed::Box, collections::BTreeMap, format, string::String, sync::Arc, vec,
+    vec::Vec,
+};
+use bevy_platform::collections::HashMap;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncRead;
+#[cfg(not(target_arch = "wasm32"))]
+use futures_io::AsyncSeek;
+use futures_lite::stream;
+use stackfuture::StackFuture;
+use std::{
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+const MAGIC: &[u8; 8] = b"BEVYPACK";
+const VERSION: u32 = 1;
+const ASSET_KIND: u8 = 0;
+const META_KIND: u8 = 1;
+
+/// Errors that occur while building or opening an asset pack.
+#[derive(Error, Debug)]
+pub enum AssetPackError {
+    /// Encountered an I/O error while reading or writing the archive.
+    #[error("encountered an io error while accessing the asset pack: {0}")]
+    Io(#[from] std::io::Error),
+    /// The data does not start with the asset pack magic bytes.
+    #[error("the data is not an asset pack")]
+    InvalidMagic,
+    /// The archive was written with an unsupported version of the format.
+    #[error("unsupported asset pack version {0}, expected version {VERSION}")]
+    UnsupportedVersion(u32),
+    /// The index of the archive is invalid.
+    #[error("the asset pack is corrupted: {0}")]
+    Corrupted(&'static str),
+    /// A path can't be stored in the archive.
+    #[error("the path {} can't be stored in an asset pack", _0.display())]
+    InvalidPath(PathBuf),
+}
+
+/// The location of an entry within the data of an archive.
+#[derive(Clone, Copy, Debug)]
+struct PackEntry {
+    offset: u64,
+    len: u64,
+}
+
+/// The index of an archive, read when it is opened.
+#[derive(Debug, Default)]
+struct PackIndex {
+    assets: HashMap<PathBuf, PackEntry>,
+    metadata: HashMap<PathBuf, PackEntry>,
+    /// The files and directories directly contained by each directory, the root being the empty path.
+    dirs: HashMap<PathBuf, Vec<PathBuf>>,
+    /// The position of the start of the data within the archive.
+    data_start: u64,
+}
+
+impl PackIndex {
+    /// Reads the header and index of an archive of `total_len` bytes.
+    fn read(reader: &mut impl Read, total_len: u64) -> Result<Self, AssetPackError> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(AssetPackError::InvalidMagic);
+        }
+        let version = read_u32(reader)?;
+        if version != VERSION {
+            return Err(AssetPackError::UnsupportedVersion(version));
+        }
+
+        let mut index = PackIndex::default();
+        index.dirs.insert(PathBuf::new(), Vec::new());
+        let count = read_u32(reader)?;
+        let mut position = (MAGIC.len() + 8) as u64;
+        for _ in 0..count {
+            let path_len = read_u32(reader)?;
+            if u64::from(path_len) > total_len {
+                return Err(AssetPackError::Corrupted("path length out of bounds"));
+            }
+            let mut path = vec![0; path_len as usize];
+            reader.read_exact(&mut path)?;
+            let path = String::from_utf8(path)
+                .map_err(|_| AssetPackError::Corrupted("path is not valid UTF-8"))?;
+            let mut kind = [0];
+            reader.read_exact(&mut kind)?;
+            let entry = PackEntry {
+                offset: read_u64(reader)?,
+                len: read_u64(reader)?,
+            };
+            position += 4 + u64::from(path_len) + 1 + 16;
+
+            let path = PathBuf::from(path);
+            match kind[0] {
+                ASSET_KIND => {
+                    index.insert_dirs(&path);
+                    index.assets.insert(path, entry);
+                }
+                META_KIND => {
+                    index.metadata.insert(path, entry);
+                }
+                _ => return Err(AssetPackError::Corrupted("unknown entry kind")),
+            }
+        }
+
+        index.data_start = position;
+        let data_len = total_len.saturating_sub(position);
+        let in_bounds = |entry: &PackEntry| {
+            entry
+                .offset
+                .checked_add(entry.len)
+                .is_some_and(|end| end <= data_len)
+        };
+        if !index
+            .assets
+            .values()
+            .chain(index.metadata.values())
+            .all(in_bounds)
+        {
+            return Err(AssetPackError::Corrupted("entry out of bounds"));
+        }
+        Ok(index)
+    }
+
+    /// Registers `path` in the listing of its parent directory, and each of its ancestors in theirs.
+    fn insert_dirs(&mut self, path: &Path) {
+        let mut child = path.to_owned();
+        while let Some(parent) = child.parent() {
+            let parent = parent.to_owned();
+            let children = self.dirs.entry(parent.clone()).or_default();
+            let known_parent = !children.is_empty();
+            children.push(child);
+            if known_parent {
+                break;
+            }
+            child = parent;
+        }
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> std::io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Where the contents of an archive are read from.
+#[derive(Debug)]
+enum PackStorage {
+    Bytes(Vec<u8>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+struct PackArchive {
+    index: PackIndex,
+    storage: PackStorage,
+}
+
+/// An [`AssetReader`] reading assets and their meta from an archive built with an [`AssetPackBuilder`].
+///
+/// The index of the archive is read when it is opened, after which each asset is read
+/// directly from its location in the archive. This is cheap to clone.
+#[derive(Clone, Debug)]
+pub struct PackedAssetReader {
+    archive: Arc<PackArchive>,
+}
+
+impl PackedAssetReader {
+    /// Opens the archive stored in the file at `path`.
+    ///
+    /// Only the index is read here. The file is then opened each time an asset is read, so it must not be
+    /// modified while this reader is in use.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AssetPackError> {
+        let path = path.into();
+        let file = std::fs::File::open(&path)?;
+        let total_len = file.metadata()?.len();
+        let index = PackIndex::read(&mut std::io::BufReader::new(file), total_len)?;
+        Ok(Self {
+            archive: Arc::new(PackArchive {
+                index,
+                storage: PackStorage::File(path),
+            }),
+        })
+    }
+
+    /// Reads the archive stored in `bytes`, for example after downloading it.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, AssetPackError> {
+        let index = PackIndex::read(&mut bytes.as_slice(), bytes.len() as u64)?;
+        Ok(Self {
+            archive: Arc::new(PackArchive {
+                index,
+                storage: PackStorage::Bytes(bytes),
+            }),
+        })
+    }
+
+    /// Returns an iterator over the paths of the assets stored in the archive.
+    pub fn asset_paths(&self) -> impl Iterator<Item = &Path> {
+        self.archive.index.assets.keys().map(PathBuf::as_path)
+    }
+
+    async fn read_entry<'a>(
+        &'a self,
+        path: &Path,
+        entry: Option<&PackEntry>,
+    ) -> Result<PackedReader<'a>, AssetReaderError> {
+        let entry = *entry.ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        let start = self.archive.index.data_start + entry.offset;
+        match &self.archive.storage {
+            PackStorage::Bytes(bytes) => Ok(PackedReader::Slice(SliceReader::new(
+                &bytes[start as usize..(start + entry.len) as usize],
+            ))),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackStorage::File(archive_path) => {
+                let mut file = async_fs::File::open(archive_path).await?;
+                futures_lite::AsyncSeekExt::seek(&mut file, std::io::SeekFrom::Start(start))
+                    .await?;
+                Ok(PackedReader::File(PackedFileReader {
+                    file,
+                    len: entry.len,
+                    position: 0,
+                }))
+            }
+        }
+    }
+}
+
+impl AssetReader for PackedAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_entry(path, self.archive.index.assets.get(path))
+            .await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_entry(path, self.archive.index.metadata.get(path))
+            .await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let children = self
+            .archive
+            .index
+            .dirs
+            .get(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        let stream: Box<PathStream> = Box::new(stream::iter(children.clone()));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.archive.index.dirs.contains_key(path))
+    }
+}
+
+/// A [`Reader`] for a single entry of an archive read by a [`PackedAssetReader`].
+enum PackedReader<'a> {
+    Slice(SliceReader<'a>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PackedFileReader),
+}
+
+impl AsyncRead for PackedReader<'_> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        match self.get_mut() {
+            PackedReader::Slice(reader) => Pin::new(reader).poll_read(cx, buf),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackedReader::File(reader) => Pin::new(reader).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncSeekForward for PackedReader<'_> {
+    fn poll_seek_forward(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<futures_io::Result<u64>> {
+        match self.get_mut() {
+            PackedReader::Slice(reader) => Pin::new(reader).poll_seek_forward(cx, offset),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackedReader::File(reader) => Pin::new(reader).poll_seek_forward(cx, offset),
+        }
+    }
+}
+
+impl Reader for PackedReader<'_> {
+    fn read_to_end<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+    ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
+        match self {
+            PackedReader::Slice(reader) => reader.read_to_end(buf),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackedReader::File(reader) => {
+                StackFuture::from(futures_lite::AsyncReadExt::read_to_end(reader, buf))
+            }
+        }
+    }
+}
+
+/// Reads an entry from the archive file, without going past its end.
+#[cfg(not(target_arch = "wasm32"))]
+struct PackedFileReader {
+    file: async_fs::File,
+    len: u64,
+    position: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AsyncRead for PackedFileReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        let remaining = self.len - self.position;
+        let max = buf.len().min(remaining.try_into().unwrap_or(usize::MAX));
+        let n = futures_lite::ready!(Pin::new(&mut self.file).poll_read(cx, &mut buf[..max]))?;
+        self.position += n as u64;
+        Poll::Ready(Ok(n))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AsyncSeekForward for PackedFileReader {
+    fn poll_seek_forward(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<futures_io::Result<u64>> {
+        // Seeking past the end of the entry stops at its end, where reading returns nothing.
+        let offset = offset.min(self.len - self.position);
+        futures_lite::ready!(
+            Pin::new(&mut self.file).poll_seek(cx, std::io::SeekFrom::Current(offset as i64))
+        )?;
+        self.position += offset;
+        Poll::Ready(Ok(self.position))
+    }
+}
+
+/// The contents of an entry added to an [`AssetPackBuilder`].
+#[derive(Debug)]
+enum PackSource {
+    Bytes(Vec<u8>),
+    File(PathBuf),
+}
+
+/// Builds an archive that can be read by a [`PackedAssetReader`].
+///
+/// Entries are stored sorted by path, so building an archive from the same assets always produces
+/// the same bytes.
+#[derive(Debug, Default)]
+pub struct AssetPackBuilder {
+    entries: BTreeMap<(String, u8), PackSource>,
+}
+
+impl AssetPackBuilder {
+    /// Adds an asset with the given contents at `path`, replacing any asset previously added there.
+    pub fn insert_asset(
+        &mut self,
+        path: &Path,
+        asset: impl Into<Vec<u8>>,
+    ) -> Result<&mut Self, AssetPackError> {
+        self.entries.insert(
+            (pack_path(path)?, ASSET_KIND),
+            PackSource::Bytes(asset.into()),
+        );
+        Ok(self)
+    }
+
+    /// Adds the meta of the asset at `path`, replacing any meta previously added there.
+    ///
+    /// `path` is the path of the asset, not including the `.meta` extension.
+    pub fn insert_meta(
+        &mut self,
+        path: &Path,
+        meta: impl Into<Vec<u8>>,
+    ) -> Result<&mut Self, AssetPackError> {
+        self.entries.insert(
+            (pack_path(path)?, META_KIND),
+            PackSource::Bytes(meta.into()),
+        );
+        Ok(self)
+    }
+
+    /// Adds every file in the directory at `root` and its subdirectories, at their path relative to `root`.
+    ///
+    /// Files with the `.meta` extension are added as the meta of the asset they belong to, matching the layout
+    /// of the processed assets written by the [`AssetProcessor`](crate::processor::AssetProcessor).
+    /// Their contents are only read when the archive is written.
+    pub fn insert_directory(
+        &mut self,
+        root: impl AsRef<Path>,
+    ) -> Result<&mut Self, AssetPackError> {
+        let root = root.as_ref();
+        let mut dirs = vec![root.to_owned()];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                let relative_path = path.strip_prefix(root).unwrap();
+                let is_meta = path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("meta"));
+                let key = if is_meta {
+                    (pack_path(&relative_path.with_extension(""))?, META_KIND)
+                } else {
+                    (pack_path(relative_path)?, ASSET_KIND)
+                };
+                self.entries.insert(key, PackSource::File(path));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Writes the archive to `writer`.
+    pub fn write(&self, mut writer: impl Write) -> Result<(), AssetPackError> {
+        let mut contents = Vec::with_capacity(self.entries.len());
+        let mut offset = 0;
+        for source in self.entries.values() {
+            let len = match source {
+                PackSource::Bytes(bytes) => bytes.len() as u64,
+                PackSource::File(path) => std::fs::metadata(path)?.len(),
+            };
+            contents.push(PackEntry { offset, len });
+            offset += len;
+        }
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        let count = u32::try_from(self.entries.len())
+            .map_err(|_| std::io::Error::other("too many entries for an asset pack"))?;
+        writer.write_all(&count.to_le_bytes())?;
+        for ((path, kind), entry) in self.entries.keys().zip(&contents) {
+            writer.write_all(&(path.len() as u32).to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&[*kind])?;
+            writer.write_all(&entry.offset.to_le_bytes())?;
+            writer.write_all(&entry.len.to_le_bytes())?;
+        }
+
+        for (source, entry) in self.entries.values().zip(&contents) {
+            match source {
+                PackSource::Bytes(bytes) => writer.write_all(bytes)?,
+                PackSource::File(path) => {
+                    let copied = std::io::copy(&mut std::fs::File::open(path)?, &mut writer)?;
+                    if copied != entry.len {
+                        return Err(std::io::Error::other(format!(
+                            "{} changed while being packed",
+                            path.display()
+                        ))
+                        .into());
+                    }
+                }
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the archive to a new file at `path`, replacing any existing file.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), AssetPackError> {
+        let file = std::fs::File::create(path)?;
+        self.write(std::io::BufWriter::new(file))
+    }
+}
+
+/// Converts `path` to the representation stored in the index, using `/` as separator.
+fn pack_path(path: &Path) -> Result<String, AssetPackError> {
+    let mut result = String::new();
+    for component in path.components() {
+        let std::path::Component::Normal(name) = component else {
+            return Err(AssetPackError::InvalidPath(path.to_owned()));
+        };
+        let name = name
+            .to_str()
+            .ok_or_else(|| AssetPackError::InvalidPath(path.to_owned()))?;
+        if !result.is_empty() {
+            result.push('/');
+        }
+        result.push_str(name);
+    }
+    if result.is_empty() {
+        return Err(AssetPackError::InvalidPath(path.to_owned()));
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetPackBuilder, AssetPackError, PackedAssetReader};
+    use crate::io::{AssetReader, AssetReaderError, AsyncSeekForwardExt, Reader};
+    use alloc::{format, vec::Vec};
+    use bevy_tasks::block_on;
+    use futures_lite::StreamExt;
+    use std::path::{Path, PathBuf};
+
+    fn read(reader: &PackedAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut asset = reader.read(Path::new(path)).await?;
+            let mut bytes = Vec::new();
+            asset.read_to_end(&mut bytes).await?;
+            Ok(bytes)
+        })
+    }
+
+    fn check_archive(reader: &PackedAssetReader) {
+        assert_eq!(read(reader, "a.txt").unwrap(), b"a");
+        assert_eq!(read(reader, "x/y/b.txt").unwrap(), b"bbbb");
+        assert_eq!(
+            block_on(reader.read_meta_bytes(Path::new("x/y/b.txt"))).unwrap(),
+            b"meta"
+        );
+        assert!(matches!(
+            read(reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(_))
+        ));
+        assert!(block_on(reader.read_meta_bytes(Path::new("a.txt"))).is_err());
+
+        block_on(async {
+            let mut asset = reader.read(Path::new("x/y/b.txt")).await.unwrap();
+            assert_eq!(asset.seek_forward(2).await.unwrap(), 2);
+            let mut bytes = Vec::new();
+            asset.read_to_end(&mut bytes).await.unwrap();
+            assert_eq!(bytes, b"bb");
+        });
+
+        let list = |path: &str| {
+            let mut paths: Vec<PathBuf> = block_on(async {
+                reader
+                    .read_directory(Path::new(path))
+                    .await
+                    .unwrap()
+                    .collect()
+                    .await
+            });
+            paths.sort();
+            paths
+        };
+        assert_eq!(list(""), [PathBuf::from("a.txt"), PathBuf::from("x")]);
+        assert_eq!(list("x"), [PathBuf::from("x/y")]);
+        assert_eq!(list("x/y"), [PathBuf::from("x/y/b.txt")]);
+        assert!(block_on(reader.is_directory(Path::new("x/y"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("a.txt"))).unwrap());
+    }
+
+    #[test]
+    fn read_packed_bytes() {
+        let mut builder = AssetPackBuilder::default();
+        builder
+            .insert_asset(Path::new("a.txt"), "a")
+            .unwrap()
+            .insert_asset(Path::new("x/y/b.txt"), "bbbb")
+            .unwrap()
+            .insert_meta(Path::new("x/y/b.txt"), "meta")
+            .unwrap();
+        let mut bytes = Vec::new();
+        builder.write(&mut bytes).unwrap();
+
+        check_archive(&PackedAssetReader::from_bytes(bytes.clone()).unwrap());
+
+        bytes.truncate(bytes.len() - 1);
+        assert!(matches!(
+            PackedAssetReader::from_bytes(bytes),
+            Err(AssetPackError::Corrupted(_))
+        ));
+        assert!(matches!(
+            PackedAssetReader::from_bytes(b"not a pack at all".to_vec()),
+            Err(AssetPackError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn pack_directory_to_file() {
+        let root = std::env::temp_dir().join(format!("bevy_asset_pack_{}", std::process::id()));
+        let assets = root.join("imported_assets");
+        std::fs::create_dir_all(assets.join("x/y")).unwrap();
+        std::fs::write(assets.join("a.txt"), "a").unwrap();
+        std::fs::write(assets.join("x/y/b.txt"), "bbbb").unwrap();
+        std::fs::write(assets.join("x/y/b.txt.meta"), "meta").unwrap();
+
+        let archive = root.join("assets.pak");
+        let mut builder = AssetPackBuilder::default();
+        builder.insert_directory(&assets).unwrap();
+        builder.write_to_file(&archive).unwrap();
+        let reader = PackedAssetReader::open(&archive).unwrap();
+        check_archive(&reader);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
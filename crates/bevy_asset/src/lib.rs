@@ -174,6 +174,7 @@ pub mod prelude {
 
 mod asset_changed;
 mod assets;
+mod budget;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -188,6 +189,7 @@ mod server;
 
 pub use assets::*;
 pub use bevy_asset_macros::Asset;
+pub use budget::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -543,6 +545,10 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Sets the [`AssetBudget`] of the [`Assets`] of the given [`Asset`] type, which must already be initialized.
+    ///
+    /// See [`Assets::set_budget`] for details.
+    fn set_asset_budget<A: Asset + AssetSize>(&mut self, budget: AssetBudget) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -646,6 +652,13 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn set_asset_budget<A: Asset + AssetSize>(&mut self, budget: AssetBudget) -> &mut Self {
+        self.world_mut()
+            .resource_mut::<Assets<A>>()
+            .set_budget(budget);
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -669,8 +682,9 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, LoadState, UnapprovedPathMode,
+        Asset, AssetApp, AssetBudget, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent,
+        AssetPath, AssetPlugin, AssetRetention, AssetServer, AssetSize, Assets, LoadState,
+        UnapprovedPathMode,
     };
     use alloc::{
         boxed::Box,
@@ -858,6 +872,21 @@ mod tests {
         (app, gate_opener)
     }
 
+    /// Creates an app loading [`CoolText`] assets from `dir`, without gating reads like [`test_app`].
+    fn cool_text_app(dir: Dir, asset_plugin: AssetPlugin) -> App {
+        let mut app = App::new();
+        let memory_reader = MemoryAssetReader { root: dir };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(memory_reader.clone())),
+        )
+        .add_plugins((TaskPoolPlugin::default(), asset_plugin))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+        app
+    }
+
     pub fn run_app_until(app: &mut App, mut predicate: impl FnMut(&mut World) -> Option<()>) {
         for _ in 0..LARGE_ITERATION_COUNT {
             app.update();
@@ -1566,6 +1595,257 @@ mod tests {
         assert_eq!(events, expected_events);
     }
 
+    #[test]
+    fn dependency_graph() {
+        let dir = Dir::default();
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: ["b.cool.ron", "c.cool.ron"],
+    embedded_dependencies: ["d.cool.ron"],
+    sub_texts: [],
+)"#;
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: ["c.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let leaf_ron = r#"
+(
+    text: "leaf",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new("a.cool.ron"), a_ron);
+        dir.insert_asset_text(Path::new("b.cool.ron"), b_ron);
+        dir.insert_asset_text(Path::new("c.cool.ron"), leaf_ron);
+        dir.insert_asset_text(Path::new("d.cool.ron"), leaf_ron);
+        dir.insert_asset_text(Path::new("e.cool.ron"), leaf_ron);
+
+        let mut app = cool_text_app(
+            dir,
+            AssetPlugin {
+                watch_for_changes_override: Some(true),
+                ..Default::default()
+            },
+        );
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let e: Handle<CoolText> = asset_server.load("e.cool.ron");
+        let missing: Handle<CoolText> = asset_server.load("missing.cool.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded_with_dependencies(&a)
+                && asset_server.is_loaded(&e)
+                && asset_server.load_state(&missing).is_failed())
+            .then_some(())
+        });
+
+        let graph = asset_server.dependency_graph_of(&a);
+        let paths: Vec<_> = graph
+            .nodes
+            .iter()
+            .map(|node| node.path.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(paths, ["a.cool.ron", "b.cool.ron", "c.cool.ron"]);
+        let [a_node, b_node, c_node] = &graph.nodes[..] else {
+            unreachable!();
+        };
+        assert_eq!(a_node.id, a.id().untyped());
+        assert_eq!(a_node.type_name, Some(core::any::type_name::<CoolText>()));
+        assert!(a_node.rec_dep_load_state.is_loaded());
+        assert_eq!(a_node.dependencies, [1, 2]);
+        assert_eq!(b_node.dependencies, [2]);
+        assert_eq!(c_node.dependents, [0, 1]);
+        assert_eq!(a_node.loader_dependencies, [AssetPath::from("d.cool.ron")]);
+        assert_eq!(graph.recursive_dependencies(0), [1, 2]);
+
+        let dot = graph.to_dot();
+        assert!(
+            dot.contains("0 [label=\"a.cool.ron\\nCoolText\"];"),
+            "{dot}"
+        );
+        assert!(dot.contains("0 -> 1;"), "{dot}");
+        assert!(dot.contains("1 -> 2;"), "{dot}");
+
+        let all = asset_server.dependency_graph();
+        assert!(all.get(&e).is_some());
+        assert!(all.get(&a).unwrap().dependents.is_empty());
+
+        let a_ron = ron::to_string(all.get(&a).unwrap()).unwrap();
+        assert!(
+            a_ron.contains(&format!("id:\"{}\"", a.id().untyped())),
+            "{a_ron}"
+        );
+        assert!(a_ron.contains("load_state:\"Loaded\""), "{a_ron}");
+        let missing_ron = ron::to_string(all.get(&missing).unwrap()).unwrap();
+        assert!(
+            missing_ron.contains("load_state:{\"Failed\":\"Path not found: missing.cool.ron\"}"),
+            "{missing_ron}"
+        );
+    }
+
+    impl AssetSize for CoolText {
+        fn estimated_size(&self) -> usize {
+            self.text.len()
+        }
+    }
+
+    /// Creates an app loading `<name>.cool.ron` assets of 4 bytes for each of the `names`.
+    fn sized_cool_text_app(names: &[&str]) -> App {
+        let dir = Dir::default();
+        for name in names {
+            let ron = format!(
+                r#"(text: "{}", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+                name.repeat(4)
+            );
+            dir.insert_asset_text(Path::new(&format!("{name}.cool.ron")), &ron);
+        }
+        cool_text_app(dir, AssetPlugin::default())
+    }
+
+    #[test]
+    fn cached_assets_are_evicted_over_budget() {
+        let mut app = sized_cool_text_app(&["a", "b", "c", "d"]);
+        app.set_asset_budget::<CoolText>(
+            AssetBudget::new(12).with_retention(AssetRetention::Cache),
+        )
+        .init_resource::<StoredEvents>()
+        .add_systems(Update, store_asset_events);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded(&a) && asset_server.is_loaded(&b) && asset_server.is_loaded(&c))
+                .then_some(())
+        });
+        let (a_id, b_id) = (a.id(), b.id());
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert_eq!(assets.resident_bytes(), Some(12));
+        assert_eq!(assets.cached_len(), 0);
+
+        // unused assets stay loaded within the budget
+        drop(a);
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(assets.contains(a_id));
+        assert_eq!(assets.cached_len(), 1);
+        assert_eq!(assets.cached_bytes(), 4);
+
+        // loading a cached asset again doesn't reload it
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        assert_eq!(a.id(), a_id);
+        assert!(asset_server.is_loaded(&a));
+        app.update();
+        assert_eq!(app.world().resource::<Assets<CoolText>>().cached_len(), 0);
+
+        drop(a);
+        app.update();
+        drop(b);
+        app.update();
+        assert_eq!(app.world().resource::<Assets<CoolText>>().cached_len(), 2);
+        app.world_mut().resource_mut::<StoredEvents>().0.clear();
+
+        // exceeding the budget evicts the least recently used asset
+        let d: Handle<CoolText> = asset_server.load("d.cool.ron");
+        run_app_until(&mut app, |world| {
+            (!world.resource::<Assets<CoolText>>().contains(a_id)).then_some(())
+        });
+        app.update();
+        assert!(asset_server.is_loaded(&d));
+        assert!(asset_server.get_load_state(a_id).is_none());
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(assets.contains(b_id));
+        assert_eq!(assets.resident_bytes(), Some(12));
+        let events = &app.world().resource::<StoredEvents>().0;
+        assert!(events.contains(&AssetEvent::Unused { id: a_id }));
+        assert!(events.contains(&AssetEvent::Removed { id: a_id }));
+        assert!(!events.contains(&AssetEvent::Unused { id: b_id }));
+
+        // unused assets are released once they are no longer cached
+        app.set_asset_budget::<CoolText>(AssetBudget::new(12));
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(!assets.contains(b_id));
+        assert_eq!(assets.resident_bytes(), Some(8));
+        drop(c);
+    }
+
+    #[test]
+    fn strong_handles_prevent_eviction_of_cached_assets() {
+        let mut app = sized_cool_text_app(&["a", "b", "c"]);
+        app.set_asset_budget::<CoolText>(AssetBudget::new(8).with_retention(AssetRetention::Cache));
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded(&a) && asset_server.is_loaded(&b)).then_some(())
+        });
+        let a_id = a.id();
+        drop(a);
+        app.update();
+        assert_eq!(app.world().resource::<Assets<CoolText>>().cached_len(), 1);
+
+        // a cached asset upgraded to a strong handle is in use again
+        let a = app
+            .world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .get_strong_handle(a_id)
+            .unwrap();
+        assert_eq!(app.world().resource::<Assets<CoolText>>().cached_len(), 0);
+
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        run_app_until(&mut app, |_| asset_server.is_loaded(&c).then_some(()));
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(assets.contains(a_id));
+        assert_eq!(assets.resident_bytes(), Some(12));
+
+        // dropping it makes the asset unused again, so it is evicted to meet the budget
+        drop(a);
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(!assets.contains(a_id));
+        assert_eq!(assets.resident_bytes(), Some(8));
+        assert!(asset_server.get_load_state(a_id).is_none());
+        drop((b, c));
+    }
+
+    #[test]
+    fn cached_assets_forgotten_by_the_asset_server_are_removed() {
+        let mut app = sized_cool_text_app(&["a", "b"]);
+        app.set_asset_budget::<CoolText>(AssetBudget::new(8).with_retention(AssetRetention::Cache));
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded(&a) && asset_server.is_loaded(&b)).then_some(())
+        });
+        let a_id = a.id();
+        drop(a);
+        app.update();
+        assert_eq!(app.world().resource::<Assets<CoolText>>().cached_len(), 1);
+
+        assert!(asset_server
+            .data
+            .infos
+            .write()
+            .process_handle_drop(a_id.untyped()));
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(!assets.contains(a_id));
+        assert_eq!(assets.cached_len(), 0);
+        assert_eq!(assets.resident_bytes(), Some(4));
+        drop(b);
+    }
+
     #[test]
     fn load_folder() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
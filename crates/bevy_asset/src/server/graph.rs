@@ -0,0 +1,189 @@
+use crate::{
+    AssetLoadError, AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState,
+    UntypedAssetId,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::Write;
+use disqualified::ShortName;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+/// A snapshot of the dependencies between the assets tracked by the [`AssetServer`](crate::AssetServer),
+/// returned by [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph) and
+/// [`AssetServer::dependency_graph_of`](crate::AssetServer::dependency_graph_of).
+///
+/// Nodes reference each other by their index in [`nodes`](Self::nodes), which are sorted by path.
+/// The graph can be exported to [DOT](https://graphviz.org/doc/info/lang.html) with [`to_dot`](Self::to_dot),
+/// and to JSON or any other format supported by `serde` as it implements [`Serialize`].
+///
+/// Only the assets tracked by the [`AssetServer`](crate::AssetServer) are included,
+/// so dependencies on assets added directly to [`Assets`](crate::Assets) are omitted.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AssetDependencyGraph {
+    /// The assets in the graph.
+    pub nodes: Vec<AssetDependencyNode>,
+}
+
+/// An asset in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetDependencyNode {
+    /// The id of the asset, serialized with its [`Display`](core::fmt::Display) implementation.
+    #[serde(serialize_with = "serialize_id")]
+    pub id: UntypedAssetId,
+    /// The name of the type of the asset, if it is registered.
+    pub type_name: Option<&'static str>,
+    /// The path of the asset, if it was loaded from one.
+    pub path: Option<AssetPath<'static>>,
+    /// The [`LoadState`] of the asset.
+    #[serde(serialize_with = "serialize_state")]
+    pub load_state: LoadState,
+    /// The [`DependencyLoadState`] of the asset.
+    #[serde(serialize_with = "serialize_state")]
+    pub dep_load_state: DependencyLoadState,
+    /// The [`RecursiveDependencyLoadState`] of the asset.
+    #[serde(serialize_with = "serialize_state")]
+    pub rec_dep_load_state: RecursiveDependencyLoadState,
+    /// The indices of the assets this asset depends on, which were loaded along with it.
+    pub dependencies: Vec<usize>,
+    /// The indices of the assets depending on this asset.
+    pub dependents: Vec<usize>,
+    /// The paths of the assets read by the loader of this asset.
+    ///
+    /// These are only recorded when the [`AssetServer`](crate::AssetServer) watches for changes.
+    pub loader_dependencies: Vec<AssetPath<'static>>,
+}
+
+impl AssetDependencyGraph {
+    /// Returns the index of the node of the asset with the given `id`, if it is in the graph.
+    pub fn index_of(&self, id: impl Into<UntypedAssetId>) -> Option<usize> {
+        let id = id.into();
+        self.nodes.iter().position(|node| node.id == id)
+    }
+
+    /// Returns the node of the asset with the given `id`, if it is in the graph.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.index_of(id).map(|index| &self.nodes[index])
+    }
+
+    /// Returns the indices of the nodes the node at `index` depends on, directly or not.
+    pub fn recursive_dependencies(&self, index: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = self.nodes[index].dependencies.clone();
+        let mut dependencies = Vec::new();
+        while let Some(index) = stack.pop() {
+            if !core::mem::replace(&mut visited[index], true) {
+                dependencies.push(index);
+                stack.extend(&self.nodes[index].dependencies);
+            }
+        }
+        dependencies.sort_unstable();
+        dependencies
+    }
+
+    /// Exports this graph to [DOT](https://graphviz.org/doc/info/lang.html).
+    ///
+    /// Dependencies are drawn as solid edges and loader dependencies as dashed edges,
+    /// and assets whose dependencies failed to load are drawn in red.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph assets {\n    node [shape=box];\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut label = match &node.path {
+                Some(path) => path.to_string(),
+                None => format!("{}", node.id),
+            };
+            if let Some(type_name) = node.type_name {
+                write!(label, "\n{}", ShortName(type_name)).unwrap();
+            }
+            write!(dot, "    {index} [label=").unwrap();
+            write_dot_string(&mut dot, &label);
+            if node.rec_dep_load_state.is_failed() || node.load_state.is_failed() {
+                dot.push_str(", color=red");
+            }
+            dot.push_str("];\n");
+        }
+        for (index, node) in self.nodes.iter().enumerate() {
+            for dependency in &node.dependencies {
+                writeln!(dot, "    {index} -> {dependency};").unwrap();
+            }
+            for path in &node.loader_dependencies {
+                for (dependency, _) in self
+                    .nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, node)| node.path.as_ref() == Some(path))
+                {
+                    writeln!(dot, "    {index} -> {dependency} [style=dashed];").unwrap();
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Writes `value` as a quoted DOT string.
+fn write_dot_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn serialize_id<S: Serializer>(id: &UntypedAssetId, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(id)
+}
+
+/// The load states, serialized by the name of their variant,
+/// or as `{"Failed": "<error>"}` when they failed.
+trait StateName {
+    fn name(&self) -> &'static str;
+    fn error(&self) -> Option<&AssetLoadError>;
+}
+
+macro_rules! impl_state_name {
+    ($($state:ty),*) => {
+        $(impl StateName for $state {
+            fn name(&self) -> &'static str {
+                match self {
+                    Self::NotLoaded => "NotLoaded",
+                    Self::Loading => "Loading",
+                    Self::Loaded => "Loaded",
+                    Self::Failed(_) => "Failed",
+                }
+            }
+
+            fn error(&self) -> Option<&AssetLoadError> {
+                match self {
+                    Self::Failed(error) => Some(error),
+                    _ => None,
+                }
+            }
+        })*
+    };
+}
+
+impl_state_name!(LoadState, DependencyLoadState, RecursiveDependencyLoadState);
+
+fn serialize_state<S: Serializer>(
+    state: &impl StateName,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match state.error() {
+        Some(error) => {
+            let mut map = serializer.serialize_map(Some(1))?;
+            map.serialize_entry(state.name(), &error.to_string())?;
+            map.end()
+        }
+        None => serializer.serialize_str(state.name()),
+    }
+}
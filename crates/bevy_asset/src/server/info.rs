@@ -1,13 +1,15 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
-    Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
-    Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
-    UntypedAssetId, UntypedHandle,
+    Asset, AssetDependencyGraph, AssetDependencyNode, AssetHandleProvider, AssetLoadError,
+    AssetPath, DependencyLoadState, ErasedLoadedAsset, Handle, InternalAssetEvent, LoadState,
+    RecursiveDependencyLoadState, StrongHandle, UntypedAssetId, UntypedHandle,
 };
 use alloc::{
     borrow::ToOwned,
     boxed::Box,
+    string::ToString,
     sync::{Arc, Weak},
+    vec,
     vec::Vec,
 };
 use bevy_ecs::world::World;
@@ -33,6 +35,8 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset, set when it is loaded.
+    dependencies: HashSet<UntypedAssetId>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -43,6 +47,8 @@ pub(crate) struct AssetInfo {
     /// The number of handle drops to skip for this asset.
     /// See usage (and comments) in `get_or_create_path_handle` for context.
     handle_drops_to_skip: usize,
+    /// Whether this asset is unused, but kept loaded by [`AssetRetention::Cache`](crate::AssetRetention::Cache).
+    retained: bool,
     /// List of tasks waiting for this asset to complete loading
     pub(crate) waiting_tasks: Vec<Waker>,
 }
@@ -59,10 +65,12 @@ impl AssetInfo {
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
             failed_rec_dependencies: HashSet::default(),
+            dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
             handle_drops_to_skip: 0,
+            retained: false,
             waiting_tasks: Vec::new(),
         }
     }
@@ -82,6 +90,8 @@ pub(crate) struct AssetInfos {
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) living_labeled_assets: HashMap<AssetPath<'static>, HashSet<Box<str>>>,
     pub(crate) handle_providers: TypeIdMap<AssetHandleProvider>,
+    /// The names of the registered asset types.
+    pub(crate) type_names: TypeIdMap<&'static str>,
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId)>,
     pub(crate) dependency_failed_event_sender:
         TypeIdMap<fn(&mut World, UntypedAssetId, AssetPath<'static>, AssetLoadError)>,
@@ -245,8 +255,11 @@ impl AssetInfos {
                     // because it locks the AssetInfos collection)
 
                     // We must create a new strong handle for the existing id and ensure that the drop of the old
-                    // strong handle doesn't remove the asset from the Assets collection
-                    info.handle_drops_to_skip += 1;
+                    // strong handle doesn't remove the asset from the Assets collection.
+                    // If the asset was kept loaded by its budget, the drop was already processed.
+                    if !core::mem::take(&mut info.retained) {
+                        info.handle_drops_to_skip += 1;
+                    }
                     let provider = self
                         .handle_providers
                         .get(&type_id)
@@ -365,6 +378,27 @@ impl AssetInfos {
         }
     }
 
+    /// Marks the asset as unused but kept loaded, after its last handle was dropped.
+    /// Returns `false` if a new handle has been created since, or the asset isn't loaded from a path.
+    pub(crate) fn retain_dropped_handle(&mut self, id: UntypedAssetId) -> bool {
+        match self.infos.get_mut(&id) {
+            Some(info)
+                if info.handle_drops_to_skip == 0
+                    && info.load_state.is_loaded()
+                    && info.path.is_some() =>
+            {
+                info.retained = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the asset is unused but kept loaded, see [`Self::retain_dropped_handle`].
+    pub(crate) fn is_retained(&self, id: UntypedAssetId) -> bool {
+        self.infos.get(&id).is_some_and(|info| info.retained)
+    }
+
     /// Returns `true` if the asset should be removed from the collection.
     pub(crate) fn process_handle_drop(&mut self, id: UntypedAssetId) -> bool {
         Self::process_handle_drop_internal(
@@ -392,7 +426,8 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
-        let mut loading_deps = loaded_asset.dependencies;
+        let dependencies = loaded_asset.dependencies;
+        let mut loading_deps = dependencies.clone();
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
         let mut loading_rec_deps = loading_deps.clone();
@@ -488,6 +523,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -544,6 +580,61 @@ impl AssetInfos {
         }
     }
 
+    /// Returns the dependency graph of the assets reachable from `root` through their dependencies,
+    /// or of all the tracked assets if `root` is `None`.
+    pub(crate) fn dependency_graph(&self, root: Option<UntypedAssetId>) -> AssetDependencyGraph {
+        let mut ids: Vec<UntypedAssetId> = match root {
+            Some(root) => {
+                let mut visited = <HashSet<_>>::default();
+                let mut stack = vec![root];
+                while let Some(id) = stack.pop() {
+                    if let Some(info) = self.infos.get(&id) {
+                        if visited.insert(id) {
+                            stack.extend(info.dependencies.iter().copied());
+                        }
+                    }
+                }
+                visited.into_iter().collect()
+            }
+            None => self.infos.keys().copied().collect(),
+        };
+        ids.sort_by_cached_key(|id| (self.infos[id].path.as_ref().map(ToString::to_string), *id));
+        let indices: HashMap<_, _> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        let mut nodes: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                let info = &self.infos[id];
+                let mut dependencies: Vec<_> = info
+                    .dependencies
+                    .iter()
+                    .filter_map(|dependency| indices.get(dependency).copied())
+                    .collect();
+                dependencies.sort_unstable();
+                let mut loader_dependencies: Vec<_> =
+                    info.loader_dependencies.keys().cloned().collect();
+                loader_dependencies.sort_by_cached_key(ToString::to_string);
+                AssetDependencyNode {
+                    id: *id,
+                    type_name: self.type_names.get(&id.type_id()).copied(),
+                    path: info.path.clone(),
+                    load_state: info.load_state.clone(),
+                    dep_load_state: info.dep_load_state.clone(),
+                    rec_dep_load_state: info.rec_dep_load_state.clone(),
+                    dependencies,
+                    dependents: Vec::new(),
+                    loader_dependencies,
+                }
+            })
+            .collect();
+        for index in 0..nodes.len() {
+            for dependency in nodes[index].dependencies.clone() {
+                nodes[dependency].dependents.push(index);
+            }
+        }
+        AssetDependencyGraph { nodes }
+    }
+
     /// Recursively propagates loaded state up the dependency tree.
     fn propagate_loaded_state(
         infos: &mut AssetInfos,
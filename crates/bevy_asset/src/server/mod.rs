@@ -1,3 +1,4 @@
+mod graph;
 mod info;
 mod loaders;
 
@@ -32,6 +33,7 @@ use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
+pub use graph::*;
 use info::*;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
@@ -186,6 +188,10 @@ impl AssetServer {
 
         let mut infos = self.data.infos.write();
 
+        infos
+            .type_names
+            .insert(TypeId::of::<A>(), core::any::type_name::<A>());
+
         infos
             .dependency_loaded_event_sender
             .insert(TypeId::of::<A>(), sender::<A>);
@@ -1259,6 +1265,21 @@ impl AssetServer {
         Some(info.path.as_ref()?.clone())
     }
 
+    /// Returns the dependency graph of all the assets tracked by this server.
+    ///
+    /// See [`AssetDependencyGraph`] for details.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph(None)
+    }
+
+    /// Returns the dependency graph of the asset with the given `id` and of all the assets it
+    /// depends on, directly or not. The graph is empty if the asset is not tracked by this server.
+    ///
+    /// See [`AssetDependencyGraph`] for details.
+    pub fn dependency_graph_of(&self, id: impl Into<UntypedAssetId>) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph(Some(id.into()))
+    }
+
     /// Returns the [`AssetServerMode`] this server is currently in.
     pub fn mode(&self) -> AssetServerMode {
         self.data.mode
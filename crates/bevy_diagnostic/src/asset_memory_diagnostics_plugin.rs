@@ -0,0 +1,173 @@
+use alloc::format;
+use bevy_app::prelude::*;
+use bevy_asset::{Asset, AssetSize, Assets};
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+use disqualified::ShortName;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds diagnostics for the memory used by the assets of type `A` to an App.
+///
+/// The estimated number of bytes resident in [`Assets<A>`], and the part of them used by unused assets
+/// kept loaded, are measured in [`Update`] under the paths returned by [`Self::resident_bytes_path`] and
+/// [`Self::cached_bytes_path`]. The plugin makes the [`Assets<A>`] estimate the size of their assets with
+/// [`AssetSize`], whether or not they have an [`AssetBudget`](bevy_asset::AssetBudget).
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct AssetMemoryDiagnosticsPlugin<A: Asset + AssetSize>(PhantomData<fn() -> A>);
+
+impl<A: Asset + AssetSize> Default for AssetMemoryDiagnosticsPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Asset + AssetSize> Plugin for AssetMemoryDiagnosticsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::resident_bytes_path()).with_suffix(" B"))
+            .register_diagnostic(Diagnostic::new(Self::cached_bytes_path()).with_suffix(" B"))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl<A: Asset + AssetSize> AssetMemoryDiagnosticsPlugin<A> {
+    /// Returns the path of the diagnostic of the bytes resident in [`Assets<A>`],
+    /// which has the form `asset_memory/<asset type>/resident_bytes`.
+    pub fn resident_bytes_path() -> DiagnosticPath {
+        DiagnosticPath::new(format!(
+            "asset_memory/{}/resident_bytes",
+            ShortName::of::<A>()
+        ))
+    }
+
+    /// Returns the path of the diagnostic of the bytes used by the unused assets kept loaded in [`Assets<A>`],
+    /// which has the form `asset_memory/<asset type>/cached_bytes`.
+    pub fn cached_bytes_path() -> DiagnosticPath {
+        DiagnosticPath::new(format!(
+            "asset_memory/{}/cached_bytes",
+            ShortName::of::<A>()
+        ))
+    }
+
+    /// Measures the memory used by the assets of type `A`.
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, assets: Option<ResMut<Assets<A>>>) {
+        let Some(mut assets) = assets else {
+            return;
+        };
+        if !assets.tracks_sizes() {
+            assets.track_sizes();
+        }
+        let Some(resident_bytes) = assets.resident_bytes() else {
+            return;
+        };
+        diagnostics.add_measurement(&Self::resident_bytes_path(), || resident_bytes as f64);
+        diagnostics.add_measurement(&Self::cached_bytes_path(), || assets.cached_bytes() as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticsStore;
+    use alloc::{boxed::Box, vec::Vec};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId, Reader,
+        },
+        AssetApp, AssetBudget, AssetLoader, AssetPlugin, AssetRetention, AssetServer, AssetSize,
+        Handle, LoadContext,
+    };
+    use bevy_reflect::TypePath;
+    use std::path::Path;
+
+    #[derive(Asset, TypePath)]
+    struct Blob(Vec<u8>);
+
+    impl AssetSize for Blob {
+        fn estimated_size(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[derive(TypePath)]
+    struct BlobLoader;
+
+    impl AssetLoader for BlobLoader {
+        type Asset = Blob;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(Blob(bytes))
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["blob"]
+        }
+    }
+
+    fn measurement(app: &App, path: &DiagnosticPath) -> Option<f64> {
+        app.world()
+            .resource::<DiagnosticsStore>()
+            .get(path)
+            .and_then(Diagnostic::value)
+    }
+
+    #[test]
+    fn measures_resident_and_cached_bytes() {
+        let dir = Dir::default();
+        dir.insert_asset(Path::new("a.blob"), b"aaaa".to_vec());
+        dir.insert_asset(Path::new("b.blob"), b"bbbbbb".to_vec());
+
+        let mut app = App::new();
+        let reader = MemoryAssetReader { root: dir };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            AssetMemoryDiagnosticsPlugin::<Blob>::default(),
+        ))
+        .init_asset::<Blob>()
+        .register_asset_loader(BlobLoader);
+
+        let resident = AssetMemoryDiagnosticsPlugin::<Blob>::resident_bytes_path();
+        let cached = AssetMemoryDiagnosticsPlugin::<Blob>::cached_bytes_path();
+
+        // sizes are measured without a budget
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<Blob> = asset_server.load("a.blob");
+        let b: Handle<Blob> = asset_server.load("b.blob");
+        for _ in 0..1000 {
+            if asset_server.is_loaded(&a) && asset_server.is_loaded(&b) {
+                break;
+            }
+            app.update();
+        }
+        app.update();
+        assert_eq!(measurement(&app, &resident), Some(10.0));
+        assert_eq!(measurement(&app, &cached), Some(0.0));
+
+        app.set_asset_budget::<Blob>(AssetBudget::new(16).with_retention(AssetRetention::Cache));
+
+        // unused assets are kept loaded, and measured as cached
+        drop(a);
+        app.update();
+        assert_eq!(measurement(&app, &resident), Some(10.0));
+        assert_eq!(measurement(&app, &cached), Some(4.0));
+        drop(b);
+    }
+}
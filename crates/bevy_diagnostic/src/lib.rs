@@ -16,6 +16,8 @@ extern crate std;
 
 extern crate alloc;
 
+#[cfg(feature = "bevy_asset")]
+mod asset_memory_diagnostics_plugin;
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
@@ -23,7 +25,10 @@ mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod system_timing_diagnostics_plugin;
 
+#[cfg(feature = "bevy_asset")]
+pub use asset_memory_diagnostics_plugin::AssetMemoryDiagnosticsPlugin;
 pub use diagnostic::*;
 
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
@@ -32,6 +37,12 @@ pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+#[cfg(feature = "std")]
+pub use system_timing_diagnostics_plugin::SystemTimingDump;
+pub use system_timing_diagnostics_plugin::{
+    SystemTimingDiagnosticsPlugin, SystemTimingFormat, SystemTimingStats, TimingStatistic,
+    TimingStatistics,
+};
 
 use bevy_app::prelude::*;
 
@@ -0,0 +1,421 @@
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel, SystemTimings, TimingTarget},
+};
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+use bevy_utils::write_json_string;
+use core::fmt::Write;
+
+/// Adds per-system and per-set execution time diagnostics to an App.
+///
+/// Each time a system or a named system set of the main world runs, the time spent running it is measured,
+/// whichever executor runs its schedule, with [`SystemTimings`]. The mean, 95th percentile and maximum of
+/// the last [`max_history_length`](Self::max_history_length) measurements are then added to the
+/// [`DiagnosticsStore`] in [`Last`], under the paths returned by [`Self::diagnostic_path`], and are available
+/// in the [`SystemTimingStats`] resource.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemTimingDiagnosticsPlugin {
+    /// The number of most recent measurements of each system and set the statistics are computed over.
+    pub max_history_length: usize,
+    /// Where to write the statistics of every system and set when the app exits, if anywhere.
+    #[cfg(feature = "std")]
+    pub dump: Option<SystemTimingDump>,
+}
+
+impl Default for SystemTimingDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_history_length: crate::DEFAULT_MAX_HISTORY_LENGTH,
+            #[cfg(feature = "std")]
+            dump: None,
+        }
+    }
+}
+
+/// A file the [`SystemTimingStats`] are written to when the app exits.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct SystemTimingDump {
+    /// The path of the file, which is overwritten.
+    pub path: std::path::PathBuf,
+    /// The format of the file.
+    pub format: SystemTimingFormat,
+}
+
+/// The formats [`SystemTimingStats`] can be exported to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SystemTimingFormat {
+    /// See [`SystemTimingStats::to_csv`].
+    Csv,
+    /// See [`SystemTimingStats::to_json`].
+    Json,
+}
+
+/// A statistic of the time spent running a system or system set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimingStatistic {
+    /// The mean of the measurements, see [`TimingStatistics::mean`].
+    Mean,
+    /// The 95th percentile of the measurements, see [`TimingStatistics::p95`].
+    P95,
+    /// The longest of the measurements, see [`TimingStatistics::max`].
+    Max,
+}
+
+impl TimingStatistic {
+    const ALL: [TimingStatistic; 3] = [Self::Mean, Self::P95, Self::Max];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Mean => "mean",
+            Self::P95 => "p95",
+            Self::Max => "max",
+        }
+    }
+}
+
+/// The statistics of the recent measurements of a system or system set, in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimingStatistics {
+    /// The number of measurements.
+    pub samples: usize,
+    /// The mean of the measurements.
+    pub mean: f64,
+    /// The 95th percentile, using the nearest-rank method.
+    pub p95: f64,
+    /// The longest of the measurements.
+    pub max: f64,
+}
+
+impl TimingStatistics {
+    fn get(&self, statistic: TimingStatistic) -> f64 {
+        match statistic {
+            TimingStatistic::Mean => self.mean,
+            TimingStatistic::P95 => self.p95,
+            TimingStatistic::Max => self.max,
+        }
+    }
+}
+
+/// The recent measurements of the time spent running each system and named system set of the main world,
+/// collected by the [`SystemTimingDiagnosticsPlugin`].
+#[derive(Resource, Debug)]
+pub struct SystemTimingStats {
+    entries: HashMap<(InternedScheduleLabel, TimingTarget), TimingHistory>,
+    max_history_length: usize,
+}
+
+#[derive(Debug)]
+struct TimingHistory {
+    /// Durations in milliseconds, oldest first.
+    durations: VecDeque<f64>,
+    paths: [DiagnosticPath; 3],
+}
+
+impl TimingHistory {
+    fn statistics(&self) -> TimingStatistics {
+        let mut sorted: Vec<f64> = self.durations.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let samples = sorted.len();
+        let p95_rank = (samples * 95).div_ceil(100).max(1);
+        TimingStatistics {
+            samples,
+            mean: sorted.iter().sum::<f64>() / samples as f64,
+            p95: sorted[p95_rank - 1],
+            max: sorted[samples - 1],
+        }
+    }
+}
+
+impl SystemTimingStats {
+    fn new(max_history_length: usize) -> Self {
+        Self {
+            entries: HashMap::default(),
+            max_history_length,
+        }
+    }
+
+    /// Returns the statistics of the system or set `target` in the schedule `schedule`, if it ran.
+    pub fn get(
+        &self,
+        schedule: impl ScheduleLabel,
+        target: &TimingTarget,
+    ) -> Option<TimingStatistics> {
+        self.entries
+            .get(&(schedule.intern(), target.clone()))
+            .map(TimingHistory::statistics)
+    }
+
+    /// Iterates over the statistics of every system and set that ran, sorted by schedule, kind and name.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (InternedScheduleLabel, &TimingTarget, TimingStatistics)> {
+        let mut entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|((schedule, target), history)| {
+                let (kind, name) = kind_and_name(target);
+                (
+                    (format!("{schedule:?}"), kind, name),
+                    *schedule,
+                    target,
+                    history,
+                )
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+            .into_iter()
+            .map(|(_, schedule, target, history)| (schedule, target, history.statistics()))
+    }
+
+    /// Exports the statistics to CSV, with a header row and one row per system and set.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("schedule,kind,name,samples,mean_ms,p95_ms,max_ms\n");
+        for (schedule, target, statistics) in self.iter() {
+            let (kind, name) = kind_and_name(target);
+            writeln!(
+                csv,
+                "{},{kind},{},{},{},{},{}",
+                csv_field(&format!("{schedule:?}")),
+                csv_field(&name),
+                statistics.samples,
+                statistics.mean,
+                statistics.p95,
+                statistics.max,
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    /// Exports the statistics to JSON, as an array with one object per system and set.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (i, (schedule, target, statistics)) in self.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let (kind, name) = kind_and_name(target);
+            json.push_str("{\"schedule\":");
+            write_json_string(&mut json, &format!("{schedule:?}"));
+            write!(json, ",\"kind\":\"{kind}\",\"name\":").unwrap();
+            write_json_string(&mut json, &name);
+            write!(
+                json,
+                ",\"samples\":{},\"mean_ms\":{},\"p95_ms\":{},\"max_ms\":{}}}",
+                statistics.samples, statistics.mean, statistics.p95, statistics.max
+            )
+            .unwrap();
+        }
+        json.push(']');
+        json
+    }
+
+    fn record(&mut self, schedule: InternedScheduleLabel, target: TimingTarget, duration: f64) {
+        let max_history_length = self.max_history_length;
+        let history =
+            self.entries
+                .entry((schedule, target))
+                .or_insert_with_key(|(schedule, target)| TimingHistory {
+                    durations: VecDeque::with_capacity(max_history_length),
+                    paths: TimingStatistic::ALL.map(|statistic| {
+                        SystemTimingDiagnosticsPlugin::diagnostic_path(*schedule, target, statistic)
+                    }),
+                });
+        if history.durations.len() == max_history_length {
+            history.durations.pop_front();
+        }
+        history.durations.push_back(duration);
+    }
+}
+
+fn kind_and_name(target: &TimingTarget) -> (&'static str, String) {
+    match target {
+        TimingTarget::System(name) => ("system", name.to_string()),
+        TimingTarget::Set(set) => ("set", format!("{set:?}")),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl Plugin for SystemTimingDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<SystemTimings>()
+            .insert_resource(SystemTimingStats::new(self.max_history_length.max(1)))
+            .add_systems(Last, Self::diagnostic_system);
+
+        #[cfg(feature = "std")]
+        if let Some(dump) = self.dump.clone() {
+            app.add_systems(
+                Last,
+                (move |mut exits: EventReader<AppExit>, stats: Res<SystemTimingStats>| {
+                    if exits.read().count() > 0 {
+                        Self::dump(&stats, &dump);
+                    }
+                })
+                .after(Self::diagnostic_system),
+            );
+        }
+    }
+}
+
+impl SystemTimingDiagnosticsPlugin {
+    /// Returns the path of the [`Diagnostic`] holding the `statistic` of the system or set `target`
+    /// in the schedule `schedule`.
+    ///
+    /// Paths have the form `system_timing/<schedule>/systems/<system name>/<statistic>` for systems and
+    /// `system_timing/<schedule>/sets/<set>/<statistic>` for sets, where `<statistic>` is `mean`, `p95` or
+    /// `max`, and any `/` in the names is replaced with `_`.
+    pub fn diagnostic_path(
+        schedule: impl ScheduleLabel,
+        target: &TimingTarget,
+        statistic: TimingStatistic,
+    ) -> DiagnosticPath {
+        let (kind, name) = match target {
+            TimingTarget::System(name) => ("systems", name.replace('/', "_")),
+            TimingTarget::Set(set) => ("sets", format!("{set:?}").replace('/', "_")),
+        };
+        let schedule = format!("{:?}", schedule.intern()).replace('/', "_");
+        DiagnosticPath::from_components(["system_timing", &schedule, kind, &name, statistic.name()])
+    }
+
+    /// Moves the measurements of [`SystemTimings`] to the [`SystemTimingStats`], and adds the updated
+    /// statistics to the [`DiagnosticsStore`].
+    pub fn diagnostic_system(
+        mut timings: ResMut<SystemTimings>,
+        mut stats: ResMut<SystemTimingStats>,
+        mut store: ResMut<DiagnosticsStore>,
+    ) {
+        let mut updated = <HashSet<_>>::default();
+        for sample in timings.drain() {
+            let key = (sample.schedule, sample.target);
+            updated.insert(key.clone());
+            stats.record(key.0, key.1, sample.duration.as_secs_f64() * 1000.0);
+        }
+
+        let now = Instant::now();
+        for key in updated {
+            let history = &stats.entries[&key];
+            let statistics = history.statistics();
+            for (statistic, path) in TimingStatistic::ALL.into_iter().zip(&history.paths) {
+                if store.get(path).is_none() {
+                    store.add(
+                        Diagnostic::new(path.clone())
+                            .with_suffix("ms")
+                            .with_max_history_length(stats.max_history_length),
+                    );
+                }
+                let diagnostic = store.get_mut(path).unwrap();
+                if diagnostic.is_enabled {
+                    diagnostic.add_measurement(DiagnosticMeasurement {
+                        time: now,
+                        value: statistics.get(statistic),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Writes the `stats` to the file of `dump`, logging an error if it fails.
+    #[cfg(feature = "std")]
+    fn dump(stats: &SystemTimingStats, dump: &SystemTimingDump) {
+        let contents = match dump.format {
+            SystemTimingFormat::Csv => stats.to_csv(),
+            SystemTimingFormat::Json => stats.to_json(),
+        };
+        if let Err(err) = std::fs::write(&dump.path, contents) {
+            log::error!(
+                "Failed to write system timings to {}: {err}",
+                dump.path.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Work;
+
+    fn work() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    #[test]
+    fn system_and_set_timings_are_diagnosed() {
+        let mut app = App::new();
+        app.add_plugins(SystemTimingDiagnosticsPlugin::default())
+            .add_systems(Update, work.in_set(Work));
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let system = TimingTarget::System(core::any::type_name_of_val(&work).into());
+        let set = TimingTarget::Set(Work.intern());
+        let stats = app.world().resource::<SystemTimingStats>();
+        let statistics = stats.get(Update, &system).unwrap();
+        assert_eq!(statistics.samples, 3);
+        assert!(statistics.mean >= 1.0);
+        assert!(statistics.mean <= statistics.p95 && statistics.p95 <= statistics.max);
+        assert_eq!(stats.get(Update, &set).unwrap().samples, 3);
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        for statistic in TimingStatistic::ALL {
+            let path = SystemTimingDiagnosticsPlugin::diagnostic_path(Update, &system, statistic);
+            assert_eq!(
+                store.get(&path).unwrap().value(),
+                Some(statistics.get(statistic))
+            );
+        }
+        let path =
+            SystemTimingDiagnosticsPlugin::diagnostic_path(Update, &set, TimingStatistic::Max);
+        assert_eq!(path.as_str(), "system_timing/Update/sets/Work/max");
+
+        let csv = stats.to_csv();
+        assert!(csv.starts_with("schedule,kind,name,samples,mean_ms,p95_ms,max_ms\n"));
+        assert!(csv.contains("\nUpdate,set,Work,3,"));
+        let json = stats.to_json();
+        assert!(json.contains(
+            "{\"schedule\":\"Update\",\"kind\":\"set\",\"name\":\"Work\",\"samples\":3,"
+        ));
+    }
+
+    #[test]
+    fn statistics_use_recent_measurements() {
+        let mut stats = SystemTimingStats::new(20);
+        let target = TimingTarget::System("system".into());
+        for duration in 0..40 {
+            stats.record(Update.intern(), target.clone(), duration as f64);
+        }
+        let statistics = stats.get(Update, &target).unwrap();
+        assert_eq!(statistics.samples, 20);
+        assert_eq!(statistics.mean, 29.5);
+        assert_eq!(statistics.p95, 38.0);
+        assert_eq!(statistics.max, 39.0);
+    }
+}
@@ -113,6 +113,25 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         .on_remove
         .map(|path| path.to_token_stream(&bevy_ecs_path));
 
+    let many_to_many = attrs
+        .relationship
+        .as_ref()
+        .is_some_and(|relationship| relationship.many_to_many);
+    let many_to_many_target = attrs
+        .relationship_target
+        .as_ref()
+        .is_some_and(|target| target.many_to_many);
+    let relationship_trait = if many_to_many {
+        quote!(#bevy_ecs_path::relationship::ManyToManyRelationship)
+    } else {
+        quote!(#bevy_ecs_path::relationship::Relationship)
+    };
+    let relationship_target_trait = if many_to_many_target {
+        quote!(#bevy_ecs_path::relationship::ManyToManyRelationshipTarget)
+    } else {
+        quote!(#bevy_ecs_path::relationship::RelationshipTarget)
+    };
+
     let on_insert_path = if relationship.is_some() {
         if attrs.on_insert.is_some() {
             return syn::Error::new(
@@ -123,7 +142,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert))
+        Some(quote!(<Self as #relationship_trait>::on_insert))
     } else {
         attrs
             .on_insert
@@ -140,7 +159,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace))
+        Some(quote!(<Self as #relationship_trait>::on_replace))
     } else if attrs.relationship_target.is_some() {
         if attrs.on_replace.is_some() {
             return syn::Error::new(
@@ -151,7 +170,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             .into();
         }
 
-        Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace))
+        Some(quote!(<Self as #relationship_target_trait>::on_replace))
     } else {
         attrs
             .on_replace
@@ -245,11 +264,13 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         }
     });
 
-    let mutable_type = (attrs.immutable || relationship.is_some())
+    let mutable_type = (attrs.immutable || relationship.is_some() || many_to_many_target)
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
-    let clone_behavior = if relationship_target.is_some() {
+    let clone_behavior = if many_to_many_target {
+        quote!(#bevy_ecs_path::component::ComponentCloneBehavior::Ignore)
+    } else if relationship_target.is_some() {
         quote!(#bevy_ecs_path::component::ComponentCloneBehavior::Custom(#bevy_ecs_path::relationship::clone_relationship_target::<Self>))
     } else {
         quote!(
@@ -473,11 +494,13 @@ struct Require {
 
 struct Relationship {
     relationship_target: Type,
+    many_to_many: bool,
 }
 
 struct RelationshipTarget {
     relationship: Type,
     linked_spawn: bool,
+    many_to_many: bool,
 }
 
 // values for `storage` attribute
@@ -654,14 +677,35 @@ mod kw {
     syn::custom_keyword!(relationship_target);
     syn::custom_keyword!(relationship);
     syn::custom_keyword!(linked_spawn);
+    syn::custom_keyword!(many_to_many);
 }
 
 impl Parse for Relationship {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        input.parse::<kw::relationship_target>()?;
-        input.parse::<Token![=]>()?;
+        let mut relationship_target: Option<Type> = None;
+        let mut many_to_many: bool = false;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::many_to_many) {
+                input.parse::<kw::many_to_many>()?;
+                many_to_many = true;
+            } else if lookahead.peek(kw::relationship_target) {
+                input.parse::<kw::relationship_target>()?;
+                input.parse::<Token![=]>()?;
+                relationship_target = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
         Ok(Relationship {
-            relationship_target: input.parse::<Type>()?,
+            relationship_target: relationship_target.ok_or_else(|| {
+                syn::Error::new(input.span(), "Missing `relationship_target = X` attribute")
+            })?,
+            many_to_many,
         })
     }
 }
@@ -670,12 +714,16 @@ impl Parse for RelationshipTarget {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
         let mut relationship: Option<Type> = None;
         let mut linked_spawn: bool = false;
+        let mut many_to_many: bool = false;
 
         while !input.is_empty() {
             let lookahead = input.lookahead1();
             if lookahead.peek(kw::linked_spawn) {
                 input.parse::<kw::linked_spawn>()?;
                 linked_spawn = true;
+            } else if lookahead.peek(kw::many_to_many) {
+                input.parse::<kw::many_to_many>()?;
+                many_to_many = true;
             } else if lookahead.peek(kw::relationship) {
                 input.parse::<kw::relationship>()?;
                 input.parse::<Token![=]>()?;
@@ -687,11 +735,18 @@ impl Parse for RelationshipTarget {
                 input.parse::<Token![,]>()?;
             }
         }
+        if linked_spawn && many_to_many {
+            return Err(syn::Error::new(
+                input.span(),
+                "`linked_spawn` is not supported by many-to-many relationships",
+            ));
+        }
         Ok(RelationshipTarget {
             relationship: relationship.ok_or_else(|| {
                 syn::Error::new(input.span(), "Missing `relationship = X` attribute")
             })?,
             linked_spawn,
+            many_to_many,
         })
     }
 }
@@ -727,6 +782,37 @@ fn derive_relationship(
 
     let relationship_target = &relationship.relationship_target;
 
+    if relationship.many_to_many {
+        if field.vis != Visibility::Inherited {
+            return Err(syn::Error::new(field.span(), "The collection in a many-to-many Relationship must be private to prevent users from directly mutating it, which could invalidate the correctness of relationships."));
+        }
+        let collection = &field.ty;
+        return Ok(Some(quote! {
+            impl #impl_generics #bevy_ecs_path::relationship::ManyToManyRelationship for #struct_name #type_generics #where_clause {
+                type RelationshipTarget = #relationship_target;
+                type Collection = #collection;
+
+                #[inline]
+                fn collection(&self) -> &Self::Collection {
+                    &self.#relationship_member
+                }
+
+                #[inline]
+                fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                    &mut self.#relationship_member
+                }
+
+                #[inline]
+                fn from_collection_risky(collection: Self::Collection) -> Self {
+                    Self {
+                        #(#members: core::default::Default::default(),)*
+                        #relationship_member: collection
+                    }
+                }
+            }
+        }));
+    }
+
     Ok(Some(quote! {
         impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
             type RelationshipTarget = #relationship_target;
@@ -783,6 +869,34 @@ fn derive_relationship_target(
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
     let linked_spawn = relationship_target.linked_spawn;
+
+    if relationship_target.many_to_many {
+        return Ok(Some(quote! {
+            impl #impl_generics #bevy_ecs_path::relationship::ManyToManyRelationshipTarget for #struct_name #type_generics #where_clause {
+                type Relationship = #relationship;
+                type Collection = #collection;
+
+                #[inline]
+                fn collection(&self) -> &Self::Collection {
+                    &self.#relationship_member
+                }
+
+                #[inline]
+                fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                    &mut self.#relationship_member
+                }
+
+                #[inline]
+                fn from_collection_risky(collection: Self::Collection) -> Self {
+                    Self {
+                        #(#members: core::default::Default::default(),)*
+                        #relationship_member: collection
+                    }
+                }
+            }
+        }));
+    }
+
     Ok(Some(quote! {
         impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
             const LINKED_SPAWN: bool = #linked_spawn;
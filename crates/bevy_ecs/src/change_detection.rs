@@ -136,6 +136,9 @@ pub trait DetectChangesMut: DetectChanges {
     /// This is a complex and error-prone operation, primarily intended for use with rollback networking strategies.
     /// If you merely want to flag this data as changed, use [`set_changed`](DetectChangesMut::set_changed) instead.
     /// If you want to avoid triggering change detection, use [`bypass_change_detection`](DetectChangesMut::bypass_change_detection) instead.
+    ///
+    /// Setting a tick more recent than the one at which this value was accessed may cause
+    /// [`Changed`](crate::query::Changed) filters to miss the change, as they skip tables without recent changes.
     fn set_last_changed(&mut self, last_changed: Tick);
 
     /// Manually sets the added tick recording the time when this data was last added.
@@ -1529,6 +1532,7 @@ impl MaybeLocation {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use bevy_ecs_macros::Resource;
     use bevy_ptr::PtrMut;
     use bevy_reflect::{FromType, ReflectFromPtr};
@@ -1540,7 +1544,9 @@ mod tests {
             MAX_CHANGE_AGE,
         },
         component::{Component, ComponentTicks, Tick},
-        system::{IntoSystem, Single, System},
+        entity::Entity,
+        query::{Added, Changed},
+        system::{IntoSystem, Query, Single, System},
         world::World,
     };
 
@@ -1885,4 +1891,72 @@ mod tests {
         assert_eq!(3, into_mut.ticks.last_run.get());
         assert_eq!(4, into_mut.ticks.this_run.get());
     }
+
+    #[derive(Component)]
+    struct Marker;
+
+    fn max_changed_tick(world: &World, entity: Entity) -> Tick {
+        let table_id = world.entity(entity).location().table_id;
+        let component_id = world.component_id::<C>().unwrap();
+        world
+            .storages()
+            .tables
+            .get(table_id)
+            .unwrap()
+            .get_max_changed_tick(component_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn change_filters_skip_unchanged_tables() {
+        let mut world = World::new();
+        let a = world.spawn(C).id();
+        let b = world.spawn((C, Marker)).id();
+
+        let mut changed = IntoSystem::into_system(|query: Query<Entity, Changed<C>>| {
+            query.iter().collect::<Vec<_>>()
+        });
+        changed.initialize(&mut world);
+        assert_eq!(changed.run((), &mut world).len(), 2);
+        assert!(changed.run((), &mut world).is_empty());
+
+        let mut added =
+            IntoSystem::into_system(|mut query: Query<&mut C, Added<C>>| query.iter_mut().count());
+        added.initialize(&mut world);
+        assert_eq!(added.run((), &mut world), 2);
+        let marked = max_changed_tick(&world, a);
+
+        // Skipped tables are not marked as changed by the mutable access of the query.
+        assert_eq!(added.run((), &mut world), 0);
+        assert_eq!(max_changed_tick(&world, a), marked);
+        // The components were not mutably dereferenced, so they are not changed.
+        assert!(changed.run((), &mut world).is_empty());
+
+        world.get_mut::<C>(b).unwrap().set_changed();
+        assert_eq!(max_changed_tick(&world, a), marked);
+        assert_eq!(changed.run((), &mut world), [b]);
+    }
+
+    #[test]
+    fn change_filters_track_table_moves() {
+        let mut world = World::new();
+        let a = world.spawn(C).id();
+        world.spawn((C, Marker));
+
+        let mut changed = IntoSystem::into_system(|query: Query<Entity, Changed<C>>| {
+            query.iter().collect::<Vec<_>>()
+        });
+        changed.initialize(&mut world);
+        assert_eq!(changed.run((), &mut world).len(), 2);
+
+        world.get_mut::<C>(a).unwrap().set_changed();
+        // Move `a` to the table with `Marker`, which doesn't have recent changes.
+        world.entity_mut(a).insert(Marker);
+        assert_eq!(changed.run((), &mut world), [a]);
+
+        world.get_mut::<C>(a).unwrap().set_changed();
+        world.entity_mut(a).remove::<Marker>();
+        assert_eq!(changed.run((), &mut world), [a]);
+        assert!(changed.run((), &mut world).is_empty());
+    }
 }
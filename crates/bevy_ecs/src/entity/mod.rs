@@ -83,7 +83,7 @@ use crate::{
     storage::{SparseSetIndex, TableId, TableRow},
 };
 use alloc::vec::Vec;
-use bevy_platform::sync::atomic::Ordering;
+use bevy_platform::{collections::HashSet, sync::atomic::Ordering};
 use core::{fmt, hash::Hash, mem, num::NonZero, panic::Location};
 use log::warn;
 
@@ -771,6 +771,43 @@ impl Entities {
         result
     }
 
+    /// Allocate the given entity IDs, overwriting their generation.
+    ///
+    /// Unlike [`alloc_at`](Self::alloc_at), the freelist is searched once for all of them, so
+    /// claiming many entities stays linear in the number of freed entities.
+    ///
+    /// The entities must not be in use, or share the same index.
+    pub(crate) fn alloc_many_at(&mut self, entities: &[Entity]) {
+        self.verify_flushed();
+
+        if let Some(max_index) = entities.iter().map(|entity| entity.index()).max() {
+            if max_index as usize >= self.meta.len() {
+                self.pending.extend((self.meta.len() as u32)..=max_index);
+                self.meta.resize(max_index as usize + 1, EntityMeta::EMPTY);
+            }
+        }
+
+        let indices: HashSet<u32> = entities.iter().map(|entity| entity.index()).collect();
+        debug_assert_eq!(
+            indices.len(),
+            entities.len(),
+            "entities must not share an index"
+        );
+        self.pending.retain(|index| !indices.contains(index));
+        let new_free_cursor = self.pending.len() as IdCursor;
+        *self.free_cursor.get_mut() = new_free_cursor;
+
+        for entity in entities {
+            let meta = &mut self.meta[entity.index() as usize];
+            debug_assert_eq!(
+                meta.location.archetype_id,
+                ArchetypeId::INVALID,
+                "entity {entity} is in use"
+            );
+            meta.generation = entity.generation;
+        }
+    }
+
     /// Destroy an entity, allowing it to be reused.
     ///
     /// Must not be called while reserved entities are awaiting `flush()`.
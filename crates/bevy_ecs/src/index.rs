@@ -0,0 +1,372 @@
+//! Opt-in indexes mapping the values of immutable components to the entities holding them.
+//!
+//! Finding "all entities with `Team(3)`" with a [`Query`] requires scanning every entity with a `Team`.
+//! [`World::add_index`] instead maintains a [`ComponentIndex`] resource, mapping each value of the
+//! component to the entities it is inserted on, so that these lookups take constant time.
+//! The [`QueryByIndex`] system parameter combines that index with a [`Query`].
+//!
+//! Only [immutable](crate::component::Immutable) components can be indexed: their value can only change
+//! by inserting them again, which the index observes.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::index::QueryByIndex;
+//! #[derive(Component, Clone, PartialEq, Eq, Hash)]
+//! #[component(immutable)]
+//! struct Team(u32);
+//!
+//! #[derive(Component)]
+//! struct Health(u32);
+//!
+//! fn heal_team(mut query: QueryByIndex<Team, &mut Health>) {
+//!     for mut health in query.iter_mut(&Team(3)) {
+//!         health.0 += 10;
+//!     }
+//! }
+//!
+//! let mut world = World::new();
+//! world.add_index::<Team>();
+//! let ally = world.spawn((Team(3), Health(50))).id();
+//! let enemy = world.spawn((Team(4), Health(50))).id();
+//!
+//! world.run_system_cached(heal_team).unwrap();
+//! assert_eq!(world.get::<Health>(ally).unwrap().0, 60);
+//! assert_eq!(world.get::<Health>(enemy).unwrap().0, 50);
+//! ```
+
+use crate::{
+    archetype::ArchetypeEntity,
+    component::{Component, Immutable},
+    entity::{Entity, EntityHashSet},
+    observer::Trigger,
+    query::{QueryData, QueryFilter, QuerySingleError, ROQueryItem},
+    resource::Resource,
+    system::{Query, Res, SystemParam},
+    world::{DeferredWorld, OnInsert, OnReplace, World},
+};
+use alloc::vec::Vec;
+use bevy_platform::collections::HashMap;
+use core::hash::Hash;
+
+/// A [`Resource`] mapping each value of the component `C` to the entities it is inserted on.
+///
+/// It is added and kept up to date by [`World::add_index`].
+#[derive(Resource)]
+pub struct ComponentIndex<C: Component<Mutability = Immutable> + Eq + Hash + Clone> {
+    entities: HashMap<C, EntityHashSet>,
+    empty: EntityHashSet,
+}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities: HashMap::default(),
+            empty: EntityHashSet::new(),
+        }
+    }
+}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> ComponentIndex<C> {
+    /// Returns the set of entities whose `C` component is equal to `value`.
+    pub fn get(&self, value: &C) -> &EntityHashSet {
+        self.entities.get(value).unwrap_or(&self.empty)
+    }
+
+    /// Returns `true` if at least one entity has a `C` component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.entities.contains_key(value)
+    }
+
+    /// Iterates over the distinct values of `C` and the entities holding them.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, &EntityHashSet)> {
+        self.entities.iter()
+    }
+
+    /// Returns the number of distinct values of `C`.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entity has a `C` component.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.entities.entry(value).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(value);
+            }
+        }
+    }
+}
+
+impl World {
+    /// Starts maintaining a [`ComponentIndex`] of the component `C`, which can then be queried with
+    /// [`QueryByIndex`].
+    ///
+    /// Entities that already have a `C` component are indexed immediately, including the ones excluded by
+    /// default query filters such as [`Disabled`](crate::entity_disabling::Disabled) entities. The index is then
+    /// updated by observers of [`OnInsert`] and [`OnReplace`], which run as soon as a command inserting, removing
+    /// or despawning is applied. Calling this again for the same component does nothing.
+    pub fn add_index<C: Component<Mutability = Immutable> + Eq + Hash + Clone>(
+        &mut self,
+    ) -> &mut Self {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return self;
+        }
+
+        let mut index = ComponentIndex::<C>::default();
+        let component_id = self.register_component::<C>();
+        let entities: Vec<Entity> = self
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect();
+        for entity in entities {
+            index.insert(self.get::<C>(entity).unwrap().clone(), entity);
+        }
+        self.insert_resource(index);
+
+        // The component is read from the world rather than with a query, so that entities excluded by
+        // default query filters are indexed too.
+        self.add_observer(|trigger: Trigger<OnInsert, C>, mut world: DeferredWorld| {
+            if let Some(value) = world.get::<C>(trigger.target()).cloned() {
+                world
+                    .resource_mut::<ComponentIndex<C>>()
+                    .insert(value, trigger.target());
+            }
+        });
+        self.add_observer(|trigger: Trigger<OnReplace, C>, mut world: DeferredWorld| {
+            if let Some(value) = world.get::<C>(trigger.target()).cloned() {
+                world
+                    .resource_mut::<ComponentIndex<C>>()
+                    .remove(&value, trigger.target());
+            }
+        });
+        self
+    }
+}
+
+/// A [`SystemParam`] that looks up the entities of a [`Query`] by the value of their `C` component,
+/// using the [`ComponentIndex`] added with [`World::add_index`].
+///
+/// # Panics
+///
+/// Systems using this parameter fail validation if `C` has not been indexed.
+#[derive(SystemParam)]
+pub struct QueryByIndex<
+    'w,
+    's,
+    C: Component<Mutability = Immutable> + Eq + Hash + Clone,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static = (),
+> {
+    query: Query<'w, 's, D, F>,
+    index: Res<'w, ComponentIndex<C>>,
+}
+
+impl<'w, 's, C, D, F> QueryByIndex<'w, 's, C, D, F>
+where
+    C: Component<Mutability = Immutable> + Eq + Hash + Clone,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static,
+{
+    /// Returns the underlying [`Query`].
+    pub fn query(&self) -> &Query<'w, 's, D, F> {
+        &self.query
+    }
+
+    /// Returns the underlying [`ComponentIndex`].
+    pub fn index(&self) -> &ComponentIndex<C> {
+        &self.index
+    }
+
+    /// Iterates over the entities whose `C` component is equal to `value`, including the ones that don't
+    /// match the query.
+    pub fn entities(&self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.index.get(value).iter().copied()
+    }
+
+    /// Returns an [`Iterator`] over the read-only query items of the entities whose `C` component
+    /// is equal to `value`.
+    ///
+    /// Entities that don't match the query are skipped.
+    pub fn iter(&self, value: &C) -> impl Iterator<Item = ROQueryItem<'_, D>> {
+        self.query.iter_many_unique(self.index.get(value))
+    }
+
+    /// Returns an [`Iterator`] over the query items of the entities whose `C` component
+    /// is equal to `value`.
+    ///
+    /// Entities that don't match the query are skipped.
+    pub fn iter_mut(&mut self, value: &C) -> impl Iterator<Item = D::Item<'_>> {
+        self.query.iter_many_unique_mut(self.index.get(value))
+    }
+
+    /// Returns the read-only query item of the only entity matching the query whose `C` component
+    /// is equal to `value`.
+    ///
+    /// Returns a [`QuerySingleError`] if there is no such entity, or more than one.
+    pub fn single(&self, value: &C) -> Result<ROQueryItem<'_, D>, QuerySingleError> {
+        let mut items = self.iter(value);
+        let item = items
+            .next()
+            .ok_or(QuerySingleError::NoEntities(core::any::type_name::<Self>()))?;
+        if items.next().is_some() {
+            return Err(QuerySingleError::MultipleEntities(core::any::type_name::<
+                Self,
+            >()));
+        }
+        Ok(item)
+    }
+
+    /// Returns the query item of the only entity matching the query whose `C` component
+    /// is equal to `value`.
+    ///
+    /// Returns a [`QuerySingleError`] if there is no such entity, or more than one.
+    pub fn single_mut(&mut self, value: &C) -> Result<D::Item<'_>, QuerySingleError> {
+        let mut items = self.iter_mut(value);
+        let item = items
+            .next()
+            .ok_or(QuerySingleError::NoEntities(core::any::type_name::<Self>()))?;
+        if items.next().is_some() {
+            return Err(QuerySingleError::MultipleEntities(core::any::type_name::<
+                Self,
+            >()));
+        }
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentIndex, QueryByIndex};
+    use crate::{
+        component::Component,
+        entity::Entity,
+        entity_disabling::Disabled,
+        query::{QuerySingleError, With},
+        system::RunSystemOnce,
+        world::World,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component, Clone, PartialEq, Eq, Hash, Debug)]
+    #[component(immutable)]
+    struct Team(u32);
+
+    #[derive(Component)]
+    struct Leader;
+
+    fn sorted(entities: impl Iterator<Item = Entity>) -> Vec<Entity> {
+        let mut entities: Vec<_> = entities.collect();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn index_tracks_inserts_replacements_and_despawns() {
+        let mut world = World::new();
+        let a = world.spawn(Team(1)).id();
+        world.add_index::<Team>();
+        let b = world.spawn(Team(1)).id();
+        let c = world.spawn(Team(2)).id();
+
+        let index = world.resource::<ComponentIndex<Team>>();
+        assert_eq!(sorted(index.get(&Team(1)).iter().copied()), [a, b]);
+        assert_eq!(sorted(index.get(&Team(2)).iter().copied()), [c]);
+        assert_eq!(index.len(), 2);
+
+        world.entity_mut(b).insert(Team(2));
+        world.entity_mut(a).remove::<Team>();
+        world.despawn(c);
+
+        let index = world.resource::<ComponentIndex<Team>>();
+        assert!(!index.contains(&Team(1)));
+        assert!(index.get(&Team(1)).is_empty());
+        assert_eq!(sorted(index.get(&Team(2)).iter().copied()), [b]);
+    }
+
+    #[test]
+    fn index_is_consistent_with_commands() {
+        let mut world = World::new();
+        world.add_index::<Team>();
+        let a = world.commands().spawn(Team(3)).id();
+        world.flush();
+        assert_eq!(
+            sorted(
+                world
+                    .resource::<ComponentIndex<Team>>()
+                    .get(&Team(3))
+                    .iter()
+                    .copied()
+            ),
+            [a]
+        );
+
+        world.commands().entity(a).despawn();
+        world.flush();
+        assert!(world.resource::<ComponentIndex<Team>>().is_empty());
+    }
+
+    #[test]
+    fn index_tracks_disabled_entities() {
+        let mut world = World::new();
+        let a = world.spawn((Team(1), Disabled)).id();
+        world.add_index::<Team>();
+        let b = world.spawn(Team(1)).id();
+        world.entity_mut(b).insert(Disabled);
+
+        let index = world.resource::<ComponentIndex<Team>>();
+        assert_eq!(sorted(index.get(&Team(1)).iter().copied()), [a, b]);
+
+        world.entity_mut(a).insert(Team(2));
+        world.entity_mut(b).remove::<Team>();
+        let index = world.resource::<ComponentIndex<Team>>();
+        assert!(!index.contains(&Team(1)));
+        assert_eq!(sorted(index.get(&Team(2)).iter().copied()), [a]);
+
+        world.despawn(a);
+        assert!(world.resource::<ComponentIndex<Team>>().is_empty());
+    }
+
+    #[test]
+    fn query_by_index() {
+        let mut world = World::new();
+        world.add_index::<Team>();
+        let leader = world.spawn((Team(1), Leader)).id();
+        let member = world.spawn(Team(1)).id();
+        world.spawn(Team(2));
+
+        world
+            .run_system_once(move |query: QueryByIndex<Team, Entity>| {
+                assert_eq!(sorted(query.iter(&Team(1))), [leader, member]);
+                assert_eq!(sorted(query.entities(&Team(1))), [leader, member]);
+                assert_eq!(query.iter(&Team(5)).count(), 0);
+                assert!(matches!(
+                    query.single(&Team(1)),
+                    Err(QuerySingleError::MultipleEntities(_))
+                ));
+                assert!(matches!(
+                    query.single(&Team(5)),
+                    Err(QuerySingleError::NoEntities(_))
+                ));
+            })
+            .unwrap();
+
+        world
+            .run_system_once(move |mut query: QueryByIndex<Team, Entity, With<Leader>>| {
+                assert_eq!(query.single(&Team(1)).unwrap(), leader);
+                assert_eq!(query.single_mut(&Team(1)).unwrap(), leader);
+                assert_eq!(sorted(query.entities(&Team(1))), [leader, member]);
+            })
+            .unwrap();
+    }
+}
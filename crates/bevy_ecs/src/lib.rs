@@ -40,6 +40,7 @@ pub mod error;
 pub mod event;
 pub mod hierarchy;
 pub mod identifier;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod name;
@@ -52,10 +53,13 @@ pub mod relationship;
 pub mod removal_detection;
 pub mod resource;
 pub mod schedule;
+pub mod snapshot;
 pub mod spawn;
 pub mod storage;
 pub mod system;
 pub mod traversal;
+#[cfg(feature = "bevy_reflect")]
+pub mod undo;
 pub mod world;
 
 pub use bevy_ptr as ptr;
@@ -109,7 +113,7 @@ pub mod prelude {
     #[doc(hidden)]
     #[cfg(feature = "bevy_reflect")]
     pub use crate::reflect::{
-        AppTypeRegistry, ReflectComponent, ReflectFromWorld, ReflectResource,
+        AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectFromWorld, ReflectResource,
     };
 
     #[doc(hidden)]
@@ -20,6 +20,7 @@ use alloc::vec::Vec;
 use bevy_platform::collections::HashMap;
 use bevy_ptr::Ptr;
 use core::{
+    cmp::Reverse,
     fmt::Debug,
     marker::PhantomData,
     ops::{Deref, DerefMut},
@@ -129,7 +130,13 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     ///
     /// You can prevent an event from propagating further using `propagate(false)`.
     ///
+    /// Like DOM events, propagated events go through up to three [phases](PropagationPhase): observers added with
+    /// [`Observer::capture`] run first, from the far end of the path down to the target, then the target's observers run,
+    /// then the other observers run along the path. Traversals such as [`RelationshipTargetTraversal`] can branch out,
+    /// in which case the event propagates to every branch.
+    ///
     /// [`Traversal`]: crate::traversal::Traversal
+    /// [`RelationshipTargetTraversal`]: crate::traversal::RelationshipTargetTraversal
     pub fn propagate(&mut self, should_propagate: bool) {
         *self.propagate = should_propagate;
     }
@@ -141,6 +148,14 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
         *self.propagate
     }
 
+    /// Returns the [`PropagationPhase`] during which this observer runs.
+    ///
+    /// Calling `propagate(false)` during the [`Capture`](PropagationPhase::Capture) phase stops the event
+    /// before it reaches its target, which lets observers closer to the root intercept it.
+    pub fn phase(&self) -> PropagationPhase {
+        self.trigger.phase
+    }
+
     /// Returns the source code location that triggered this observer.
     pub fn caller(&self) -> MaybeLocation {
         self.trigger.caller
@@ -280,6 +295,27 @@ all_tuples!(
     T
 );
 
+/// The phase of the propagation of an [`Event`] during which an [`Observer`] runs.
+///
+/// See [`Trigger::propagate`] for how events propagate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PropagationPhase {
+    /// The event travels along its propagation path in reverse, from the farthest entity towards its target.
+    ///
+    /// Only observers added with [`Observer::capture`] run during this phase.
+    Capture,
+    /// The event reached the entity it was triggered for.
+    ///
+    /// All of the observers of that entity run during this phase, as well as the observers of events that
+    /// don't target any entity.
+    #[default]
+    Target,
+    /// The event travels along its propagation path, away from its target.
+    ///
+    /// Observers that were not added with [`Observer::capture`] run during this phase.
+    Bubble,
+}
+
 /// A description of what an [`Observer`] observes.
 #[derive(Default, Clone)]
 pub struct ObserverDescriptor {
@@ -291,6 +327,12 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// Whether the observer runs during the capture phase instead of the bubble phase.
+    capture: bool,
+
+    /// The priority of the observer over the other observers of the same trigger.
+    priority: i32,
 }
 
 impl ObserverDescriptor {
@@ -315,11 +357,26 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Set whether the observer runs during the [capture phase](PropagationPhase::Capture)
+    /// of propagated events, instead of their [bubble phase](PropagationPhase::Bubble).
+    pub fn with_capture(mut self, capture: bool) -> Self {
+        self.capture = capture;
+        self
+    }
+
+    /// Set the priority of the observer. Observers with a higher priority run first.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        self.capture |= descriptor.capture;
+        self.priority = descriptor.priority;
     }
 
     /// Returns the `events` that the observer is watching.
@@ -336,6 +393,21 @@ impl ObserverDescriptor {
     pub fn entities(&self) -> &[Entity] {
         &self.entities
     }
+
+    /// Returns whether the observer runs during the capture phase of propagated events.
+    pub fn capture(&self) -> bool {
+        self.capture
+    }
+
+    /// Returns the priority of the observer.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns whether the observer must be sorted among the other observers of the same trigger.
+    fn is_ordered(&self) -> bool {
+        self.capture || self.priority != 0
+    }
 }
 
 /// Event trigger metadata for a given [`Observer`],
@@ -349,6 +421,8 @@ pub struct ObserverTrigger {
     components: SmallVec<[ComponentId; 2]>,
     /// The entity the trigger targeted.
     pub target: Entity,
+    /// The phase of the propagation of the event.
+    pub phase: PropagationPhase,
     /// The location of the source code that triggered the obserer.
     pub caller: MaybeLocation,
 }
@@ -360,8 +434,26 @@ impl ObserverTrigger {
     }
 }
 
+/// An [`ObserverRunner`] along with the settings deciding when it runs.
+#[derive(Clone, Copy, Debug)]
+struct CachedObserver {
+    runner: ObserverRunner,
+    capture: bool,
+    priority: i32,
+}
+
+impl CachedObserver {
+    fn new(state: &ObserverState) -> Self {
+        Self {
+            runner: state.runner,
+            capture: state.descriptor.capture,
+            priority: state.descriptor.priority,
+        }
+    }
+}
+
 // Map between an observer entity and its runner
-type ObserverMap = EntityHashMap<ObserverRunner>;
+type ObserverMap = EntityHashMap<CachedObserver>;
 
 /// Collection of [`ObserverRunner`] for [`Observer`] registered to a particular trigger targeted at a specific component.
 #[derive(Default, Debug)]
@@ -381,6 +473,48 @@ pub struct CachedObservers {
     component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     entity_observers: EntityHashMap<ObserverMap>,
+    // Number of observers which run during the capture phase or have a priority
+    ordered: usize,
+    // Number of observers which run during the capture phase
+    capture: usize,
+}
+
+impl CachedObservers {
+    /// Returns whether any of these observers runs during the [capture phase](PropagationPhase::Capture).
+    pub(crate) fn has_capture(&self) -> bool {
+        self.capture > 0
+    }
+
+    /// Calls `f` on each observer that runs for a trigger targeting the given `target` and `components`.
+    fn for_each<'a>(
+        &'a self,
+        target: Entity,
+        components: impl Iterator<Item = ComponentId>,
+        mut f: impl FnMut((&'a Entity, &'a CachedObserver)),
+    ) {
+        // Trigger observers listening for any kind of this trigger
+        self.map.iter().for_each(&mut f);
+
+        // Trigger entity observers listening for this kind of trigger
+        if target != Entity::PLACEHOLDER {
+            if let Some(map) = self.entity_observers.get(&target) {
+                map.iter().for_each(&mut f);
+            }
+        }
+
+        // Trigger observers listening to this trigger targeting a specific component
+        components.for_each(|id| {
+            if let Some(component_observers) = self.component_observers.get(&id) {
+                component_observers.map.iter().for_each(&mut f);
+
+                if target != Entity::PLACEHOLDER {
+                    if let Some(map) = component_observers.entity_map.get(&target) {
+                        map.iter().for_each(&mut f);
+                    }
+                }
+            }
+        });
+    }
 }
 
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
@@ -419,7 +553,8 @@ impl Observers {
         }
     }
 
-    /// This will run the observers of the given `event_type`, targeting the given `entity` and `components`.
+    /// This will run the observers of the given `event_type`, targeting the given `entity` and `components`,
+    /// which run during the given propagation `phase`.
     pub(crate) fn invoke<T>(
         mut world: DeferredWorld,
         event_type: ComponentId,
@@ -427,6 +562,7 @@ impl Observers {
         components: impl Iterator<Item = ComponentId> + Clone,
         data: &mut T,
         propagate: &mut bool,
+        phase: PropagationPhase,
         caller: MaybeLocation,
     ) {
         // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
@@ -444,45 +580,45 @@ impl Observers {
 
         let trigger_for_components = components.clone();
 
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
-            (runner)(
+        let trigger_observer = |(&observer, cached): (&Entity, &CachedObserver)| {
+            (cached.runner)(
                 world.reborrow(),
                 ObserverTrigger {
                     observer,
                     event_type,
                     components: components.clone().collect(),
                     target,
+                    phase,
                     caller,
                 },
                 data.into(),
                 propagate,
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
 
-        // Trigger entity observers listening for this kind of trigger
-        if target != Entity::PLACEHOLDER {
-            if let Some(map) = observers.entity_observers.get(&target) {
-                map.iter().for_each(&mut trigger_observer);
+        if observers.ordered == 0 {
+            // No observer runs during the capture phase, and they all have the same priority.
+            if phase != PropagationPhase::Capture {
+                observers.for_each(target, trigger_for_components, trigger_observer);
             }
+            return;
         }
 
-        // Trigger observers listening to this trigger targeting a specific component
-        trigger_for_components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
-
-                if target != Entity::PLACEHOLDER {
-                    if let Some(map) = component_observers.entity_map.get(&target) {
-                        map.iter().for_each(&mut trigger_observer);
-                    }
-                }
+        let mut ordered: SmallVec<[(&Entity, &CachedObserver); 8]> = SmallVec::new();
+        observers.for_each(target, trigger_for_components, |observer| {
+            let runs = match phase {
+                PropagationPhase::Capture => observer.1.capture,
+                PropagationPhase::Target => true,
+                PropagationPhase::Bubble => !observer.1.capture,
+            };
+            if runs {
+                ordered.push(observer);
             }
         });
+        // Capture observers run before the others at the target, like they would have
+        // if the event had propagated past it.
+        ordered.sort_by_key(|(_, cached)| (!cached.capture, Reverse(cached.priority)));
+        ordered.into_iter().for_each(trigger_observer);
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -735,17 +871,20 @@ impl World {
             (&*observer_state, &mut self.archetypes, &mut self.observers)
         };
         let descriptor = &observer_state.descriptor;
+        let cached = CachedObserver::new(observer_state);
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.ordered += usize::from(descriptor.is_ordered());
+            cache.capture += usize::from(descriptor.capture);
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
-                cache.map.insert(observer_entity, observer_state.runner);
+                cache.map.insert(observer_entity, cached);
             } else if descriptor.components.is_empty() {
                 // Observer is not targeting any components so register it as an entity observer
                 for &watched_entity in &observer_state.descriptor.entities {
                     let map = cache.entity_observers.entry(watched_entity).or_default();
-                    map.insert(observer_entity, observer_state.runner);
+                    map.insert(observer_entity, cached);
                 }
             } else {
                 // Register observer for each watched component
@@ -762,12 +901,12 @@ impl World {
                             });
                     if descriptor.entities.is_empty() {
                         // Register for all triggers targeting the component
-                        observers.map.insert(observer_entity, observer_state.runner);
+                        observers.map.insert(observer_entity, cached);
                     } else {
                         // Register for each watched entity
                         for &watched_entity in &descriptor.entities {
                             let map = observers.entity_map.entry(watched_entity).or_default();
-                            map.insert(observer_entity, observer_state.runner);
+                            map.insert(observer_entity, cached);
                         }
                     }
                 }
@@ -782,6 +921,8 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.ordered -= usize::from(descriptor.is_ordered());
+            cache.capture -= usize::from(descriptor.capture);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -849,9 +990,10 @@ mod tests {
     use crate::component::ComponentId;
     use crate::{
         change_detection::MaybeLocation,
-        observer::{Observer, ObserverDescriptor, ObserverState, OnReplace},
+        hierarchy::Children,
+        observer::{Observer, ObserverDescriptor, ObserverState, OnReplace, PropagationPhase},
         prelude::*,
-        traversal::Traversal,
+        traversal::{RelationshipTargetTraversal, Traversal},
     };
 
     #[derive(Component)]
@@ -898,6 +1040,10 @@ mod tests {
     #[event(traversal = &'static ChildOf, auto_propagate)]
     struct EventPropagating;
 
+    #[derive(Event)]
+    #[event(traversal = RelationshipTargetTraversal<Children>, auto_propagate)]
+    struct EventBroadcast;
+
     #[test]
     fn observer_order_spawn_despawn() {
         let mut world = World::new();
@@ -1535,6 +1681,149 @@ mod tests {
         );
     }
 
+    #[test]
+    fn observer_propagating_capture() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let grandparent = world.spawn_empty().id();
+        let parent = world.spawn(ChildOf(grandparent)).id();
+        let child = world.spawn(ChildOf(parent)).id();
+        for (entity, name) in [
+            (grandparent, "grandparent"),
+            (parent, "parent"),
+            (child, "child"),
+        ] {
+            world.spawn(
+                Observer::new(
+                    move |trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                        assert_ne!(trigger.phase(), PropagationPhase::Capture);
+                        res.observed(name);
+                    },
+                )
+                .with_entity(entity),
+            );
+            world.spawn(
+                Observer::new(
+                    move |trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                        assert_eq!(
+                            trigger.phase() == PropagationPhase::Target,
+                            trigger.target() == child
+                        );
+                        res.observed(match name {
+                            "grandparent" => "grandparent capture",
+                            "parent" => "parent capture",
+                            _ => "child capture",
+                        });
+                    },
+                )
+                .with_entity(entity)
+                .capture(),
+            );
+        }
+
+        world.flush();
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(
+            vec![
+                "grandparent capture",
+                "parent capture",
+                "child capture",
+                "child",
+                "parent",
+                "grandparent"
+            ],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_propagating_capture_halt() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let parent = world
+            .spawn_empty()
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("parent");
+            })
+            .id();
+        world.spawn(
+            Observer::new(
+                |mut trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                    res.observed("parent capture");
+                    trigger.propagate(false);
+                },
+            )
+            .with_entity(parent)
+            .capture(),
+        );
+
+        let child = world
+            .spawn(ChildOf(parent))
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("child");
+            })
+            .id();
+
+        world.flush();
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(vec!["parent capture"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_propagating_down_relationship() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let root = world.spawn_empty().id();
+        let a = world.spawn(crate::hierarchy::ChildOf(root)).id();
+        let a1 = world.spawn(crate::hierarchy::ChildOf(a)).id();
+        let b = world.spawn(crate::hierarchy::ChildOf(root)).id();
+        for (entity, name) in [(root, "root"), (a, "a"), (a1, "a1"), (b, "b")] {
+            world.entity_mut(entity).observe(
+                move |_: Trigger<EventBroadcast>, mut res: ResMut<Order>| {
+                    res.observed(name);
+                },
+            );
+        }
+
+        world.flush();
+        world.trigger_targets(EventBroadcast, root);
+        world.flush();
+        assert_eq!(vec!["root", "a", "a1", "b"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_priority() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.add_observer(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("default"));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("low"))
+                .with_priority(-5),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("high"))
+                .with_priority(10),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("medium"))
+                .with_priority(1),
+        );
+
+        world.flush();
+        world.trigger(EventA);
+        world.flush();
+        assert_eq!(
+            vec!["high", "medium", "default", "low"],
+            world.resource::<Order>().0
+        );
+    }
+
     #[test]
     fn observer_propagating_no_next() {
         let mut world = World::new();
@@ -319,6 +319,26 @@ impl Observer {
         self
     }
 
+    /// Run this [`Observer`] during the [capture phase] of propagated events, before the event reaches its target,
+    /// instead of during their [bubble phase].
+    ///
+    /// This lets an observer further along the propagation path intercept an event by calling
+    /// [`Trigger::propagate(false)`](Trigger::propagate).
+    ///
+    /// [capture phase]: crate::observer::PropagationPhase::Capture
+    /// [bubble phase]: crate::observer::PropagationPhase::Bubble
+    pub fn capture(mut self) -> Self {
+        self.descriptor.capture = true;
+        self
+    }
+
+    /// Set the priority of this [`Observer`]. When the same trigger runs several observers,
+    /// the ones with a higher priority run first. Observers have a priority of `0` by default.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.descriptor.priority = priority;
+        self
+    }
+
     /// Set the error handler to use for this observer.
     ///
     /// See the [`error` module-level documentation](crate::error) for more information.
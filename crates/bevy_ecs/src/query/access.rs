@@ -897,6 +897,9 @@ pub struct FilteredAccess<T: SparseSetIndex> {
     // An array of filter sets to express `With` or `Without` clauses in disjunctive normal form, for example: `Or<(With<A>, With<B>)>`.
     // Filters like `(With<A>, Or<(With<B>, Without<C>)>` are expanded into `Or<((With<A>, With<B>), (With<A>, Without<C>))>`.
     pub(crate) filter_sets: Vec<AccessFilters<T>>,
+    // Access to entities other than the ones matched by the filters, such as the targets of a relationship.
+    // It is never ruled out by `filter_sets`.
+    pub(crate) unfiltered_access: Access<T>,
 }
 
 // This is needed since `#[derive(Clone)]` does not generate optimized `clone_from`.
@@ -906,6 +909,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
             access: self.access.clone(),
             required: self.required.clone(),
             filter_sets: self.filter_sets.clone(),
+            unfiltered_access: self.unfiltered_access.clone(),
         }
     }
 
@@ -913,6 +917,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
         self.access.clone_from(&source.access);
         self.required.clone_from(&source.required);
         self.filter_sets.clone_from(&source.filter_sets);
+        self.unfiltered_access.clone_from(&source.unfiltered_access);
     }
 }
 
@@ -1000,6 +1005,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: vec![AccessFilters::default()],
+            unfiltered_access: Access::default(),
         }
     }
 
@@ -1010,6 +1016,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: Vec::new(),
+            unfiltered_access: Access::default(),
         }
     }
 
@@ -1025,6 +1032,23 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         &mut self.access
     }
 
+    /// Returns a reference to the access to entities that are not matched by the filters,
+    /// such as the targets of a relationship.
+    ///
+    /// Unlike [`FilteredAccess::access`], this access can never be made disjoint from another
+    /// by [`With`](super::With) or [`Without`](super::Without) filters.
+    #[inline]
+    pub fn unfiltered_access(&self) -> &Access<T> {
+        &self.unfiltered_access
+    }
+
+    /// Adds all of the accesses from `other` to the access to entities that are not matched by
+    /// the filters of `self`. See [`FilteredAccess::unfiltered_access`].
+    pub fn extend_unfiltered_access(&mut self, other: &FilteredAccess<T>) {
+        self.unfiltered_access.extend(&other.access);
+        self.unfiltered_access.extend(&other.unfiltered_access);
+    }
+
     /// Adds access to the component given by `index`.
     pub fn add_component_read(&mut self, index: T) {
         self.access.add_component_read(index.clone());
@@ -1085,6 +1109,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// Adds all of the accesses from `other` to `self`.
     pub fn extend_access(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.unfiltered_access.extend(&other.unfiltered_access);
     }
 
     /// Returns `true` if this and `other` can be active at the same time.
@@ -1095,6 +1120,21 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             return false;
         }
 
+        // Unfiltered accesses may touch entities that don't match the filters,
+        // so they must be compatible even if the filters are disjoint.
+        if !self
+            .unfiltered_access
+            .is_components_compatible(&other.access)
+            || !self
+                .access
+                .is_components_compatible(&other.unfiltered_access)
+            || !self
+                .unfiltered_access
+                .is_components_compatible(&other.unfiltered_access)
+        {
+            return false;
+        }
+
         if self.access.is_components_compatible(&other.access) {
             return true;
         }
@@ -1118,7 +1158,15 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     pub fn get_conflicts(&self, other: &FilteredAccess<T>) -> AccessConflicts {
         if !self.is_compatible(other) {
             // filters are disjoint, so we can just look at the unfiltered intersection
-            return self.access.get_conflicts(&other.access);
+            let mut conflicts = self.access.get_conflicts(&other.access);
+            conflicts.add(&self.unfiltered_access.get_conflicts(&other.access));
+            conflicts.add(&self.access.get_conflicts(&other.unfiltered_access));
+            conflicts.add(
+                &self
+                    .unfiltered_access
+                    .get_conflicts(&other.unfiltered_access),
+            );
+            return conflicts;
         }
         AccessConflicts::empty()
     }
@@ -1132,6 +1180,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     pub fn extend(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
         self.required.union_with(&other.required);
+        self.unfiltered_access.extend(&other.unfiltered_access);
 
         // We can avoid allocating a new array of bitsets if `other` contains just a single set of filters:
         // in this case we can short-circuit by performing an in-place union for each bitset.
@@ -1178,7 +1227,11 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// Returns `true` if the set is a subset of another, i.e. `other` contains
     /// at least all the values in `self`.
     pub fn is_subset(&self, other: &FilteredAccess<T>) -> bool {
-        self.required.is_subset(&other.required) && self.access().is_subset(other.access())
+        self.required.is_subset(&other.required)
+            && self.access().is_subset(other.access())
+            && self
+                .unfiltered_access()
+                .is_subset(other.unfiltered_access())
     }
 
     /// Returns the indices of the elements that this access filters for.
@@ -1199,6 +1252,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     pub fn contains(&self, index: T) -> bool {
         self.access().has_component_read(index.clone())
             || self.access().has_archetypal(index.clone())
+            || self.unfiltered_access().has_component_read(index.clone())
             || self.filter_sets.iter().any(|f| {
                 f.with.contains(index.sparse_set_index())
                     || f.without.contains(index.sparse_set_index())
@@ -1338,7 +1392,11 @@ impl<T: SparseSetIndex> FilteredAccessSet<T> {
     pub fn get_conflicts_single(&self, filtered_access: &FilteredAccess<T>) -> AccessConflicts {
         // if the unfiltered access is incompatible, must check each pair
         let mut conflicts = AccessConflicts::empty();
-        if !self.combined_access.is_compatible(filtered_access.access()) {
+        if !self.combined_access.is_compatible(filtered_access.access())
+            || !self
+                .combined_access
+                .is_compatible(filtered_access.unfiltered_access())
+        {
             for filtered in &self.filtered_accesses {
                 conflicts.add(&filtered.get_conflicts(filtered_access));
             }
@@ -1349,6 +1407,8 @@ impl<T: SparseSetIndex> FilteredAccessSet<T> {
     /// Adds the filtered access to the set.
     pub fn add(&mut self, filtered_access: FilteredAccess<T>) {
         self.combined_access.extend(&filtered_access.access);
+        self.combined_access
+            .extend(&filtered_access.unfiltered_access);
         self.filtered_accesses.push(filtered_access);
     }
 
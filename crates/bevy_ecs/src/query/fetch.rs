@@ -1542,6 +1542,10 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
         table: &'w Table,
     ) {
         let column = table.get_column(component_id).debug_checked_unwrap();
+        // Any component of this table may be changed through the fetched items. Marking the column
+        // here rather than on `DerefMut` keeps `Mut` unchanged, at the cost of also marking tables
+        // whose components are never mutably dereferenced.
+        column.mark_changed(fetch.this_run);
         let table_data = Some((
             column.get_data_slice(table.entity_count()).into(),
             column.get_added_ticks_slice(table.entity_count()).into(),
@@ -105,6 +105,16 @@ pub unsafe trait QueryFilter: WorldQuery {
         entity: Entity,
         table_row: TableRow,
     ) -> bool;
+
+    /// Returns false if no entity of the table set by the last call to [`WorldQuery::set_table`] or
+    /// [`WorldQuery::set_archetype`] can pass [`QueryFilter::filter_fetch`], in which case the table
+    /// or archetype is skipped entirely.
+    ///
+    /// The default implementation always returns true, which is correct for every filter.
+    #[inline(always)]
+    fn may_match_table(_fetch: &Self::Fetch<'_>) -> bool {
+        true
+    }
 }
 
 /// Filter that selects entities with a component `T`.
@@ -501,6 +511,12 @@ macro_rules! impl_or_query_filter {
                 // SAFETY: The invariants are upheld by the caller.
                 false $(|| ($filter.matches && unsafe { $filter::filter_fetch(&mut $filter.fetch, entity, table_row) }))*
             }
+
+            #[inline(always)]
+            fn may_match_table(fetch: &Self::Fetch<'_>) -> bool {
+                let ($($filter,)*) = fetch;
+                false $(|| ($filter.matches && $filter::may_match_table(&$filter.fetch)))*
+            }
         }
     };
 }
@@ -534,6 +550,12 @@ macro_rules! impl_tuple_query_filter {
                 // SAFETY: The invariants are upheld by the caller.
                 true $(&& unsafe { $name::filter_fetch($name, entity, table_row) })*
             }
+
+            #[inline(always)]
+            fn may_match_table(fetch: &Self::Fetch<'_>) -> bool {
+                let ($($name,)*) = fetch;
+                true $(&& $name::may_match_table($name))*
+            }
         }
 
     };
@@ -576,6 +598,13 @@ all_tuples!(
 /// if the query (with `T` component filter) matches a million entities,
 /// `Added<T>` filter will iterate over all of them even if none of them were just added.
 ///
+/// When `T` is stored in tables, each table records the most recent tick at which its `T` components
+/// may have been added or changed, and tables without any recent change are skipped entirely.
+/// Tables are recorded as changed as soon as mutable access to their `T` components is granted,
+/// even if the components are never mutably dereferenced. As a consequence, a system iterating a
+/// `Query<&mut T>` every frame prevents this query from skipping the tables it visited, so prefer
+/// read-only access to `T` where possible.
+///
 /// For example, these two systems are roughly equivalent in terms of performance:
 ///
 /// ```
@@ -631,6 +660,8 @@ pub struct AddedFetch<'w, T: Component> {
         // Can be `None` when the component has never been inserted
         Option<&'w ComponentSparseSet>,
     >,
+    // The max changed tick of the current table, which is also at least as recent as its added ticks.
+    max_changed: Option<Tick>,
     last_run: Tick,
     this_run: Tick,
 }
@@ -639,6 +670,7 @@ impl<T: Component> Clone for AddedFetch<'_, T> {
     fn clone(&self) -> Self {
         Self {
             ticks: self.ticks,
+            max_changed: self.max_changed,
             last_run: self.last_run,
             this_run: self.this_run,
         }
@@ -676,6 +708,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
                     unsafe { world.storages().sparse_sets.get(id) }
                 },
             ),
+            max_changed: None,
             last_run,
             this_run,
         }
@@ -715,6 +748,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
                 .debug_checked_unwrap()
                 .into(),
         );
+        fetch.max_changed = table.get_max_changed_tick(component_id);
         // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
         unsafe { fetch.ticks.set_table(table_ticks) };
     }
@@ -775,6 +809,14 @@ unsafe impl<T: Component> QueryFilter for Added<T> {
             },
         )
     }
+
+    #[inline(always)]
+    fn may_match_table(fetch: &Self::Fetch<'_>) -> bool {
+        // `max_changed` is only set for tables, and is at least as recent as the ticks checked above.
+        fetch
+            .max_changed
+            .is_none_or(|tick| tick.is_newer_than(fetch.last_run, fetch.this_run))
+    }
 }
 
 /// A filter on a component that only retains results the first time after they have been added or mutably dereferenced.
@@ -802,6 +844,13 @@ unsafe impl<T: Component> QueryFilter for Added<T> {
 /// if query (with `T` component filter) matches million entities,
 /// `Changed<T>` filter will iterate over all of them even if none of them were changed.
 ///
+/// When `T` is stored in tables, each table records the most recent tick at which its `T` components
+/// may have been changed, and tables without any recent change are skipped entirely.
+/// Tables are recorded as changed as soon as mutable access to their `T` components is granted,
+/// even if the components are never mutably dereferenced. As a consequence, a system iterating a
+/// `Query<&mut T>` every frame prevents this query from skipping the tables it visited, so prefer
+/// read-only access to `T` where possible.
+///
 /// For example, these two systems are roughly equivalent in terms of performance:
 ///
 /// ```
@@ -858,6 +907,8 @@ pub struct ChangedFetch<'w, T: Component> {
         // Can be `None` when the component has never been inserted
         Option<&'w ComponentSparseSet>,
     >,
+    // The max changed tick of the current table.
+    max_changed: Option<Tick>,
     last_run: Tick,
     this_run: Tick,
 }
@@ -866,6 +917,7 @@ impl<T: Component> Clone for ChangedFetch<'_, T> {
     fn clone(&self) -> Self {
         Self {
             ticks: self.ticks,
+            max_changed: self.max_changed,
             last_run: self.last_run,
             this_run: self.this_run,
         }
@@ -903,6 +955,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
                     unsafe { world.storages().sparse_sets.get(id) }
                 },
             ),
+            max_changed: None,
             last_run,
             this_run,
         }
@@ -942,6 +995,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
                 .debug_checked_unwrap()
                 .into(),
         );
+        fetch.max_changed = table.get_max_changed_tick(component_id);
         // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
         unsafe { fetch.ticks.set_table(table_ticks) };
     }
@@ -1003,6 +1057,14 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
             },
         )
     }
+
+    #[inline(always)]
+    fn may_match_table(fetch: &Self::Fetch<'_>) -> bool {
+        // `max_changed` is only set for tables, and is at least as recent as the ticks checked above.
+        fetch
+            .max_changed
+            .is_none_or(|tick| tick.is_newer_than(fetch.last_run, fetch.this_run))
+    }
 }
 
 /// A marker trait to indicate that the filter works at an archetype level.
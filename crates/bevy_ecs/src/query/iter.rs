@@ -212,12 +212,15 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             "TableRow is only valid up to u32::MAX"
         );
 
-        D::set_table(&mut self.cursor.fetch, &self.query_state.fetch_state, table);
         F::set_table(
             &mut self.cursor.filter,
             &self.query_state.filter_state,
             table,
         );
+        if !F::may_match_table(&self.cursor.filter) {
+            return accum;
+        }
+        D::set_table(&mut self.cursor.fetch, &self.query_state.fetch_state, table);
 
         let entities = table.entities();
         for row in rows {
@@ -263,18 +266,21 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             return accum;
         }
         let table = self.tables.get(archetype.table_id()).debug_checked_unwrap();
-        D::set_archetype(
-            &mut self.cursor.fetch,
-            &self.query_state.fetch_state,
-            archetype,
-            table,
-        );
         F::set_archetype(
             &mut self.cursor.filter,
             &self.query_state.filter_state,
             archetype,
             table,
         );
+        if !F::may_match_table(&self.cursor.filter) {
+            return accum;
+        }
+        D::set_archetype(
+            &mut self.cursor.fetch,
+            &self.query_state.fetch_state,
+            archetype,
+            table,
+        );
 
         let entities = archetype.entities();
         for index in indices {
@@ -341,18 +347,21 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             "archetype and it's table must have the same length. "
         );
 
-        D::set_archetype(
-            &mut self.cursor.fetch,
-            &self.query_state.fetch_state,
-            archetype,
-            table,
-        );
         F::set_archetype(
             &mut self.cursor.filter,
             &self.query_state.filter_state,
             archetype,
             table,
         );
+        if !F::may_match_table(&self.cursor.filter) {
+            return accum;
+        }
+        D::set_archetype(
+            &mut self.cursor.fetch,
+            &self.query_state.fetch_state,
+            archetype,
+            table,
+        );
         let entities = table.entities();
         for row in rows {
             // SAFETY: Caller assures `row` in range of the current archetype.
@@ -2473,9 +2482,17 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                     // SAFETY: `table` is from the world that `fetch/filter` were created for,
                     // `fetch_state`/`filter_state` are the states that `fetch/filter` were initialized with
                     unsafe {
-                        D::set_table(&mut self.fetch, &query_state.fetch_state, table);
                         F::set_table(&mut self.filter, &query_state.filter_state, table);
                     }
+                    // Skip the table before setting the fetch, which may mark it as changed.
+                    if !F::may_match_table(&self.filter) {
+                        continue;
+                    }
+                    // SAFETY: `table` is from the world that `fetch` was created for,
+                    // `fetch_state` is the state that `fetch` was initialized with
+                    unsafe {
+                        D::set_table(&mut self.fetch, &query_state.fetch_state, table);
+                    }
                     self.table_entities = table.entities();
                     self.current_len = table.entity_count();
                     self.current_row = 0;
@@ -2512,12 +2529,6 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                     // SAFETY: `archetype` and `tables` are from the world that `fetch/filter` were created for,
                     // `fetch_state`/`filter_state` are the states that `fetch/filter` were initialized with
                     unsafe {
-                        D::set_archetype(
-                            &mut self.fetch,
-                            &query_state.fetch_state,
-                            archetype,
-                            table,
-                        );
                         F::set_archetype(
                             &mut self.filter,
                             &query_state.filter_state,
@@ -2525,6 +2536,20 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                             table,
                         );
                     }
+                    // Skip the archetype before setting the fetch, which may mark its table as changed.
+                    if !F::may_match_table(&self.filter) {
+                        continue;
+                    }
+                    // SAFETY: `archetype` and `tables` are from the world that `fetch` was created for,
+                    // `fetch_state` is the state that `fetch` was initialized with
+                    unsafe {
+                        D::set_archetype(
+                            &mut self.fetch,
+                            &query_state.fetch_state,
+                            archetype,
+                            table,
+                        );
+                    }
                     self.archetype_entities = archetype.entities();
                     self.current_len = archetype.len();
                     self.current_row = 0;
@@ -188,6 +188,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 if state.new_archetype_internal(archetype) {
                     state.update_archetype_component_access(archetype, access);
                 }
+                state.update_unfiltered_archetype_component_access(archetype, access);
             }
         }
         state.archetype_generation = world.archetypes.generation();
@@ -254,6 +255,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         // Merge the temporary filter access with the main access. This ensures that filter access is
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
+        assert_unfiltered_access_compatibility::<D, F>(&component_access);
 
         // For queries without dynamic filters the dense-ness of the query is equal to the dense-ness
         // of its static type parameters.
@@ -290,6 +292,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         D::set_access(&mut fetch_state, builder.access());
 
         let mut component_access = builder.access().clone();
+        assert_unfiltered_access_compatibility::<D, F>(&component_access);
 
         // For dynamic queries the dense-ness is given by the query builder.
         let mut is_dense = builder.is_dense();
@@ -620,6 +623,8 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
             unsafe { self.update_archetype_component_access(archetype, access) };
         }
+        // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
+        unsafe { self.update_unfiltered_archetype_component_access(archetype, access) };
     }
 
     /// Process the given [`Archetype`] to update internal metadata about the [`Table`](crate::storage::Table)s
@@ -683,44 +688,23 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         archetype: &Archetype,
         access: &mut Access<ArchetypeComponentId>,
     ) {
-        // As a fast path, we can iterate directly over the components involved
-        // if the `access` is finite.
-        if let Ok(iter) = self.component_access.access.try_iter_component_access() {
-            iter.for_each(|component_access| {
-                if let Some(id) = archetype.get_archetype_component_id(*component_access.index()) {
-                    match component_access {
-                        ComponentAccessKind::Archetypal(_) => {}
-                        ComponentAccessKind::Shared(_) => {
-                            access.add_component_read(id);
-                        }
-                        ComponentAccessKind::Exclusive(_) => {
-                            access.add_component_write(id);
-                        }
-                    }
-                }
-            });
-
-            return;
-        }
+        add_archetype_component_access(&self.component_access.access, archetype, access);
+    }
 
-        for (component_id, archetype_component_id) in
-            archetype.components_with_archetype_component_id()
-        {
-            if self
-                .component_access
-                .access
-                .has_component_read(component_id)
-            {
-                access.add_component_read(archetype_component_id);
-            }
-            if self
-                .component_access
-                .access
-                .has_component_write(component_id)
-            {
-                access.add_component_write(archetype_component_id);
-            }
-        }
+    /// For the given `archetype`, adds any component accessed on entities that are not matched by this query,
+    /// as given by [`FilteredAccess::unfiltered_access`], to `access`.
+    ///
+    /// Unlike [`update_archetype_component_access`](Self::update_archetype_component_access), this must be called
+    /// for every archetype, whether or not it matches the query.
+    ///
+    /// # Safety
+    /// `archetype` must be from the `World` this state was initialized from.
+    pub unsafe fn update_unfiltered_archetype_component_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+    ) {
+        add_archetype_component_access(&self.component_access.unfiltered_access, archetype, access);
     }
 
     /// Use this to transform a [`QueryState`] into a more generic [`QueryState`].
@@ -1877,6 +1861,58 @@ impl<D: QueryData, F: QueryFilter> From<QueryBuilder<'_, D, F>> for QueryState<D
     }
 }
 
+/// For the given `archetype`, adds the archetype components of any component in `component_access` to `access`.
+fn add_archetype_component_access(
+    component_access: &Access<ComponentId>,
+    archetype: &Archetype,
+    access: &mut Access<ArchetypeComponentId>,
+) {
+    // As a fast path, we can iterate directly over the components involved
+    // if the `access` is finite.
+    if let Ok(iter) = component_access.try_iter_component_access() {
+        iter.for_each(|component_access| {
+            if let Some(id) = archetype.get_archetype_component_id(*component_access.index()) {
+                match component_access {
+                    ComponentAccessKind::Archetypal(_) => {}
+                    ComponentAccessKind::Shared(_) => {
+                        access.add_component_read(id);
+                    }
+                    ComponentAccessKind::Exclusive(_) => {
+                        access.add_component_write(id);
+                    }
+                }
+            }
+        });
+
+        return;
+    }
+
+    for (component_id, archetype_component_id) in archetype.components_with_archetype_component_id()
+    {
+        if component_access.has_component_read(component_id) {
+            access.add_component_read(archetype_component_id);
+        }
+        if component_access.has_component_write(component_id) {
+            access.add_component_write(archetype_component_id);
+        }
+    }
+}
+
+/// Panics if the query accesses components on unmatched entities in a way that conflicts with its own access,
+/// for example `Query<(&mut Transform, Related<ChildOf, &Transform>)>`, since the related entity may also be matched.
+fn assert_unfiltered_access_compatibility<D: QueryData, F: QueryFilter>(
+    component_access: &FilteredAccess<ComponentId>,
+) {
+    assert!(
+        component_access
+            .unfiltered_access()
+            .is_components_compatible(component_access.access()),
+        "Query<{}, {}> accesses components on related entities that conflict with its own access.",
+        core::any::type_name::<D>(),
+        core::any::type_name::<F>(),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
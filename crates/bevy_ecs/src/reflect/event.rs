@@ -0,0 +1,217 @@
+//! Definitions for [`Event`] reflection.
+//! This allows sending, triggering and reading events whose types are known only at runtime.
+//!
+//! This module exports two types: [`ReflectEventFns`] and [`ReflectEvent`].
+//!
+//! Same as [`super::component`], but for [`Event`]s.
+
+use crate::{
+    entity::Entity,
+    event::{Event, Events},
+    world::World,
+};
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect, TypePath, TypeRegistry};
+
+use super::from_reflect_with_fallback;
+
+/// A struct used to operate on reflected [`Event`] of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectEvent(ReflectEventFns);
+
+/// The raw function pointers needed to make up a [`ReflectEvent`].
+///
+/// This is used when creating custom implementations of [`ReflectEvent`] with
+/// [`ReflectEvent::new()`].
+///
+/// > **Note:**
+/// > Creating custom implementations of [`ReflectEvent`] is an advanced feature that most users
+/// > will not need.
+/// > Usually a [`ReflectEvent`] is created for a type by deriving [`Reflect`]
+/// > and adding the `#[reflect(Event)]` attribute.
+/// > After adding the event to the [`TypeRegistry`],
+/// > its [`ReflectEvent`] can then be retrieved when needed.
+#[derive(Clone)]
+pub struct ReflectEventFns {
+    /// Function pointer implementing [`ReflectEvent::send()`].
+    pub send: fn(&mut World, &dyn PartialReflect, &TypeRegistry) -> Option<usize>,
+    /// Function pointer implementing [`ReflectEvent::trigger()`].
+    pub trigger: fn(&mut World, &dyn PartialReflect, &[Entity], &TypeRegistry),
+    /// Function pointer implementing [`ReflectEvent::event_count()`].
+    pub event_count: fn(&World) -> Option<usize>,
+    /// Function pointer implementing [`ReflectEvent::get()`].
+    pub get: for<'w> fn(&'w World, usize) -> Option<&'w dyn Reflect>,
+}
+
+impl ReflectEventFns {
+    /// Get the default set of [`ReflectEventFns`] for a specific event type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Event + FromReflect + TypePath>() -> Self {
+        <ReflectEvent as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectEvent {
+    /// Sends a reflected [`Event`] like [`send_event()`](World::send_event).
+    ///
+    /// Returns the id of the sent event, or `None` if the [`Events`] resource of this type
+    /// doesn't exist in the world.
+    pub fn send(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> Option<usize> {
+        (self.0.send)(world, event, registry)
+    }
+
+    /// Triggers a reflected [`Event`] like [`trigger_targets()`](World::trigger_targets).
+    ///
+    /// If `targets` is empty, only the observers watching for this event globally will run.
+    pub fn trigger(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        targets: &[Entity],
+        registry: &TypeRegistry,
+    ) {
+        (self.0.trigger)(world, event, targets, registry);
+    }
+
+    /// Returns the number of events of this type that have been sent so far, which is also
+    /// the id the next sent event will receive.
+    ///
+    /// Returns `None` if the [`Events`] resource of this type doesn't exist in the world.
+    pub fn event_count(&self, world: &World) -> Option<usize> {
+        (self.0.event_count)(world)
+    }
+
+    /// Gets a sent event by its id as a reflected reference, if it is still buffered.
+    pub fn get<'w>(&self, world: &'w World, id: usize) -> Option<&'w dyn Reflect> {
+        (self.0.get)(world, id)
+    }
+
+    /// Create a custom implementation of [`ReflectEvent`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(Event)]` attribute
+    /// to generate a [`ReflectEvent`] implementation automatically.
+    ///
+    /// See [`ReflectEventFns`] for more information.
+    pub fn new(fns: ReflectEventFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectEvent`.
+    ///
+    /// This is useful when you want to keep track locally of an individual
+    /// function pointer.
+    ///
+    /// Calling [`TypeRegistry::get`] followed by
+    /// [`TypeRegistration::data::<ReflectEvent>`] can be costly if done several
+    /// times per frame. Consider cloning [`ReflectEvent`] and keeping it
+    /// between frames, cloning a `ReflectEvent` is very cheap.
+    ///
+    /// If you only need a subset of the methods on `ReflectEvent`,
+    /// use `fn_pointers` to get the underlying [`ReflectEventFns`]
+    /// and copy the subset of function pointers you care about.
+    ///
+    /// [`TypeRegistration::data::<ReflectEvent>`]: bevy_reflect::TypeRegistration::data
+    pub fn fn_pointers(&self) -> &ReflectEventFns {
+        &self.0
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent(ReflectEventFns {
+            send: |world, reflected_event, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.send_event(event).map(|id| id.id)
+            },
+            trigger: |world, reflected_event, targets, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.trigger_targets(event, targets);
+            },
+            event_count: |world| {
+                world
+                    .get_resource::<Events<E>>()
+                    .map(|events| events.event_count)
+            },
+            get: |world, id| {
+                let events = world.get_resource::<Events<E>>()?;
+                events.get_event(id).map(|(event, _)| event as &dyn Reflect)
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, EventRegistry},
+        observer::Trigger,
+        reflect::ReflectEvent,
+        resource::Resource,
+        system::ResMut,
+        world::World,
+    };
+    use bevy_reflect::{Reflect, TypeRegistry};
+    use core::any::TypeId;
+
+    #[derive(Event, Reflect, PartialEq, Debug)]
+    #[reflect(Event)]
+    struct Ping(u32);
+
+    #[derive(Resource, Default)]
+    struct Received(u32);
+
+    #[test]
+    fn send_and_get_reflected_event() {
+        let mut world = World::new();
+        EventRegistry::register_event::<Ping>(&mut world);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Ping>();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(TypeId::of::<Ping>())
+            .unwrap();
+
+        assert_eq!(reflect_event.event_count(&world), Some(0));
+        let id = reflect_event.send(&mut world, &Ping(7), &registry);
+        assert_eq!(id, Some(0));
+        assert_eq!(reflect_event.event_count(&world), Some(1));
+
+        let event = reflect_event.get(&world, 0).unwrap();
+        assert_eq!(event.downcast_ref::<Ping>(), Some(&Ping(7)));
+        assert!(reflect_event.get(&world, 1).is_none());
+    }
+
+    #[test]
+    fn trigger_reflected_event() {
+        let mut world = World::new();
+        world.init_resource::<Received>();
+        world.add_observer(|trigger: Trigger<Ping>, mut received: ResMut<Received>| {
+            received.0 += trigger.event().0;
+        });
+        world.flush();
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Ping>();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(TypeId::of::<Ping>())
+            .unwrap();
+        reflect_event.trigger(&mut world, &Ping(3), &[], &registry);
+
+        assert_eq!(world.resource::<Received>().0, 3);
+    }
+}
@@ -0,0 +1,623 @@
+use crate::{
+    component::{Component, HookContext, Immutable},
+    entity::Entity,
+    relationship::{RelationshipHookMode, RelationshipSourceCollection},
+    system::EntityCommands,
+    world::{DeferredWorld, EntityWorldMut},
+};
+use alloc::{format, vec::Vec};
+use log::warn;
+
+/// A [`Component`] on a "source" [`Entity`] that references any number of "target" entities, creating a many-to-many
+/// relationship between them. Every [`ManyToManyRelationship`] has a corresponding [`ManyToManyRelationshipTarget`]
+/// type (and vice-versa), which exists on each "target" entity and contains the list of all "source" entities that
+/// relate to it.
+///
+/// Like for [`Relationship`](super::Relationship), the [`ManyToManyRelationship`] component is the "source of truth"
+/// and the [`ManyToManyRelationshipTarget`] component reflects it: component hooks keep both sides consistent when
+/// either component is inserted, removed or despawned. Despawning an entity removes it from the collections of all
+/// the entities it is related to, but never despawns them.
+///
+/// [`ManyToManyRelationship`] and [`ManyToManyRelationshipTarget`] should always be derived via the [`Component`]
+/// trait with the `many_to_many` attribute, to ensure the hooks are set up properly. Both components are immutable, and
+/// their collections must be private to prevent direct mutation. Use [`ManyToManyRelationship::from_targets`] to create the source
+/// component, and [`EntityWorldMut::add_relationship_targets`] / [`EntityWorldMut::remove_relationship_targets`] to
+/// update it.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::relationship::{ManyToManyRelationship, ManyToManyRelationshipTarget};
+/// /// The containers an item is stored in.
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Contains, many_to_many)]
+/// pub struct StoredIn(Vec<Entity>);
+///
+/// /// The items stored in a container.
+/// #[derive(Component)]
+/// #[relationship_target(relationship = StoredIn, many_to_many)]
+/// pub struct Contains(Vec<Entity>);
+///
+/// let mut world = World::new();
+/// let chest = world.spawn_empty().id();
+/// let bag = world.spawn_empty().id();
+/// let sword = world.spawn(StoredIn::from_targets([chest, bag])).id();
+/// let shield = world.spawn(StoredIn::from_targets([chest])).id();
+///
+/// assert_eq!(world.get::<Contains>(chest).unwrap().collection(), &[sword, shield]);
+/// assert_eq!(world.get::<Contains>(bag).unwrap().collection(), &[sword]);
+///
+/// world.despawn(chest);
+/// assert_eq!(world.get::<StoredIn>(sword).unwrap().collection(), &[bag]);
+/// assert!(!world.entity(shield).contains::<StoredIn>());
+/// ```
+pub trait ManyToManyRelationship: Component<Mutability = Immutable> + Sized {
+    /// The [`Component`] added to the "target" entities of this [`ManyToManyRelationship`], which contains the list
+    /// of all "source" entities that relate to the "target".
+    type RelationshipTarget: ManyToManyRelationshipTarget<Relationship = Self>;
+    /// The collection type that stores the "target" entities of this [`ManyToManyRelationship`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`ManyToManyRelationship::Collection`].
+    fn collection(&self) -> &Self::Collection;
+    /// Returns a mutable reference to the stored [`ManyToManyRelationship::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`ManyToManyRelationship`] from the given [`ManyToManyRelationship::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// Creates this [`ManyToManyRelationship`] relating to the given `targets`, ignoring duplicates.
+    fn from_targets(targets: impl IntoIterator<Item = Entity>) -> Self {
+        let mut collection = <Self::Collection as RelationshipSourceCollection>::new();
+        for target in targets {
+            if !collection.iter().any(|entity| entity == target) {
+                collection.add(target);
+            }
+        }
+        Self::from_collection_risky(collection)
+    }
+
+    /// Iterates the target entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of target entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this collection has no target entity.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// Returns true if this collection contains the given `target` entity.
+    #[inline]
+    fn contains(&self, target: Entity) -> bool {
+        self.iter().any(|entity| entity == target)
+    }
+
+    /// The `on_insert` component hook that maintains the [`ManyToManyRelationship`] / [`ManyToManyRelationshipTarget`] connection.
+    fn on_insert(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            caller,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let targets: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        let mut invalid = Vec::new();
+        for target_entity in targets {
+            if target_entity == entity {
+                warn!(
+                    "{}The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid target has been removed.",
+                    caller.map(|location|format!("{location}: ")).unwrap_or_default(),
+                    core::any::type_name::<Self>(),
+                );
+                invalid.push(target_entity);
+            } else if world.get_entity(target_entity).is_err() {
+                warn!(
+                    "{}The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid target has been removed.",
+                    caller.map(|location|format!("{location}: ")).unwrap_or_default(),
+                    core::any::type_name::<Self>(),
+                );
+                invalid.push(target_entity);
+            } else if !modify_collection(
+                &mut world,
+                target_entity,
+                |relationship_target: &mut Self::RelationshipTarget| {
+                    relationship_target.collection_mut_risky().add(entity);
+                },
+            ) {
+                let mut target =
+                    <Self::RelationshipTarget as ManyToManyRelationshipTarget>::with_capacity(1);
+                target.collection_mut_risky().add(entity);
+                world.commands().entity(target_entity).insert(target);
+            }
+        }
+
+        if !invalid.is_empty() {
+            modify_collection(&mut world, entity, |relationship: &mut Self| {
+                for target_entity in invalid {
+                    relationship.collection_mut_risky().remove(target_entity);
+                }
+            });
+            remove_if_empty(
+                &mut world,
+                entity,
+                <Self as ManyToManyRelationship>::is_empty,
+            );
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`ManyToManyRelationship`] / [`ManyToManyRelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let targets: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        for target_entity in targets {
+            modify_collection(
+                &mut world,
+                target_entity,
+                |relationship_target: &mut Self::RelationshipTarget| {
+                    relationship_target.collection_mut_risky().remove(entity);
+                },
+            );
+            remove_if_empty(
+                &mut world,
+                target_entity,
+                <Self::RelationshipTarget as ManyToManyRelationshipTarget>::is_empty,
+            );
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated
+/// [`ManyToManyRelationship`] type. See the [`ManyToManyRelationship`] documentation for more information.
+pub trait ManyToManyRelationshipTarget: Component<Mutability = Immutable> + Sized {
+    /// The [`ManyToManyRelationship`] that populates this [`ManyToManyRelationshipTarget`] collection.
+    type Relationship: ManyToManyRelationship<RelationshipTarget = Self>;
+    /// The collection type that stores the "source" entities for this [`ManyToManyRelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`ManyToManyRelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+    /// Returns a mutable reference to the stored [`ManyToManyRelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`ManyToManyRelationshipTarget`] from the given [`ManyToManyRelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// Creates this [`ManyToManyRelationshipTarget`] with the given pre-allocated entity capacity.
+    fn with_capacity(capacity: usize) -> Self {
+        let collection =
+            <Self::Collection as RelationshipSourceCollection>::with_capacity(capacity);
+        Self::from_collection_risky(collection)
+    }
+
+    /// Iterates the source entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of source entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this collection has no source entity.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_replace` component hook that maintains the [`ManyToManyRelationship`] / [`ManyToManyRelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        if let RelationshipHookMode::Skip = relationship_hook_mode {
+            return;
+        }
+        let sources: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        for source_entity in sources {
+            modify_collection(
+                &mut world,
+                source_entity,
+                |relationship: &mut Self::Relationship| {
+                    relationship.collection_mut_risky().remove(entity);
+                },
+            );
+            remove_if_empty(
+                &mut world,
+                source_entity,
+                <Self::Relationship as ManyToManyRelationship>::is_empty,
+            );
+        }
+    }
+}
+
+/// Modifies the immutable `C` component of `entity` in place, returning `false` if it doesn't have one.
+///
+/// The hooks and observers of `C` are triggered as for a re-insertion, but with [`RelationshipHookMode::Skip`] so
+/// that the relationship hooks don't undo the modification.
+fn modify_collection<C: Component>(
+    world: &mut DeferredWorld,
+    entity: Entity,
+    f: impl FnOnce(&mut C),
+) -> bool {
+    matches!(
+        world.modify_component_with_relationship_hook_mode(entity, RelationshipHookMode::Skip, f),
+        Ok(Some(()))
+    )
+}
+
+/// Queues the removal of the `C` component of `entity`, if it is empty when the command is applied.
+fn remove_if_empty<C: Component>(
+    world: &mut DeferredWorld,
+    entity: Entity,
+    is_empty: fn(&C) -> bool,
+) {
+    if !world.get::<C>(entity).is_some_and(is_empty) {
+        return;
+    }
+    if let Ok(mut entity) = world.commands().get_entity(entity) {
+        // this "remove" operation must check emptiness because in the event that an identical
+        // relationship is inserted on top, this removal would result in the removal of that identical
+        // relationship ... not what we want!
+        entity.queue(move |mut entity: EntityWorldMut| {
+            if entity.get::<C>().is_some_and(is_empty) {
+                entity.remove::<C>();
+            }
+        });
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Relates this entity to the given `targets` with the many-to-many relation `R`, in addition to the targets it
+    /// already relates to.
+    ///
+    /// Targets that don't exist, this entity itself, and targets this entity already relates to are ignored. The `R`
+    /// component is re-inserted with the new targets, so its hooks and observers run.
+    pub fn add_relationship_targets<R: ManyToManyRelationship>(
+        &mut self,
+        targets: &[Entity],
+    ) -> &mut Self {
+        let id = self.id();
+        let mut collection: Vec<Entity> = self
+            .get::<R>()
+            .map(|relationship| relationship.iter().collect())
+            .unwrap_or_default();
+        let len = collection.len();
+        for &target in targets {
+            if target != id
+                && !collection.contains(&target)
+                && self.world().get_entity(target).is_ok()
+            {
+                collection.push(target);
+            }
+        }
+        if collection.len() > len {
+            self.insert(R::from_targets(collection));
+        }
+        self
+    }
+
+    /// Removes the many-to-many relation `R` between this entity and the given `targets`.
+    ///
+    /// The `R` component is re-inserted with the remaining targets, or removed if this entity no longer relates to
+    /// any target.
+    pub fn remove_relationship_targets<R: ManyToManyRelationship>(
+        &mut self,
+        targets: &[Entity],
+    ) -> &mut Self {
+        let Some(relationship) = self.get::<R>() else {
+            return self;
+        };
+        let len = relationship.len();
+        let remaining: Vec<Entity> = relationship
+            .iter()
+            .filter(|target| !targets.contains(target))
+            .collect();
+        if remaining.is_empty() {
+            self.remove::<R>();
+        } else if remaining.len() < len {
+            self.insert(R::from_targets(remaining));
+        }
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Relates this entity to the given `targets` with the many-to-many relation `R`, in addition to the targets it
+    /// already relates to.
+    ///
+    /// See [`EntityWorldMut::add_relationship_targets`] for more information.
+    pub fn add_relationship_targets<R: ManyToManyRelationship>(
+        &mut self,
+        targets: &[Entity],
+    ) -> &mut Self {
+        let targets: Vec<Entity> = targets.to_vec();
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.add_relationship_targets::<R>(&targets);
+        })
+    }
+
+    /// Removes the many-to-many relation `R` between this entity and the given `targets`.
+    ///
+    /// See [`EntityWorldMut::remove_relationship_targets`] for more information.
+    pub fn remove_relationship_targets<R: ManyToManyRelationship>(
+        &mut self,
+        targets: &[Entity],
+    ) -> &mut Self {
+        let targets: Vec<Entity> = targets.to_vec();
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.remove_relationship_targets::<R>(&targets);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ManyToManyRelationship, ManyToManyRelationshipTarget};
+    use crate::{
+        component::Component,
+        entity::Entity,
+        observer::Trigger,
+        resource::Resource,
+        system::{Query, ResMut, RunSystemOnce},
+        world::{OnInsert, World},
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = MemberOf, many_to_many)]
+    struct Members(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Members, many_to_many)]
+    struct MemberOf(Vec<Entity>);
+
+    fn targets(world: &World, entity: Entity) -> Vec<Entity> {
+        world
+            .get::<Members>(entity)
+            .map(|members| members.iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn sources(world: &World, entity: Entity) -> Vec<Entity> {
+        world
+            .get::<MemberOf>(entity)
+            .map(|member_of| member_of.iter().collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let x = world.spawn(Members::from_targets([a, b, a])).id();
+        let y = world.spawn(Members::from_targets([a])).id();
+
+        assert_eq!(targets(&world, x), vec![a, b]);
+        assert_eq!(sources(&world, a), vec![x, y]);
+        assert_eq!(sources(&world, b), vec![x]);
+
+        world.entity_mut(x).remove::<Members>();
+        assert_eq!(sources(&world, a), vec![y]);
+        assert!(!world.entity(b).contains::<MemberOf>());
+    }
+
+    #[test]
+    fn invalid_targets_are_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let x = world.spawn_empty().id();
+        world
+            .entity_mut(x)
+            .insert(Members::from_targets([x, missing, a]));
+        assert_eq!(targets(&world, x), vec![a]);
+
+        let y = world.spawn_empty().id();
+        world.entity_mut(y).insert(Members::from_targets([y]));
+        assert!(!world.entity(y).contains::<Members>());
+        assert!(!world.entity(y).contains::<MemberOf>());
+    }
+
+    #[test]
+    fn despawn_cleans_up_both_sides() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let x = world.spawn(Members::from_targets([a, b])).id();
+        let y = world.spawn(Members::from_targets([a])).id();
+
+        world.despawn(a);
+        assert_eq!(targets(&world, x), vec![b]);
+        assert!(!world.entity(y).contains::<Members>());
+
+        world.despawn(x);
+        assert!(world.get_entity(b).is_ok());
+        assert!(!world.entity(b).contains::<MemberOf>());
+    }
+
+    #[test]
+    fn add_and_remove_relationship_targets() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+        let x = world.spawn_empty().id();
+
+        world
+            .entity_mut(x)
+            .add_relationship_targets::<Members>(&[a, x]);
+        assert_eq!(targets(&world, x), vec![a]);
+
+        world
+            .entity_mut(x)
+            .add_relationship_targets::<Members>(&[a, b, c]);
+        assert_eq!(targets(&world, x), vec![a, b, c]);
+        assert_eq!(sources(&world, c), vec![x]);
+
+        world
+            .entity_mut(x)
+            .remove_relationship_targets::<Members>(&[a, b]);
+        assert_eq!(targets(&world, x), vec![c]);
+        assert!(!world.entity(a).contains::<MemberOf>());
+
+        world
+            .commands()
+            .entity(x)
+            .remove_relationship_targets::<Members>(&[c]);
+        world.flush();
+        assert!(!world.entity(x).contains::<Members>());
+        assert!(!world.entity(c).contains::<MemberOf>());
+
+        world
+            .commands()
+            .entity(x)
+            .add_relationship_targets::<Members>(&[b]);
+        world.flush();
+        assert_eq!(sources(&world, b), vec![x]);
+    }
+
+    #[test]
+    fn modifications_run_hooks_and_observers() {
+        #[derive(Resource, Default)]
+        struct Inserted(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Inserted>();
+        world.add_observer(
+            |trigger: Trigger<OnInsert, MemberOf>, mut inserted: ResMut<Inserted>| {
+                inserted.0.push(trigger.target());
+            },
+        );
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let x = world.spawn(Members::from_targets([a])).id();
+        let y = world.spawn(Members::from_targets([a])).id();
+        assert_eq!(world.resource::<Inserted>().0, vec![a, a]);
+
+        world
+            .entity_mut(y)
+            .add_relationship_targets::<Members>(&[b]);
+        assert_eq!(sources(&world, a), vec![x, y]);
+        assert_eq!(sources(&world, b), vec![y]);
+
+        // The relationship can only be modified through re-insertion, which keeps both sides consistent.
+        world
+            .modify_component(x, |members: &mut Members| {
+                members.collection_mut_risky().clear();
+                members.collection_mut_risky().push(b);
+            })
+            .unwrap();
+        assert_eq!(sources(&world, a), vec![y]);
+        assert_eq!(sources(&world, b), vec![y, x]);
+    }
+
+    #[test]
+    fn cloning_keeps_targets_consistent() {
+        #[derive(Component, Clone)]
+        #[relationship(relationship_target = TaggedBy, many_to_many)]
+        struct Tags(Vec<Entity>);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = Tags, many_to_many)]
+        struct TaggedBy(Vec<Entity>);
+
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let x = world.spawn(Tags::from_targets([a])).id();
+        let y = world.spawn_empty().id();
+        world.entity_mut(x).clone_with(y, |_| {});
+        let z = world.spawn_empty().id();
+        world.entity_mut(a).clone_with(z, |_| {});
+
+        assert_eq!(world.get::<Tags>(y).unwrap().collection(), &[a]);
+        assert_eq!(world.get::<TaggedBy>(a).unwrap().collection(), &[x, y]);
+        assert!(!world.entity(z).contains::<TaggedBy>());
+    }
+
+    #[test]
+    fn reachable_iteration_handles_cycles() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+        let d = world.spawn_empty().id();
+        world.entity_mut(a).insert(Members::from_targets([b, c]));
+        world.entity_mut(b).insert(Members::from_targets([c]));
+        world.entity_mut(c).insert(Members::from_targets([a, d]));
+
+        let reachable = world
+            .run_system_once(move |query: Query<&Members>| {
+                query
+                    .iter_reachable_targets::<Members>(b)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(reachable, vec![c, a, d]);
+
+        let reachable = world
+            .run_system_once(move |query: Query<&MemberOf>| {
+                query
+                    .iter_reachable_sources::<MemberOf>(d)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(reachable, vec![c, a, b]);
+
+        let direct = world
+            .run_system_once(move |query: Query<&Members>| {
+                query.relationship_targets::<Members>(a).collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(direct, vec![b, c]);
+    }
+}
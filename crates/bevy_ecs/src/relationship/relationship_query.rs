@@ -1,10 +1,19 @@
 use crate::{
-    entity::Entity,
-    query::{QueryData, QueryFilter},
-    relationship::{Relationship, RelationshipTarget},
+    archetype::{Archetype, ArchetypeId},
+    component::{Component, ComponentId, Components, Tick},
+    entity::{Entity, EntityHashSet},
+    query::{
+        DebugCheckedUnwrap, FilteredAccess, QueryData, QueryFilter, ReadOnlyQueryData, WorldQuery,
+    },
+    relationship::{
+        ManyToManyRelationship, ManyToManyRelationshipTarget, Relationship, RelationshipTarget,
+    },
+    storage::{Table, TableRow},
     system::Query,
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
 use alloc::collections::VecDeque;
+use core::marker::PhantomData;
 use smallvec::SmallVec;
 
 use super::SourceIter;
@@ -141,6 +150,68 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
     {
         AncestorIter::new(self, entity)
     }
+
+    /// If the given `entity` contains the `R` [`ManyToManyRelationship`] component, returns the
+    /// target entities of that relationship.
+    pub fn relationship_targets<R: ManyToManyRelationship>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: QueryData<Item<'w> = &'w R>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(ManyToManyRelationship::iter)
+    }
+
+    /// If the given `entity` contains the `S` [`ManyToManyRelationshipTarget`] component, returns the
+    /// source entities stored on that component.
+    pub fn many_to_many_sources<S: ManyToManyRelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: QueryData<Item<'w> = &'w S>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(ManyToManyRelationshipTarget::iter)
+    }
+
+    /// Iterates all entities reachable from the given `entity` by recursively following its `R`
+    /// [`ManyToManyRelationship`] targets, breadth-first.
+    ///
+    /// Each entity is returned at most once, so this terminates on graphs that contain loops.
+    /// The given `entity` itself is never returned.
+    pub fn iter_reachable_targets<R: ManyToManyRelationship>(
+        &'w self,
+        entity: Entity,
+    ) -> ReachableIter<'w, 's, D, F, R>
+    where
+        D::ReadOnly: QueryData<Item<'w> = &'w R>,
+    {
+        ReachableIter::new(self, entity, |relationship, queue| {
+            queue.extend(relationship.iter());
+        })
+    }
+
+    /// Iterates all entities reachable from the given `entity` by recursively following its `S`
+    /// [`ManyToManyRelationshipTarget`] sources, breadth-first.
+    ///
+    /// Each entity is returned at most once, so this terminates on graphs that contain loops.
+    /// The given `entity` itself is never returned.
+    pub fn iter_reachable_sources<S: ManyToManyRelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> ReachableIter<'w, 's, D, F, S>
+    where
+        D::ReadOnly: QueryData<Item<'w> = &'w S>,
+    {
+        ReachableIter::new(self, entity, |relationship_target, queue| {
+            queue.extend(relationship_target.iter());
+        })
+    }
 }
 
 /// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
@@ -270,3 +341,495 @@ where
         self.next
     }
 }
+
+/// An [`Iterator`] of [`Entity`]s over the entities reachable from an [`Entity`] through a
+/// many-to-many relationship graph.
+///
+/// Traverses the graph breadth-first, visiting each entity at most once.
+pub struct ReachableIter<'w, 's, D: QueryData, F: QueryFilter, C: Component>
+where
+    D::ReadOnly: QueryData<Item<'w> = &'w C>,
+{
+    query: &'w Query<'w, 's, D, F>,
+    vecdeque: VecDeque<Entity>,
+    visited: EntityHashSet,
+    neighbors: fn(&'w C, &mut VecDeque<Entity>),
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, C: Component> ReachableIter<'w, 's, D, F, C>
+where
+    D::ReadOnly: QueryData<Item<'w> = &'w C>,
+{
+    /// Returns a new [`ReachableIter`], which uses `neighbors` to queue the entities directly
+    /// related to an entity from its `C` component.
+    pub fn new(
+        query: &'w Query<'w, 's, D, F>,
+        entity: Entity,
+        neighbors: fn(&'w C, &mut VecDeque<Entity>),
+    ) -> Self {
+        let mut vecdeque = VecDeque::new();
+        if let Ok(component) = query.get(entity) {
+            neighbors(component, &mut vecdeque);
+        }
+        let mut visited = EntityHashSet::default();
+        visited.insert(entity);
+        ReachableIter {
+            query,
+            vecdeque,
+            visited,
+            neighbors,
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, C: Component> Iterator for ReachableIter<'w, 's, D, F, C>
+where
+    D::ReadOnly: QueryData<Item<'w> = &'w C>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entity = self.vecdeque.pop_front()?;
+            if !self.visited.insert(entity) {
+                continue;
+            }
+
+            if let Ok(component) = self.query.get(entity) {
+                (self.neighbors)(component, &mut self.vecdeque);
+            }
+
+            return Some(entity);
+        }
+    }
+}
+
+/// A [`QueryData`] that fetches `D` from the target entity of the `R` [`Relationship`].
+///
+/// Only entities with the `R` component are matched. The item is `None` if the target entity
+/// doesn't match `D`; use [`RelatedWith`] to filter those entities out instead.
+///
+/// The components read on target entities are declared as unfiltered access (see
+/// [`FilteredAccess::unfiltered_access`]): the target may not match the query's filters, so
+/// `Query<Related<ChildOf, &Transform>, With<Player>>` conflicts with
+/// `Query<&mut Transform, Without<Player>>` and the systems using them won't run in parallel.
+/// For the same reason, a query can't also mutably access the components it reads through [`Related`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::relationship::Related;
+/// #[derive(Component)]
+/// struct Speed(f32);
+///
+/// fn inherit_speed(query: Query<(Entity, Related<ChildOf, &Speed>)>) {
+///     for (entity, parent_speed) in &query {
+///         if let Some(Speed(speed)) = parent_speed {
+///             println!("{entity} moves at {speed} like its parent");
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(inherit_speed);
+/// ```
+pub struct Related<R: Relationship, D: ReadOnlyQueryData>(PhantomData<(R, D)>);
+
+/// A [`QueryFilter`] that matches entities with the `R` [`Relationship`] whose target entity matches `F`.
+///
+/// Like [`Related`], the components read on target entities are declared as unfiltered access.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::relationship::RelatedWith;
+/// #[derive(Component)]
+/// struct Hidden;
+///
+/// #[derive(Component)]
+/// struct Visible(bool);
+///
+/// fn hide_children(mut query: Query<&mut Visible, RelatedWith<ChildOf, With<Hidden>>>) {
+///     for mut visible in &mut query {
+///         visible.0 = false;
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(hide_children);
+/// ```
+pub struct RelatedWith<R: Relationship, F: QueryFilter>(PhantomData<(R, F)>);
+
+/// The [`WorldQuery::Fetch`] type for [`Related`] and [`RelatedWith`].
+pub struct RelatedFetch<'w, R: Relationship, Q: WorldQuery> {
+    relationship: <&'static R as WorldQuery>::Fetch<'w>,
+    target: Q::Fetch<'w>,
+    state: Q::State,
+    world: UnsafeWorldCell<'w>,
+    // The archetype `target` was last set to, and whether it matches `Q`.
+    archetype: Option<(ArchetypeId, bool)>,
+}
+
+impl<R: Relationship, Q: WorldQuery> Clone for RelatedFetch<'_, R, Q>
+where
+    Q::State: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            relationship: self.relationship,
+            target: self.target.clone(),
+            state: self.state.clone(),
+            world: self.world,
+            archetype: self.archetype,
+        }
+    }
+}
+
+impl<'w, R: Relationship, Q: WorldQuery> RelatedFetch<'w, R, Q> {
+    /// Prepares `target` to fetch the target entity of the relationship of `entity`, returning
+    /// the target entity and its table row if it matches `Q`.
+    ///
+    /// # Safety
+    ///
+    /// - `entity` and `table_row` must be in the range of the current table and archetype.
+    /// - The `R` fetch must have been set to the archetype or table of `entity`.
+    #[inline]
+    unsafe fn set_target(
+        &mut self,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Option<(Entity, TableRow)> {
+        // SAFETY: The invariants are upheld by the caller.
+        let relationship =
+            unsafe { <&R as QueryData>::fetch(&mut self.relationship, entity, table_row) };
+        let target = relationship.get();
+        let location = self.world.entities().get(target)?;
+        let matches = match self.archetype {
+            Some((archetype_id, matches)) if archetype_id == location.archetype_id => matches,
+            _ => {
+                // SAFETY: The location was just fetched from the world's entities.
+                let archetype = unsafe {
+                    self.world
+                        .archetypes()
+                        .get(location.archetype_id)
+                        .debug_checked_unwrap()
+                };
+                let matches = Q::matches_component_set(&self.state, &|id| archetype.contains(id));
+                if matches {
+                    // SAFETY:
+                    // - The table was just fetched from the entity's location in the world.
+                    // - Access to the components of `Q` was registered as unfiltered access.
+                    unsafe {
+                        let table = self
+                            .world
+                            .storages()
+                            .tables
+                            .get(location.table_id)
+                            .debug_checked_unwrap();
+                        Q::set_archetype(&mut self.target, &self.state, archetype, table);
+                    }
+                }
+                self.archetype = Some((location.archetype_id, matches));
+                matches
+            }
+        };
+        matches.then_some((target, location.table_row))
+    }
+}
+
+macro_rules! impl_related_world_query {
+    ($name:ident, $query:ident, $query_trait:ident) => {
+        /// SAFETY:
+        /// `update_component_access` adds read access and a `With` filter for `R`, which is the only component
+        /// fetched from the matched entities, and `matches_component_set` returns whether the set contains `R`.
+        /// The components accessed by the inner query on target entities are added as unfiltered access,
+        /// which `QueryState` registers for every archetype.
+        unsafe impl<R: Relationship, $query: $query_trait> WorldQuery for $name<R, $query>
+        where
+            $query::State: Clone,
+        {
+            type Fetch<'w> = RelatedFetch<'w, R, $query>;
+            type State = (ComponentId, $query::State);
+
+            fn shrink_fetch<'wlong: 'wshort, 'wshort>(
+                fetch: Self::Fetch<'wlong>,
+            ) -> Self::Fetch<'wshort> {
+                RelatedFetch {
+                    relationship: fetch.relationship,
+                    target: $query::shrink_fetch(fetch.target),
+                    state: fetch.state,
+                    world: fetch.world,
+                    archetype: fetch.archetype,
+                }
+            }
+
+            #[inline]
+            unsafe fn init_fetch<'w>(
+                world: UnsafeWorldCell<'w>,
+                state: &Self::State,
+                last_run: Tick,
+                this_run: Tick,
+            ) -> Self::Fetch<'w> {
+                RelatedFetch {
+                    // SAFETY: The invariants are upheld by the caller.
+                    relationship: unsafe {
+                        <&R as WorldQuery>::init_fetch(world, &state.0, last_run, this_run)
+                    },
+                    // SAFETY: The invariants are upheld by the caller.
+                    target: unsafe { $query::init_fetch(world, &state.1, last_run, this_run) },
+                    state: state.1.clone(),
+                    world,
+                    archetype: None,
+                }
+            }
+
+            const IS_DENSE: bool = <&R as WorldQuery>::IS_DENSE;
+
+            #[inline]
+            unsafe fn set_archetype<'w>(
+                fetch: &mut Self::Fetch<'w>,
+                state: &Self::State,
+                archetype: &'w Archetype,
+                table: &'w Table,
+            ) {
+                // SAFETY: The invariants are upheld by the caller.
+                unsafe {
+                    <&R as WorldQuery>::set_archetype(
+                        &mut fetch.relationship,
+                        &state.0,
+                        archetype,
+                        table,
+                    );
+                }
+            }
+
+            #[inline]
+            unsafe fn set_table<'w>(
+                fetch: &mut Self::Fetch<'w>,
+                state: &Self::State,
+                table: &'w Table,
+            ) {
+                // SAFETY: The invariants are upheld by the caller.
+                unsafe { <&R as WorldQuery>::set_table(&mut fetch.relationship, &state.0, table) };
+            }
+
+            fn update_component_access(
+                state: &Self::State,
+                access: &mut FilteredAccess<ComponentId>,
+            ) {
+                <&R as WorldQuery>::update_component_access(&state.0, access);
+                let mut related_access = FilteredAccess::default();
+                $query::update_component_access(&state.1, &mut related_access);
+                access.extend_unfiltered_access(&related_access);
+            }
+
+            fn init_state(world: &mut World) -> Self::State {
+                (
+                    <&R as WorldQuery>::init_state(world),
+                    $query::init_state(world),
+                )
+            }
+
+            fn get_state(components: &Components) -> Option<Self::State> {
+                Some((
+                    <&R as WorldQuery>::get_state(components)?,
+                    $query::get_state(components)?,
+                ))
+            }
+
+            fn matches_component_set(
+                state: &Self::State,
+                set_contains_id: &impl Fn(ComponentId) -> bool,
+            ) -> bool {
+                <&R as WorldQuery>::matches_component_set(&state.0, set_contains_id)
+            }
+        }
+    };
+}
+
+impl_related_world_query!(Related, D, ReadOnlyQueryData);
+impl_related_world_query!(RelatedWith, F, QueryFilter);
+
+/// SAFETY: `Self` is the same as `Self::ReadOnly`
+unsafe impl<R: Relationship, D: ReadOnlyQueryData> QueryData for Related<R, D>
+where
+    D::State: Clone,
+{
+    const IS_READ_ONLY: bool = true;
+    type ReadOnly = Self;
+    type Item<'w> = Option<D::Item<'w>>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item.map(D::shrink)
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The invariants are upheld by the caller.
+        let (target, target_row) = unsafe { fetch.set_target(entity, table_row) }?;
+        // SAFETY: `set_target` set the archetype of `fetch.target` to the one of `target`.
+        Some(unsafe { D::fetch(&mut fetch.target, target, target_row) })
+    }
+}
+
+/// SAFETY: access is read only
+unsafe impl<R: Relationship, D: ReadOnlyQueryData> ReadOnlyQueryData for Related<R, D> where
+    D::State: Clone
+{
+}
+
+/// SAFETY: `filter_fetch` only reads `R` and the components of `F` on the target entity,
+/// which are declared in `update_component_access`.
+unsafe impl<R: Relationship, F: QueryFilter> QueryFilter for RelatedWith<R, F>
+where
+    F::State: Clone,
+{
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { fetch.set_target(entity, table_row) }.is_some_and(|(target, target_row)| {
+            // SAFETY: `set_target` set the archetype of `fetch.target` to the one of `target`.
+            unsafe { F::filter_fetch(&mut fetch.target, target, target_row) }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Related, RelatedWith};
+    use crate::{
+        component::Component,
+        entity::Entity,
+        hierarchy::ChildOf,
+        prelude::{With, Without},
+        query::{Access, QueryState},
+        system::{IntoSystem, Query, RunSystemOnce, System},
+        world::World,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component, PartialEq, Debug)]
+    struct A(u32);
+
+    #[derive(Component)]
+    struct B;
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn related_fetches_from_target() {
+        let mut world = World::new();
+        let parent = world.spawn(A(1)).id();
+        let empty_parent = world.spawn_empty().id();
+        let child = world.spawn((A(2), ChildOf(parent))).id();
+        let other_child = world.spawn(ChildOf(empty_parent)).id();
+        let grandchild = world.spawn(ChildOf(child)).id();
+
+        let mut query = world.query::<Related<ChildOf, &A>>();
+        assert_eq!(query.get(&world, child).unwrap(), Some(&A(1)));
+        assert_eq!(query.get(&world, other_child).unwrap(), None);
+        assert_eq!(query.get(&world, grandchild).unwrap(), Some(&A(2)));
+        assert!(query.get(&world, parent).is_err());
+        assert_eq!(query.iter(&world).count(), 3);
+    }
+
+    #[test]
+    fn related_with_filters_by_target() {
+        let mut world = World::new();
+        let parent = world.spawn((A(1), Marker)).id();
+        let other_parent = world.spawn(A(2)).id();
+        let child = world.spawn(ChildOf(parent)).id();
+        let other_child = world.spawn(ChildOf(other_parent)).id();
+
+        let mut query = world.query_filtered::<Entity, RelatedWith<ChildOf, With<Marker>>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [child]);
+
+        let mut query = world.query_filtered::<Entity, RelatedWith<ChildOf, Without<Marker>>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [other_child]);
+    }
+
+    #[test]
+    fn related_access_is_not_ruled_out_by_filters() {
+        let mut world = World::new();
+        let related = QueryState::<Related<ChildOf, &A>, With<Marker>>::new(&mut world);
+        let write_a = QueryState::<&mut A, Without<Marker>>::new(&mut world);
+        let write_b = QueryState::<&mut B, Without<Marker>>::new(&mut world);
+        assert!(!related
+            .component_access
+            .is_compatible(&write_a.component_access));
+        assert!(related
+            .component_access
+            .is_compatible(&write_b.component_access));
+
+        let filter =
+            QueryState::<(), (With<Marker>, RelatedWith<ChildOf, With<A>>)>::new(&mut world);
+        assert!(filter
+            .component_access
+            .is_compatible(&write_a.component_access));
+    }
+
+    #[test]
+    fn related_access_covers_unmatched_archetypes() {
+        let mut world = World::new();
+        world.spawn(A(1));
+        let mut related = QueryState::<Related<ChildOf, &A>>::new(&mut world);
+        let a = world.register_component::<A>();
+
+        let mut access = Access::default();
+        for archetype in world.archetypes().iter() {
+            // SAFETY: the archetype comes from the world the state was initialized from.
+            unsafe { related.new_archetype(archetype, &mut access) };
+        }
+        let archetype = world
+            .archetypes()
+            .iter()
+            .find(|archetype| archetype.contains(a) && archetype.len() == 1)
+            .unwrap();
+        assert!(access.has_component_read(archetype.get_archetype_component_id(a).unwrap()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn related_access_conflicts_with_own_write() {
+        let mut world = World::new();
+        QueryState::<(&mut A, Related<ChildOf, &A>)>::new(&mut world);
+    }
+
+    #[test]
+    #[should_panic]
+    fn related_access_conflicts_across_disjoint_queries() {
+        let mut world = World::new();
+        world
+            .run_system_once(
+                |_: Query<Related<ChildOf, &A>, With<Marker>>,
+                 _: Query<&mut A, Without<Marker>>| {},
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn related_archetype_access_includes_existing_archetypes() {
+        let mut world = World::new();
+        let parent = world.spawn(A(1)).id();
+        world.spawn(ChildOf(parent));
+
+        // The archetypes exist before the systems are initialized, so their access is only recorded by
+        // the initialization, which the multi-threaded executor relies on to run systems in parallel.
+        let mut related = IntoSystem::into_system(|_: Query<Related<ChildOf, &A>>| {});
+        let mut write = IntoSystem::into_system(|_: Query<&mut A>| {});
+        related.initialize(&mut world);
+        write.initialize(&mut world);
+        assert!(!related
+            .archetype_component_access()
+            .is_compatible(write.archetype_component_access()));
+    }
+}
@@ -0,0 +1,490 @@
+//! Structured reports of the ambiguities of a [`Schedule`].
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use bevy_utils::write_json_string;
+use core::fmt::{self, Write};
+use disqualified::ShortName;
+
+use crate::{
+    component::ComponentId,
+    schedule::{NodeId, Schedule, ScheduleBuildError},
+    system::ScheduleSystem,
+    world::World,
+};
+
+/// A structured report of the pairs of systems of a [`Schedule`] with conflicting data access
+/// and an indeterminate execution order, returned by [`Schedule::analyze`].
+///
+/// Unlike [`ScheduleBuildSettings::ambiguity_detection`](crate::schedule::ScheduleBuildSettings::ambiguity_detection),
+/// which only logs the ambiguities, this can be inspected, compared against a previous analysis with
+/// [`ScheduleAnalysis::new_ambiguities`] or exported with [`ScheduleAnalysis::to_json`] and
+/// [`ScheduleAnalysis::to_dot`], for example to fail CI when new ambiguities are introduced.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleAnalysis {
+    /// The name of the label of the analyzed schedule.
+    pub schedule: String,
+    /// The ambiguous pairs of systems, in the order of their first system in the schedule.
+    pub ambiguities: Vec<SystemAmbiguity>,
+}
+
+/// A pair of systems with conflicting data access and no ordering between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemAmbiguity {
+    /// The name of the system that currently runs first when the schedule runs on a single thread.
+    pub first: String,
+    /// The name of the other system.
+    pub second: String,
+    /// The data the systems conflict on.
+    pub conflicts: Vec<AccessConflict>,
+    /// The ordering that would resolve this ambiguity while keeping the current single-threaded order.
+    pub suggestion: SuggestedEdge,
+}
+
+/// A piece of data that two [ambiguous](SystemAmbiguity) systems both access, with at least one of them mutably.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessConflict {
+    /// What kind of data the systems conflict on.
+    pub kind: ConflictKind,
+    /// The name of the component or resource, or `None` for [`ConflictKind::World`].
+    pub name: Option<String>,
+    /// How the first system accesses the data.
+    pub first_access: AccessKind,
+    /// How the second system accesses the data.
+    pub second_access: AccessKind,
+}
+
+/// The kind of data of an [`AccessConflict`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConflictKind {
+    /// A component.
+    Component,
+    /// A resource.
+    Resource,
+    /// The whole [`World`], when a system is exclusive or accesses all entities.
+    World,
+}
+
+/// How a system accesses the data of an [`AccessConflict`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccessKind {
+    /// The system reads the data.
+    Read,
+    /// The system writes to the data.
+    Write,
+}
+
+/// An ordering constraint between two systems, to be added with
+/// [`before`](crate::schedule::IntoScheduleConfigs::before) or
+/// [`after`](crate::schedule::IntoScheduleConfigs::after).
+///
+/// Its [`Display`](fmt::Display) implementation reads as `before.before(after)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuggestedEdge {
+    /// The name of the system that should run first.
+    pub before: String,
+    /// The name of the system that should run second.
+    pub after: String,
+}
+
+impl fmt::Display for SuggestedEdge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.before({})", self.before, self.after)
+    }
+}
+
+impl Schedule {
+    /// Initializes the schedule and returns a [`ScheduleAnalysis`] of its ambiguities.
+    ///
+    /// Ambiguities ignored with [`ambiguous_with`](crate::schedule::IntoScheduleConfigs::ambiguous_with),
+    /// [`ambiguous_with_all`](crate::schedule::IntoScheduleConfigs::ambiguous_with_all) or
+    /// [`World::allow_ambiguous_component`] are not reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule fails to build, which includes the case where
+    /// [`ScheduleBuildSettings::ambiguity_detection`](crate::schedule::ScheduleBuildSettings::ambiguity_detection)
+    /// is set to [`LogLevel::Error`](crate::schedule::LogLevel::Error) and there are ambiguities.
+    pub fn analyze(&mut self, world: &mut World) -> Result<ScheduleAnalysis, ScheduleBuildError> {
+        self.initialize(world)?;
+
+        // The systems, in the order of the single-threaded executor.
+        let systems: Vec<(NodeId, &ScheduleSystem)> = self
+            .systems()
+            .map_err(|_| ScheduleBuildError::Uninitialized)?
+            .collect();
+        let position = |id: &NodeId| systems.iter().position(|(other, _)| other == id).unwrap();
+        let use_shortnames = self.get_build_settings().use_shortnames;
+        let name = |system: &ScheduleSystem| {
+            let name = system.name();
+            if use_shortnames {
+                ShortName(&name).to_string()
+            } else {
+                name.to_string()
+            }
+        };
+
+        let mut ambiguities: Vec<_> = self
+            .graph()
+            .conflicting_systems()
+            .iter()
+            .map(|(a, b, conflicts)| {
+                let (first, second) = if position(a) <= position(b) {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                let first_system = systems[position(first)].1;
+                let second_system = systems[position(second)].1;
+                let first_name = name(first_system);
+                let second_name = name(second_system);
+
+                let conflicts = if conflicts.is_empty() {
+                    vec![AccessConflict {
+                        kind: ConflictKind::World,
+                        name: None,
+                        first_access: world_access(first_system),
+                        second_access: world_access(second_system),
+                    }]
+                } else {
+                    conflicts
+                        .iter()
+                        .map(|&id| {
+                            let kind = if first_system.component_access().has_resource_read(id)
+                                || second_system.component_access().has_resource_read(id)
+                            {
+                                ConflictKind::Resource
+                            } else {
+                                ConflictKind::Component
+                            };
+                            AccessConflict {
+                                kind,
+                                name: world.components().get_name(id).map(|name| name.to_string()),
+                                first_access: data_access(first_system, kind, id),
+                                second_access: data_access(second_system, kind, id),
+                            }
+                        })
+                        .collect()
+                };
+
+                (
+                    position(first),
+                    SystemAmbiguity {
+                        suggestion: SuggestedEdge {
+                            before: first_name.clone(),
+                            after: second_name.clone(),
+                        },
+                        first: first_name,
+                        second: second_name,
+                        conflicts,
+                    },
+                )
+            })
+            .collect();
+        ambiguities.sort_by_key(|(position, _)| *position);
+
+        Ok(ScheduleAnalysis {
+            schedule: format!("{:?}", self.label()),
+            ambiguities: ambiguities
+                .into_iter()
+                .map(|(_, ambiguity)| ambiguity)
+                .collect(),
+        })
+    }
+}
+
+fn world_access(system: &ScheduleSystem) -> AccessKind {
+    if system.is_exclusive() || system.component_access().has_any_write() {
+        AccessKind::Write
+    } else {
+        AccessKind::Read
+    }
+}
+
+fn data_access(system: &ScheduleSystem, kind: ConflictKind, id: ComponentId) -> AccessKind {
+    let access = system.component_access();
+    let write = match kind {
+        ConflictKind::Resource => access.has_resource_write(id),
+        _ => access.has_component_write(id),
+    };
+    if system.is_exclusive() || write {
+        AccessKind::Write
+    } else {
+        AccessKind::Read
+    }
+}
+
+impl SystemAmbiguity {
+    /// Returns `true` if this is an ambiguity between the systems named `a` and `b`, in any order.
+    pub fn is_between(&self, a: &str, b: &str) -> bool {
+        (self.first == a && self.second == b) || (self.first == b && self.second == a)
+    }
+}
+
+impl ScheduleAnalysis {
+    /// Returns `true` if the schedule has no ambiguities.
+    pub fn is_empty(&self) -> bool {
+        self.ambiguities.is_empty()
+    }
+
+    /// Iterates over the ambiguities between pairs of systems that are not ambiguous in `baseline`,
+    /// typically an analysis of the same schedule from a previous version of the app.
+    pub fn new_ambiguities<'a>(
+        &'a self,
+        baseline: &'a ScheduleAnalysis,
+    ) -> impl Iterator<Item = &'a SystemAmbiguity> + 'a {
+        self.ambiguities.iter().filter(|ambiguity| {
+            !baseline
+                .ambiguities
+                .iter()
+                .any(|other| other.is_between(&ambiguity.first, &ambiguity.second))
+        })
+    }
+
+    /// Returns the suggested orderings that would resolve every ambiguity.
+    pub fn suggested_edges(&self) -> impl Iterator<Item = &SuggestedEdge> {
+        self.ambiguities
+            .iter()
+            .map(|ambiguity| &ambiguity.suggestion)
+    }
+
+    /// Exports this analysis to JSON.
+    ///
+    /// The format matches the one of `serde_json` with the `serialize` feature enabled,
+    /// so that a previous analysis can be deserialized to be passed to [`ScheduleAnalysis::new_ambiguities`].
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"schedule\":");
+        write_json_string(&mut json, &self.schedule);
+        json.push_str(",\"ambiguities\":[");
+        for (i, ambiguity) in self.ambiguities.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"first\":");
+            write_json_string(&mut json, &ambiguity.first);
+            json.push_str(",\"second\":");
+            write_json_string(&mut json, &ambiguity.second);
+            json.push_str(",\"conflicts\":[");
+            for (j, conflict) in ambiguity.conflicts.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                write!(json, "{{\"kind\":\"{:?}\",\"name\":", conflict.kind).unwrap();
+                match &conflict.name {
+                    Some(name) => write_json_string(&mut json, name),
+                    None => json.push_str("null"),
+                }
+                write!(
+                    json,
+                    ",\"first_access\":\"{:?}\",\"second_access\":\"{:?}\"}}",
+                    conflict.first_access, conflict.second_access
+                )
+                .unwrap();
+            }
+            json.push_str("],\"suggestion\":{\"before\":");
+            write_json_string(&mut json, &ambiguity.suggestion.before);
+            json.push_str(",\"after\":");
+            write_json_string(&mut json, &ambiguity.suggestion.after);
+            json.push_str("}}");
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Exports this analysis to the Graphviz DOT format.
+    ///
+    /// Each ambiguity is drawn as a dashed edge along its suggested ordering,
+    /// labeled with the data the systems conflict on.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_id(&self.schedule)).unwrap();
+        dot.push_str("  node [shape=box];\n");
+        for ambiguity in &self.ambiguities {
+            let label = ambiguity
+                .conflicts
+                .iter()
+                .map(|conflict| match &conflict.name {
+                    Some(name) => format!("{name} ({:?})", conflict.kind),
+                    None => format!("{:?}", conflict.kind),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            writeln!(
+                dot,
+                "  {} -> {} [style=dashed, color=red, label={}];",
+                dot_id(&ambiguity.suggestion.before),
+                dot_id(&ambiguity.suggestion.after),
+                dot_id(&label),
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Returns `value` as a quoted DOT identifier.
+pub(super) fn dot_id(value: &str) -> String {
+    let mut id = String::with_capacity(value.len() + 2);
+    id.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => id.push_str("\\\""),
+            '\\' => id.push_str("\\\\"),
+            '\n' => id.push_str("\\n"),
+            c => id.push(c),
+        }
+    }
+    id.push('"');
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessConflict, AccessKind, ConflictKind, ScheduleAnalysis, SuggestedEdge};
+    use crate::{
+        component::Component,
+        prelude::{Query, Res, ResMut, Resource, World},
+        schedule::{IntoScheduleConfigs, Schedule},
+    };
+    use alloc::string::ToString;
+
+    #[derive(Resource, Default)]
+    struct Score(u32);
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    fn add_score(mut score: ResMut<Score>) {
+        score.0 += 1;
+    }
+
+    fn show_score(_score: Res<Score>) {}
+
+    fn heal(mut query: Query<&mut Health>) {
+        for mut health in &mut query {
+            health.0 += 1;
+        }
+    }
+
+    fn show_health(_query: Query<&Health>) {}
+
+    fn exclusive(_world: &mut World) {}
+
+    #[test]
+    fn reports_conflicts_and_suggestions() {
+        let mut world = World::new();
+        world.init_resource::<Score>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((add_score, show_score, heal, show_health.after(heal)));
+
+        let analysis = schedule.analyze(&mut world).unwrap();
+        assert_eq!(analysis.ambiguities.len(), 1);
+        let ambiguity = &analysis.ambiguities[0];
+        assert!(ambiguity.is_between("show_score", "add_score"));
+        assert_eq!(
+            ambiguity.conflicts,
+            [AccessConflict {
+                kind: ConflictKind::Resource,
+                name: Some(core::any::type_name::<Score>().to_string()),
+                first_access: if ambiguity.first == "add_score" {
+                    AccessKind::Write
+                } else {
+                    AccessKind::Read
+                },
+                second_access: if ambiguity.first == "add_score" {
+                    AccessKind::Read
+                } else {
+                    AccessKind::Write
+                },
+            }]
+        );
+        assert_eq!(
+            ambiguity.suggestion,
+            SuggestedEdge {
+                before: ambiguity.first.clone(),
+                after: ambiguity.second.clone(),
+            }
+        );
+
+        // Following the suggestion resolves the ambiguity.
+        let mut schedule = Schedule::default();
+        if ambiguity.first == "add_score" {
+            schedule.add_systems((add_score.before(show_score), show_score));
+        } else {
+            schedule.add_systems((add_score, show_score.before(add_score)));
+        }
+        assert!(schedule.analyze(&mut world).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_world_conflicts_and_new_ambiguities() {
+        let mut world = World::new();
+        world.init_resource::<Score>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((heal, show_health));
+        let baseline = schedule.analyze(&mut world).unwrap();
+        assert_eq!(baseline.ambiguities.len(), 1);
+        assert_eq!(
+            baseline.ambiguities[0].conflicts[0].kind,
+            ConflictKind::Component
+        );
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((heal, show_health, exclusive));
+        let analysis = schedule.analyze(&mut world).unwrap();
+        assert_eq!(analysis.ambiguities.len(), 3);
+        let new: alloc::vec::Vec<_> = analysis.new_ambiguities(&baseline).collect();
+        assert_eq!(new.len(), 2);
+        for ambiguity in new {
+            assert!(ambiguity.first == "exclusive" || ambiguity.second == "exclusive");
+            assert_eq!(ambiguity.conflicts[0].kind, ConflictKind::World);
+            assert_eq!(ambiguity.conflicts[0].name, None);
+        }
+    }
+
+    #[test]
+    fn exports() {
+        let analysis = ScheduleAnalysis {
+            schedule: "Update".to_string(),
+            ambiguities: alloc::vec![super::SystemAmbiguity {
+                first: "a".to_string(),
+                second: "b \"quoted\"".to_string(),
+                conflicts: alloc::vec![AccessConflict {
+                    kind: ConflictKind::Resource,
+                    name: Some("R".to_string()),
+                    first_access: AccessKind::Write,
+                    second_access: AccessKind::Read,
+                }],
+                suggestion: SuggestedEdge {
+                    before: "a".to_string(),
+                    after: "b \"quoted\"".to_string(),
+                },
+            }],
+        };
+
+        assert_eq!(
+            analysis.to_json(),
+            r#"{"schedule":"Update","ambiguities":[{"first":"a","second":"b \"quoted\"","conflicts":[{"kind":"Resource","name":"R","first_access":"Write","second_access":"Read"}],"suggestion":{"before":"a","after":"b \"quoted\""}}]}"#
+        );
+        assert_eq!(
+            analysis.to_dot(),
+            "digraph \"Update\" {\n  node [shape=box];\n  \"a\" -> \"b \\\"quoted\\\"\" [style=dashed, color=red, label=\"R (Resource)\"];\n}\n"
+        );
+        assert_eq!(
+            analysis.ambiguities[0].suggestion.to_string(),
+            "a.before(b \"quoted\")"
+        );
+    }
+}
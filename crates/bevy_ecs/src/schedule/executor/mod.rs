@@ -4,7 +4,7 @@ mod simple;
 mod single_threaded;
 
 use alloc::{borrow::Cow, vec, vec::Vec};
-use core::any::TypeId;
+use core::{any::TypeId, time::Duration};
 
 pub use self::{simple::SimpleExecutor, single_threaded::SingleThreadedExecutor};
 
@@ -58,6 +58,17 @@ pub enum ExecutorKind {
     #[cfg(feature = "std")]
     #[cfg_attr(all(not(target_arch = "wasm32"), feature = "multi_threaded"), default)]
     MultiThreaded,
+    /// Like [`MultiThreaded`](ExecutorKind::MultiThreaded), but the outcome of running the schedule
+    /// does not depend on how systems happen to be scheduled across threads.
+    ///
+    /// Systems with conflicting access, including ambiguous ones, always run in the topological order
+    /// of the schedule, as do systems with [deferred](crate::system::System::has_deferred) buffers
+    /// (which can reserve entities). Deferred buffers are applied in that same order, at the same
+    /// sync points on each run. Systems that don't conflict still run in parallel.
+    ///
+    /// Useful for lockstep networking and replays, which need identical world states across runs.
+    #[cfg(feature = "std")]
+    DeterministicMultiThreaded,
 }
 
 /// Holds systems and conditions of a [`Schedule`](super::Schedule) sorted in topological order
@@ -82,10 +93,6 @@ pub struct SystemSchedule {
     pub(super) system_dependencies: Vec<usize>,
     /// Indexed by system node id.
     /// List of systems that immediately depend on the system.
-    #[cfg_attr(
-        not(feature = "std"),
-        expect(dead_code, reason = "currently only used with the std feature")
-    )]
     pub(super) system_dependents: Vec<Vec<usize>>,
     /// Indexed by system node id.
     /// List of sets containing the system that have conditions
@@ -99,6 +106,15 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Whether executors measure the time spent running each system into `system_durations`.
+    pub(super) record_durations: bool,
+    /// Indexed by system node id.
+    /// Time spent running the system during the last run, if it ran while `record_durations` was set.
+    pub(super) system_durations: Vec<Option<Duration>>,
+    /// List of the named system sets, with the systems they contain.
+    ///
+    /// The time spent running a set is the sum of the time spent running these systems.
+    pub(super) timed_sets: Vec<(InternedSystemSet, FixedBitSet)>,
 }
 
 impl SystemSchedule {
@@ -114,6 +130,9 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            record_durations: false,
+            system_durations: Vec::new(),
+            timed_sets: Vec::new(),
         }
     }
 }
@@ -324,10 +343,11 @@ mod tests {
     #[derive(Component)]
     struct TestComponent;
 
-    const EXECUTORS: [ExecutorKind; 3] = [
+    const EXECUTORS: [ExecutorKind; 4] = [
         ExecutorKind::Simple,
         ExecutorKind::SingleThreaded,
         ExecutorKind::MultiThreaded,
+        ExecutorKind::DeterministicMultiThreaded,
     ];
 
     #[derive(Resource, Default)]
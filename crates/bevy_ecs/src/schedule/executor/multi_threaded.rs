@@ -1,9 +1,9 @@
-use alloc::{boxed::Box, vec::Vec};
-use bevy_platform::sync::Arc;
+use alloc::{boxed::Box, vec, vec::Vec};
+use bevy_platform::{sync::Arc, time::Instant};
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
 use concurrent_queue::ConcurrentQueue;
-use core::{any::Any, panic::AssertUnwindSafe};
+use core::{any::Any, panic::AssertUnwindSafe, time::Duration};
 use fixedbitset::FixedBitSet;
 #[cfg(feature = "std")]
 use std::eprintln;
@@ -14,6 +14,7 @@ use tracing::{info_span, Span};
 
 use crate::{
     archetype::ArchetypeComponentId,
+    component::ComponentId,
     error::{default_error_handler, BevyError, ErrorContext, Result},
     prelude::Resource,
     query::Access,
@@ -30,6 +31,7 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<ScheduleSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    record_durations: bool,
 }
 
 struct Conditions<'a> {
@@ -55,6 +57,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
             world_cell: world.as_unsafe_world_cell(),
+            record_durations: schedule.record_durations,
         }
     }
 }
@@ -70,11 +73,16 @@ struct SystemTaskMetadata {
     is_send: bool,
     /// Is `true` if the system is exclusive.
     is_exclusive: bool,
+    /// Indices of the systems before this one in topological order that it conflicts with.
+    /// Only computed when the executor is deterministic.
+    conflicting_predecessors: FixedBitSet,
 }
 
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// The time spent running the system, if it is recorded.
+    duration: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -88,6 +96,8 @@ pub struct MultiThreadedExecutor {
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
     starting_systems: FixedBitSet,
+    /// Whether conflicting systems are run in topological order.
+    deterministic: bool,
     /// Cached tracing span
     #[cfg(feature = "trace")]
     executor_span: Span,
@@ -121,6 +131,10 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// Whether conflicting systems are run in topological order.
+    deterministic: bool,
+    /// Time spent running each system, if it is recorded.
+    system_durations: Vec<Option<Duration>>,
 }
 
 /// References to data required by the executor.
@@ -142,7 +156,11 @@ impl Default for MultiThreadedExecutor {
 
 impl SystemExecutor for MultiThreadedExecutor {
     fn kind(&self) -> ExecutorKind {
-        ExecutorKind::MultiThreaded
+        if self.deterministic {
+            ExecutorKind::DeterministicMultiThreaded
+        } else {
+            ExecutorKind::MultiThreaded
+        }
     }
 
     fn init(&mut self, schedule: &SystemSchedule) {
@@ -161,13 +179,21 @@ impl SystemExecutor for MultiThreadedExecutor {
         state.skipped_systems = FixedBitSet::with_capacity(sys_count);
         state.unapplied_systems = FixedBitSet::with_capacity(sys_count);
 
+        state.deterministic = self.deterministic;
+        let conflicting_predecessors = if self.deterministic {
+            conflicting_predecessors(schedule)
+        } else {
+            vec![FixedBitSet::new(); sys_count]
+        };
+
         state.system_task_metadata = Vec::with_capacity(sys_count);
-        for index in 0..sys_count {
+        for (index, conflicting_predecessors) in conflicting_predecessors.into_iter().enumerate() {
             state.system_task_metadata.push(SystemTaskMetadata {
                 archetype_component_access: default(),
                 dependents: schedule.system_dependents[index].clone(),
                 is_send: schedule.systems[index].is_send(),
                 is_exclusive: schedule.systems[index].is_exclusive(),
+                conflicting_predecessors,
             });
             if schedule.system_dependencies[index] == 0 {
                 self.starting_systems.insert(index);
@@ -194,6 +220,10 @@ impl SystemExecutor for MultiThreadedExecutor {
             .num_dependencies_remaining
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
+        state.system_durations.clear();
+        if schedule.record_durations {
+            state.system_durations.resize(schedule.systems.len(), None);
+        }
 
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -249,6 +279,12 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        if schedule.record_durations {
+            schedule
+                .system_durations
+                .clone_from(&state.system_durations);
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -272,6 +308,7 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
     fn system_completed(
         &self,
         system_index: usize,
+        duration: Option<Duration>,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
     ) {
@@ -279,7 +316,10 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                duration,
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             #[cfg(feature = "std")]
@@ -333,12 +373,23 @@ impl MultiThreadedExecutor {
             state: Mutex::new(ExecutorState::new()),
             system_completion: ConcurrentQueue::unbounded(),
             starting_systems: FixedBitSet::new(),
+            deterministic: false,
             apply_final_deferred: true,
             panic_payload: Mutex::new(None),
             #[cfg(feature = "trace")]
             executor_span: info_span!("multithreaded executor"),
         }
     }
+
+    /// Creates a new `multi_threaded` executor that runs conflicting systems in topological order.
+    ///
+    /// See [`ExecutorKind::DeterministicMultiThreaded`] for details.
+    pub fn deterministic() -> Self {
+        Self {
+            deterministic: true,
+            ..Self::new()
+        }
+    }
 }
 
 impl ExecutorState {
@@ -357,6 +408,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            deterministic: false,
+            system_durations: Vec::new(),
         }
     }
 
@@ -482,10 +535,29 @@ impl ExecutorState {
             return false;
         }
 
+        if self.deterministic
+            && !system_meta
+                .conflicting_predecessors
+                .is_subset(&self.completed_systems)
+        {
+            return false;
+        }
+
         // TODO: an earlier out if world's archetypes did not change
         for set_idx in conditions.sets_with_conditions_of_systems[system_index]
             .difference(&self.evaluated_sets)
         {
+            // Set conditions are evaluated by the first system of the set that runs, so evaluate them
+            // with the first system of the set in topological order to always see the same world.
+            if self.deterministic
+                && !conditions.systems_in_sets_with_conditions[set_idx]
+                    .ones()
+                    .take_while(|&index| index < system_index)
+                    .all(|index| self.completed_systems.contains(index))
+            {
+                return false;
+            }
+
             for condition in &mut conditions.set_conditions[set_idx] {
                 condition.update_archetype_component_access(world);
                 if !condition
@@ -624,6 +696,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.record_durations.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -645,7 +718,8 @@ impl ExecutorState {
                     }
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let duration = start.map(|start| start.elapsed());
+            context.system_completed(system_index, duration, res, system);
         };
 
         self.active_access
@@ -676,7 +750,7 @@ impl ExecutorState {
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, None, res, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -685,6 +759,7 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context.environment.record_durations.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
                         (context.error_handler)(
@@ -696,7 +771,8 @@ impl ExecutorState {
                         );
                     }
                 }));
-                context.system_completed(system_index, res, system);
+                let duration = start.map(|start| start.elapsed());
+                context.system_completed(system_index, duration, res, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -707,7 +783,14 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            duration,
+        } = result;
+
+        if duration.is_some() {
+            self.system_durations[system_index] = duration;
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -752,6 +835,46 @@ impl ExecutorState {
     }
 }
 
+/// Returns, for each system, the systems before it in topological order that it conflicts with.
+///
+/// Systems conflict if their accesses (including the ones of their conditions) are incompatible,
+/// if either is exclusive, or if both have deferred buffers, since they may reserve entities.
+fn conflicting_predecessors(schedule: &SystemSchedule) -> Vec<FixedBitSet> {
+    let sys_count = schedule.systems.len();
+    let accesses: Vec<Access<ComponentId>> = (0..sys_count)
+        .map(|index| {
+            let mut access = schedule.systems[index].component_access().clone();
+            for condition in &schedule.system_conditions[index] {
+                access.extend(condition.component_access());
+            }
+            for set_idx in schedule.sets_with_conditions_of_systems[index].ones() {
+                for condition in &schedule.set_conditions[set_idx] {
+                    access.extend(condition.component_access());
+                }
+            }
+            access
+        })
+        .collect();
+
+    (0..sys_count)
+        .map(|index| {
+            let system = &schedule.systems[index];
+            let mut predecessors = FixedBitSet::with_capacity(sys_count);
+            for other_index in 0..index {
+                let other = &schedule.systems[other_index];
+                if system.is_exclusive()
+                    || other.is_exclusive()
+                    || (system.has_deferred() && other.has_deferred())
+                    || !accesses[index].is_compatible(&accesses[other_index])
+                {
+                    predecessors.insert(other_index);
+                }
+            }
+            predecessors
+        })
+        .collect()
+}
+
 fn apply_deferred(
     unapplied_systems: &FixedBitSet,
     systems: &[SyncUnsafeCell<ScheduleSystem>],
@@ -844,15 +967,115 @@ impl MainThreadExecutor {
 #[cfg(test)]
 mod tests {
     use crate::{
+        component::Component,
+        entity::Entity,
         prelude::Resource,
-        schedule::{ExecutorKind, IntoScheduleConfigs, Schedule},
-        system::Commands,
+        schedule::{ExecutorKind, IntoScheduleConfigs, Schedule, SystemSet},
+        system::{Commands, Query, ResMut},
         world::World,
     };
+    use alloc::vec::Vec;
+    use core::{hint::black_box, time::Duration};
 
     #[derive(Resource)]
     struct R;
 
+    #[derive(Resource, Default)]
+    struct Log(Vec<u32>);
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<u32>);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Value(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Counter(u32);
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    enum Step {
+        Spawn,
+        Update,
+    }
+
+    /// Runs a schedule of ambiguous systems on a new world, returning its resulting state.
+    fn run_ambiguous_systems(kind: ExecutorKind) -> (Vec<u32>, Vec<u32>, Vec<(Entity, u32, u32)>) {
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        world.init_resource::<Order>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(kind);
+        schedule.configure_sets((Step::Spawn, Step::Update).chain());
+        // Holds `Log` long enough for the systems only accessing `Order` to be run first,
+        // unless the executor is deterministic.
+        schedule.add_systems(
+            (|mut log: ResMut<Log>| {
+                std::thread::sleep(Duration::from_millis(2));
+                log.0.push(100);
+            })
+            .in_set(Step::Spawn),
+        );
+        for i in 0..8 {
+            // Conflicting systems, spawning entities through commands.
+            schedule.add_systems(
+                (
+                    move |mut log: ResMut<Log>,
+                          mut order: ResMut<Order>,
+                          mut commands: Commands| {
+                        log.0.push(i);
+                        order.0.push(i);
+                        commands.spawn((Value(i), Counter(0)));
+                    },
+                    move |mut order: ResMut<Order>| order.0.push(100 + i),
+                )
+                    .in_set(Step::Spawn),
+            );
+            // Systems conflicting on `Counter` or `Log`, among systems that don't conflict.
+            schedule.add_systems(
+                (
+                    move |mut query: Query<&mut Counter>| {
+                        for mut counter in &mut query {
+                            counter.0 = counter.0.wrapping_mul(31).wrapping_add(i);
+                        }
+                    },
+                    move |query: Query<&Value>| {
+                        black_box(query.iter().map(|value| value.0 + i).sum::<u32>());
+                    },
+                    move |query: Query<&Counter>, mut log: ResMut<Log>| {
+                        log.0
+                            .push(query.iter().map(|counter| counter.0 % 100).sum());
+                    },
+                )
+                    .in_set(Step::Update),
+            );
+        }
+
+        for _ in 0..3 {
+            schedule.run(&mut world);
+        }
+
+        let log = world.remove_resource::<Log>().unwrap().0;
+        let order = world.remove_resource::<Order>().unwrap().0;
+        let mut entities: Vec<_> = world
+            .query::<(Entity, &Value, &Counter)>()
+            .iter(&world)
+            .map(|(entity, value, counter)| (entity, value.0, counter.0))
+            .collect();
+        entities.sort_by_key(|(entity, ..)| *entity);
+        (log, order, entities)
+    }
+
+    #[test]
+    fn deterministic_executor_gives_identical_world_states() {
+        let expected = run_ambiguous_systems(ExecutorKind::SingleThreaded);
+        for _ in 0..20 {
+            assert_eq!(
+                run_ambiguous_systems(ExecutorKind::DeterministicMultiThreaded),
+                expected
+            );
+        }
+    }
+
     #[test]
     fn skipped_systems_notify_dependents() {
         let mut world = World::new();
@@ -1,3 +1,4 @@
+use bevy_platform::time::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -119,6 +120,7 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            let start = schedule.record_durations.then(Instant::now);
             let f = AssertUnwindSafe(|| {
                 if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
                     error_handler(
@@ -144,6 +146,10 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            if let Some(start) = start {
+                schedule.system_durations[system_index] = Some(start.elapsed());
+            }
         }
 
         self.evaluated_sets.clear();
@@ -1,3 +1,4 @@
+use bevy_platform::time::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -127,6 +128,7 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            let start = schedule.record_durations.then(Instant::now);
             let f = AssertUnwindSafe(|| {
                 if system.is_exclusive() {
                     if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
@@ -172,6 +174,10 @@ impl SystemExecutor for SingleThreadedExecutor {
                 (f)();
             }
 
+            if let Some(start) = start {
+                schedule.system_durations[system_index] = Some(start.elapsed());
+            }
+
             self.unapplied_systems.insert(system_index);
         }
 
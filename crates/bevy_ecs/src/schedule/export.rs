@@ -0,0 +1,417 @@
+//! Visual exports of the graph of a [`Schedule`].
+
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use bevy_platform::collections::{HashMap, HashSet};
+use core::{fmt::Write, time::Duration};
+use disqualified::ShortName;
+
+use crate::schedule::{
+    analysis::dot_id, executor::is_apply_deferred, graph::Direction, BoxedCondition, NodeId,
+    Schedule, ScheduleNotInitialized,
+};
+
+/// The formats a [`Schedule`] can be exported to with [`Schedule::export_graph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScheduleGraphFormat {
+    /// The [Graphviz](https://graphviz.org/) DOT format.
+    Dot,
+    /// A [Mermaid](https://mermaid.js.org/) flowchart.
+    Mermaid,
+}
+
+/// Options for [`Schedule::export_graph`].
+#[derive(Clone, Debug, Default)]
+pub struct ScheduleGraphSettings {
+    /// The time spent running each system, keyed by its full [`System::name`](crate::system::System::name).
+    ///
+    /// Systems found in this map are annotated with their time, in milliseconds.
+    pub timings: HashMap<String, Duration>,
+}
+
+impl ScheduleGraphSettings {
+    /// Annotates the system named `name` with the time spent running it.
+    pub fn with_timing(mut self, name: impl Into<String>, duration: Duration) -> Self {
+        self.timings.insert(name.into(), duration);
+        self
+    }
+}
+
+/// A system set drawn as a cluster around the systems and sets it contains.
+struct Cluster {
+    lines: Vec<String>,
+    parent: Option<usize>,
+}
+
+/// A system, or a sync point applying deferred buffers.
+struct Node {
+    lines: Vec<String>,
+    sync_point: bool,
+    parent: Option<usize>,
+}
+
+/// The parts of a built [`Schedule`] that are drawn.
+struct ScheduleGraphExport {
+    name: String,
+    clusters: Vec<Cluster>,
+    nodes: Vec<Node>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Schedule {
+    /// Exports the systems of this schedule, in the given `format`.
+    ///
+    /// Systems are connected by their ordering dependencies, once those between system sets have been
+    /// flattened. System sets are drawn as clusters around the systems and sets they contain, nested
+    /// in the first set that contains them if they belong to several. The sync points applying
+    /// deferred buffers, including the ones inserted by
+    /// [`AutoInsertApplyDeferredPass`](crate::schedule::passes::AutoInsertApplyDeferredPass), are drawn
+    /// as their own shape. Systems and sets are labeled with their run conditions, and systems with
+    /// the [timings](ScheduleGraphSettings::timings) found in `settings`.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn export_graph(
+        &self,
+        format: ScheduleGraphFormat,
+        settings: &ScheduleGraphSettings,
+    ) -> Result<String, ScheduleNotInitialized> {
+        let export = self.graph_export(settings)?;
+        Ok(match format {
+            ScheduleGraphFormat::Dot => export.to_dot(),
+            ScheduleGraphFormat::Mermaid => export.to_mermaid(),
+        })
+    }
+
+    fn graph_export(
+        &self,
+        settings: &ScheduleGraphSettings,
+    ) -> Result<ScheduleGraphExport, ScheduleNotInitialized> {
+        let systems: Vec<_> = self.systems()?.collect();
+        let executable = self.executable();
+        let graph = self.graph();
+        let use_shortnames = self.get_build_settings().use_shortnames;
+        let shorten = |name: &str| {
+            if use_shortnames {
+                ShortName(name).to_string()
+            } else {
+                name.to_string()
+            }
+        };
+        let run_if = |conditions: &[BoxedCondition]| {
+            conditions
+                .iter()
+                .map(|condition| format!("run_if: {}", shorten(&condition.name())))
+                .collect::<Vec<_>>()
+        };
+
+        // The conditions of sets were moved to the executable schedule when it was built.
+        let set_conditions: HashMap<NodeId, &[BoxedCondition]> = executable
+            .set_ids
+            .iter()
+            .zip(&executable.set_conditions)
+            .map(|(&id, conditions)| (id, conditions.as_slice()))
+            .collect();
+        // System type sets only contain their system, and anonymous sets without conditions
+        // only group systems to configure them together.
+        let is_cluster = |id: NodeId| {
+            graph.get_set_at(id).is_some_and(|set| {
+                set.system_type().is_none()
+                    && (!set.is_anonymous() || set_conditions.contains_key(&id))
+            })
+        };
+        // Finds the closest set drawn as a cluster containing `id`.
+        let cluster_of = |id: NodeId| {
+            let hierarchy = graph.hierarchy().graph();
+            let mut visited = HashSet::new();
+            let mut queue: VecDeque<NodeId> = hierarchy
+                .neighbors_directed(id, Direction::Incoming)
+                .collect();
+            while let Some(parent) = queue.pop_front() {
+                if is_cluster(parent) {
+                    return Some(parent);
+                }
+                if visited.insert(parent) {
+                    queue.extend(hierarchy.neighbors_directed(parent, Direction::Incoming));
+                }
+            }
+            None
+        };
+
+        let mut clusters = Vec::new();
+        let mut cluster_indices = HashMap::<NodeId, usize>::new();
+        let mut add_cluster = |id: NodeId| -> usize {
+            // Sets are added after the sets containing them.
+            let mut chain = vec![id];
+            while let Some(parent) = cluster_of(*chain.last().unwrap()) {
+                if cluster_indices.contains_key(&parent) {
+                    break;
+                }
+                chain.push(parent);
+            }
+            for &set_id in chain.iter().rev() {
+                if cluster_indices.contains_key(&set_id) {
+                    continue;
+                }
+                let set = graph.set_at(set_id);
+                let mut lines = vec![if set.is_anonymous() {
+                    "anonymous set".to_string()
+                } else {
+                    format!("{set:?}")
+                }];
+                lines.extend(run_if(set_conditions.get(&set_id).copied().unwrap_or(&[])));
+                let parent = cluster_of(set_id).map(|parent| cluster_indices[&parent]);
+                cluster_indices.insert(set_id, clusters.len());
+                clusters.push(Cluster { lines, parent });
+            }
+            cluster_indices[&id]
+        };
+
+        let nodes = systems
+            .iter()
+            .zip(&executable.system_conditions)
+            .map(|(&(id, system), conditions)| {
+                let sync_point = is_apply_deferred(system);
+                let name = system.name();
+                let mut lines = vec![if sync_point {
+                    "apply_deferred".to_string()
+                } else {
+                    shorten(&name)
+                }];
+                lines.extend(run_if(conditions));
+                if let Some(duration) = settings.timings.get(name.as_ref()) {
+                    lines.push(format!("{:.3} ms", duration.as_secs_f64() * 1000.0));
+                }
+                Node {
+                    lines,
+                    sync_point,
+                    parent: cluster_of(id).map(&mut add_cluster),
+                }
+            })
+            .collect();
+
+        let edges = executable
+            .system_dependents
+            .iter()
+            .enumerate()
+            .flat_map(|(i, dependents)| dependents.iter().map(move |&j| (i, j)))
+            .collect();
+
+        Ok(ScheduleGraphExport {
+            name: format!("{:?}", self.label()),
+            clusters,
+            nodes,
+            edges,
+        })
+    }
+}
+
+impl ScheduleGraphExport {
+    fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_id(&self.name)).unwrap();
+        dot.push_str("  rankdir=LR;\n  node [shape=box];\n");
+        self.write_dot_cluster(&mut dot, None, 1);
+        for &(from, to) in &self.edges {
+            writeln!(dot, "  system_{from} -> system_{to};").unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_dot_cluster(&self, dot: &mut String, cluster: Option<usize>, depth: usize) {
+        let indent = "  ".repeat(depth);
+        for (i, child) in self.clusters.iter().enumerate() {
+            if child.parent != cluster {
+                continue;
+            }
+            writeln!(dot, "{indent}subgraph cluster_{i} {{").unwrap();
+            writeln!(
+                dot,
+                "{indent}  label={};\n{indent}  style=rounded;",
+                dot_id(&child.lines.join("\n"))
+            )
+            .unwrap();
+            self.write_dot_cluster(dot, Some(i), depth + 1);
+            writeln!(dot, "{indent}}}").unwrap();
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.parent != cluster {
+                continue;
+            }
+            let label = dot_id(&node.lines.join("\n"));
+            if node.sync_point {
+                writeln!(
+                    dot,
+                    "{indent}system_{i} [label={label}, shape=hexagon, style=dashed];"
+                )
+                .unwrap();
+            } else {
+                writeln!(dot, "{indent}system_{i} [label={label}];").unwrap();
+            }
+        }
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut mermaid = String::new();
+        writeln!(mermaid, "---\ntitle: {}\n---", mermaid_text(&self.name)).unwrap();
+        mermaid.push_str("flowchart LR\n");
+        self.write_mermaid_cluster(&mut mermaid, None, 1);
+        for &(from, to) in &self.edges {
+            writeln!(mermaid, "  system_{from} --> system_{to}").unwrap();
+        }
+        mermaid
+    }
+
+    fn write_mermaid_cluster(&self, mermaid: &mut String, cluster: Option<usize>, depth: usize) {
+        let indent = "  ".repeat(depth);
+        for (i, child) in self.clusters.iter().enumerate() {
+            if child.parent != cluster {
+                continue;
+            }
+            writeln!(
+                mermaid,
+                "{indent}subgraph cluster_{i}[\"{}\"]",
+                mermaid_label(&child.lines)
+            )
+            .unwrap();
+            self.write_mermaid_cluster(mermaid, Some(i), depth + 1);
+            writeln!(mermaid, "{indent}end").unwrap();
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.parent != cluster {
+                continue;
+            }
+            let label = mermaid_label(&node.lines);
+            if node.sync_point {
+                writeln!(mermaid, "{indent}system_{i}{{{{\"{label}\"}}}}").unwrap();
+            } else {
+                writeln!(mermaid, "{indent}system_{i}[\"{label}\"]").unwrap();
+            }
+        }
+    }
+}
+
+/// Joins `lines` into the text of a quoted Mermaid label.
+fn mermaid_label(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|line| mermaid_text(line))
+        .collect::<Vec<_>>()
+        .join("<br/>")
+}
+
+/// Escapes the characters Mermaid would otherwise interpret in `value`.
+fn mermaid_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => text.push_str("#quot;"),
+            '<' => text.push_str("#lt;"),
+            '>' => text.push_str("#gt;"),
+            '#' => text.push_str("#35;"),
+            '\n' => text.push_str("<br/>"),
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{
+        prelude::*,
+        schedule::{ScheduleGraphFormat, ScheduleGraphSettings, ScheduleNotInitialized},
+    };
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    enum Sets {
+        Outer,
+        Inner,
+    }
+
+    #[derive(Resource)]
+    struct Enabled;
+
+    fn spawner(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    fn reader(_query: Query<Entity>) {}
+
+    fn other() {}
+
+    fn enabled() -> bool {
+        true
+    }
+
+    fn build_schedule(world: &mut World) -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Sets::Inner.in_set(Sets::Outer));
+        schedule.configure_sets(Sets::Outer.run_if(resource_exists::<Enabled>));
+        schedule.add_systems((
+            (spawner, reader).chain().in_set(Sets::Inner),
+            other.run_if(enabled),
+        ));
+        schedule.initialize(world).unwrap();
+        schedule
+    }
+
+    #[test]
+    fn export_requires_initialization() {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(other);
+        assert!(matches!(
+            schedule.export_graph(ScheduleGraphFormat::Dot, &Default::default()),
+            Err(ScheduleNotInitialized)
+        ));
+    }
+
+    #[test]
+    fn export_dot() {
+        let mut world = World::new();
+        let schedule = build_schedule(&mut world);
+        let settings = ScheduleGraphSettings::default().with_timing(
+            core::any::type_name_of_val(&other),
+            Duration::from_micros(1500),
+        );
+        let dot = schedule
+            .export_graph(ScheduleGraphFormat::Dot, &settings)
+            .unwrap();
+
+        assert!(dot.starts_with("digraph \"DefaultSchedule\" {"));
+        // `Inner` is nested in `Outer`, which is labeled with its condition.
+        let outer = dot.find("label=\"Outer\\nrun_if: resource_exists<Enabled>\"");
+        let inner = dot.find("label=\"Inner\"");
+        assert!(outer.is_some() && inner.is_some() && outer < inner);
+        assert_eq!(dot.matches("subgraph").count(), 2);
+        // The sync point inserted between `spawner` and `reader`.
+        assert_eq!(dot.matches("label=\"apply_deferred\"").count(), 1);
+        assert!(dot.contains("shape=hexagon"));
+        assert!(dot.contains("label=\"other\\nrun_if: enabled\\n1.500 ms\""));
+        assert_eq!(dot.matches(" -> ").count(), 2);
+    }
+
+    #[test]
+    fn export_mermaid() {
+        let mut world = World::new();
+        let schedule = build_schedule(&mut world);
+        let mermaid = schedule
+            .export_graph(ScheduleGraphFormat::Mermaid, &Default::default())
+            .unwrap();
+
+        assert!(mermaid.contains("flowchart LR"));
+        assert!(mermaid.contains("[\"Outer<br/>run_if: resource_exists#lt;Enabled#gt;\"]"));
+        assert_eq!(mermaid.matches("subgraph").count(), 2);
+        assert_eq!(mermaid.matches("\n    end").count(), 1);
+        assert_eq!(mermaid.matches("{{\"apply_deferred\"}}").count(), 1);
+        assert_eq!(mermaid.matches(" --> ").count(), 2);
+    }
+}
@@ -1,16 +1,21 @@
 //! Contains APIs for ordering systems and executing them on a [`World`](crate::world::World)
 
+mod analysis;
 mod auto_insert_apply_deferred;
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod pass;
 mod schedule;
 mod set;
 mod stepping;
+mod timings;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{
+    analysis::*, condition::*, config::*, executor::*, export::*, schedule::*, set::*, timings::*,
+};
 pub use pass::ScheduleBuildPass;
 
 pub use self::graph::NodeId;
@@ -1252,5 +1257,11 @@ mod tests {
         fn multi_threaded_executor() {
             assert_executor_supports_stepping!(ExecutorKind::MultiThreaded);
         }
+
+        /// verify the deterministic [`MultiThreadedExecutor`] supports stepping
+        #[test]
+        fn deterministic_multi_threaded_executor() {
+            assert_executor_supports_stepping!(ExecutorKind::DeterministicMultiThreaded);
+        }
     }
 }
@@ -222,6 +222,10 @@ fn make_executor(kind: ExecutorKind) -> Box<dyn SystemExecutor> {
         ExecutorKind::SingleThreaded => Box::new(SingleThreadedExecutor::new()),
         #[cfg(feature = "std")]
         ExecutorKind::MultiThreaded => Box::new(MultiThreadedExecutor::new()),
+        #[cfg(feature = "std")]
+        ExecutorKind::DeterministicMultiThreaded => {
+            Box::new(MultiThreadedExecutor::deterministic())
+        }
     }
 }
 
@@ -443,6 +447,15 @@ impl Schedule {
 
         let error_handler = default_error_handler();
 
+        let record_durations = world.contains_resource::<SystemTimings>();
+        self.executable.record_durations = record_durations;
+        self.executable.system_durations.clear();
+        if record_durations {
+            self.executable
+                .system_durations
+                .resize(self.executable.systems.len(), None);
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor
             .run(&mut self.executable, world, None, error_handler);
@@ -461,6 +474,12 @@ impl Schedule {
                 error_handler,
             );
         }
+
+        if record_durations {
+            if let Some(mut timings) = world.get_resource_mut::<SystemTimings>() {
+                timings.record(self.label, &self.executable);
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -1484,6 +1503,28 @@ impl ScheduleGraph {
             }
         }
 
+        // get the systems contained in each named set
+        // (needed to measure the time spent running sets)
+        let timed_sets = self
+            .hierarchy
+            .topsort
+            .iter()
+            .enumerate()
+            .filter(|&(_i, id)| {
+                id.is_set()
+                    && !self.system_sets[id.index()].is_system_type()
+                    && !self.system_sets[id.index()].is_anonymous()
+            })
+            .map(|(row, id)| {
+                let mut bitset = FixedBitSet::with_capacity(sys_count);
+                for &(col, sys_id) in &hg_systems {
+                    let idx = dg_system_idx_map[&sys_id];
+                    bitset.set(idx, hier_results_reachable[index(row, col, hg_node_count)]);
+                }
+                (self.system_sets[id.index()].inner, bitset)
+            })
+            .collect();
+
         SystemSchedule {
             systems: Vec::with_capacity(sys_count),
             system_conditions: Vec::with_capacity(sys_count),
@@ -1494,6 +1535,9 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            record_durations: false,
+            system_durations: Vec::new(),
+            timed_sets,
         }
     }
 
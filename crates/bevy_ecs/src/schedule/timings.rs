@@ -0,0 +1,179 @@
+//! Opt-in measurements of the time spent running the systems of each [`Schedule`](super::Schedule).
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::time::Duration;
+
+use crate::{
+    resource::Resource,
+    schedule::{is_apply_deferred, InternedScheduleLabel, InternedSystemSet, SystemSchedule},
+};
+
+/// A [`Resource`] that, while it exists in a [`World`](crate::world::World), makes the schedules run on it
+/// measure the time spent running each of their systems and named system sets.
+///
+/// A [`SystemTimingSample`] is added each time a system runs, so samples accumulate until they are
+/// [drained](SystemTimings::drain), typically once per frame.
+///
+/// The sync points applying deferred buffers, which include the buffers of many systems,
+/// and the run conditions, are not measured.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{SystemTimings, TimingTarget};
+/// fn system() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemTimings>();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(system);
+/// schedule.run(&mut world);
+///
+/// let mut timings = world.resource_mut::<SystemTimings>();
+/// let sample = timings.drain().next().unwrap();
+/// assert_eq!(sample.target, TimingTarget::System(core::any::type_name_of_val(&system).into()));
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct SystemTimings {
+    samples: Vec<SystemTimingSample>,
+}
+
+/// The time spent running a system or system set during a single run of a schedule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SystemTimingSample {
+    /// The label of the schedule that ran.
+    pub schedule: InternedScheduleLabel,
+    /// The measured system or system set.
+    pub target: TimingTarget,
+    /// The time spent running it.
+    pub duration: Duration,
+}
+
+/// What a [`SystemTimingSample`] measures.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TimingTarget {
+    /// A system, identified by its [`System::name`].
+    System(Cow<'static, str>),
+    /// A named system set.
+    ///
+    /// Its time is the sum of the time spent running the systems it contains, so it can be more than
+    /// the time that elapsed if the multi-threaded executor ran some of them in parallel.
+    Set(InternedSystemSet),
+}
+
+impl SystemTimings {
+    /// Returns the samples recorded since they were last drained.
+    pub fn samples(&self) -> &[SystemTimingSample] {
+        &self.samples
+    }
+
+    /// Removes and returns the samples recorded since they were last drained.
+    pub fn drain(&mut self) -> impl Iterator<Item = SystemTimingSample> + '_ {
+        self.samples.drain(..)
+    }
+
+    /// Records the durations measured during the last run of `executable`.
+    pub(super) fn record(&mut self, schedule: InternedScheduleLabel, executable: &SystemSchedule) {
+        for (system, duration) in executable.systems.iter().zip(&executable.system_durations) {
+            if let Some(duration) = *duration {
+                if !is_apply_deferred(system) {
+                    self.samples.push(SystemTimingSample {
+                        schedule,
+                        target: TimingTarget::System(system.name()),
+                        duration,
+                    });
+                }
+            }
+        }
+
+        for (set, systems) in &executable.timed_sets {
+            let mut ran = false;
+            let mut duration = Duration::ZERO;
+            for index in systems.ones() {
+                if let Some(system_duration) = executable.system_durations[index] {
+                    ran = true;
+                    duration += system_duration;
+                }
+            }
+            if ran {
+                self.samples.push(SystemTimingSample {
+                    schedule,
+                    target: TimingTarget::Set(*set),
+                    duration,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, SystemTimings, TimingTarget},
+    };
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Timed;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Slow;
+
+    fn sleepy() {
+        std::thread::sleep(Duration::from_millis(2));
+    }
+
+    fn spawner(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    fn skipped() {}
+
+    #[test]
+    fn timings_are_recorded_for_systems_and_sets() {
+        for kind in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            let mut schedule = Schedule::new(Timed);
+            schedule.set_executor_kind(kind);
+            schedule.add_systems((
+                (sleepy, spawner).chain().in_set(Slow),
+                skipped.run_if(|| false),
+            ));
+
+            // Nothing is measured until the resource exists.
+            schedule.run(&mut world);
+            world.init_resource::<SystemTimings>();
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+
+            let samples: Vec<_> = world.resource_mut::<SystemTimings>().drain().collect();
+            let count = |target: &TimingTarget| {
+                samples
+                    .iter()
+                    .filter(|sample| &sample.target == target)
+                    .count()
+            };
+            let sleepy = TimingTarget::System(core::any::type_name_of_val(&sleepy).into());
+            let spawner = TimingTarget::System(core::any::type_name_of_val(&spawner).into());
+            let slow = TimingTarget::Set(Slow.intern());
+            assert_eq!(count(&sleepy), 2, "{kind:?}");
+            assert_eq!(count(&spawner), 2, "{kind:?}");
+            assert_eq!(count(&slow), 2, "{kind:?}");
+            // Only the two systems and the set are measured, not the sync point nor the skipped system.
+            assert_eq!(samples.len(), 6, "{kind:?}");
+            assert!(samples
+                .iter()
+                .all(|sample| sample.schedule == Timed.intern()));
+
+            let set_sample = samples.iter().find(|sample| sample.target == slow).unwrap();
+            assert!(set_sample.duration >= Duration::from_millis(2), "{kind:?}");
+            assert!(world.resource::<SystemTimings>().samples().is_empty());
+        }
+    }
+}
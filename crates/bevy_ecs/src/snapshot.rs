@@ -0,0 +1,545 @@
+//! Snapshots of selected components and resources, for rolling the [`World`] back to an earlier
+//! tick.
+//!
+//! Components and resources opt into snapshots by being registered in the [`SnapshotRegistry`]
+//! resource, which requires them to implement [`Clone`]. [`World::save_snapshot`] then clones
+//! their current values into the [`Snapshots`] ring buffer, keyed by tick, and
+//! [`World::restore_snapshot`] writes them back.
+//!
+//! Restoring a snapshot preserves entity IDs: entities despawned since the snapshot are respawned
+//! with their original [`Entity`], so that any stored reference to them remains valid.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::snapshot::{SnapshotRegistry, Snapshots};
+//! #[derive(Component, Clone, PartialEq, Debug)]
+//! struct Position(f32);
+//!
+//! let mut world = World::new();
+//! world.init_resource::<SnapshotRegistry>();
+//! world.resource_mut::<SnapshotRegistry>().register_component::<Position>();
+//! world.insert_resource(Snapshots::new(8));
+//!
+//! let entity = world.spawn(Position(0.0)).id();
+//! world.save_snapshot(0);
+//!
+//! world.entity_mut(entity).despawn();
+//! world.restore_snapshot(0).unwrap();
+//! assert_eq!(world.get::<Position>(entity), Some(&Position(0.0)));
+//! ```
+
+use crate::{
+    archetype::ArchetypeEntity,
+    component::{Component, ComponentId},
+    entity::{hash_set::EntityHashSet, Entity},
+    resource::Resource,
+    world::{Mut, World},
+};
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::any::{Any, TypeId};
+use thiserror::Error;
+
+/// The components and resources captured by [`World::save_snapshot`].
+///
+/// Types are registered with [`register_component`](Self::register_component) and
+/// [`register_resource`](Self::register_resource), in any order. Registering a type that is
+/// already registered does nothing.
+#[derive(Resource, Default, Clone)]
+pub struct SnapshotRegistry {
+    components: Vec<ComponentSnapshotFns>,
+    resources: Vec<ResourceSnapshotFns>,
+}
+
+impl SnapshotRegistry {
+    /// Registers the component `C` to be captured by snapshots.
+    pub fn register_component<C: Component + Clone>(&mut self) -> &mut Self {
+        if !self.contains_component::<C>() {
+            self.components.push(ComponentSnapshotFns::of::<C>());
+        }
+        self
+    }
+
+    /// Registers the resource `R` to be captured by snapshots.
+    pub fn register_resource<R: Resource + Clone>(&mut self) -> &mut Self {
+        if !self.contains_resource::<R>() {
+            self.resources.push(ResourceSnapshotFns::of::<R>());
+        }
+        self
+    }
+
+    /// Returns `true` if the component `C` is captured by snapshots.
+    pub fn contains_component<C: Component>(&self) -> bool {
+        let type_id = TypeId::of::<C>();
+        self.components.iter().any(|fns| fns.type_id == type_id)
+    }
+
+    /// Returns `true` if the resource `R` is captured by snapshots.
+    pub fn contains_resource<R: Resource>(&self) -> bool {
+        let type_id = TypeId::of::<R>();
+        self.resources.iter().any(|fns| fns.type_id == type_id)
+    }
+}
+
+type ErasedValue = Box<dyn Any + Send + Sync>;
+
+#[derive(Clone)]
+struct ComponentSnapshotFns {
+    type_id: TypeId,
+    component_id: fn(&World) -> Option<ComponentId>,
+    capture: fn(&World, Option<ComponentId>) -> ErasedValue,
+    entities: fn(&ErasedValue) -> &[Entity],
+    restore: fn(&mut World, &ErasedValue),
+}
+
+impl ComponentSnapshotFns {
+    fn of<C: Component + Clone>() -> Self {
+        Self {
+            type_id: TypeId::of::<C>(),
+            component_id: World::component_id::<C>,
+            capture: |world, component_id| {
+                let values: ComponentValues<C> = entities_with(world, component_id)
+                    .filter_map(|entity| Some((entity, world.get::<C>(entity)?.clone())))
+                    .unzip();
+                Box::new(values)
+            },
+            entities: |values| &values.downcast_ref::<ComponentValues<C>>().unwrap().0,
+            restore: |world, values| {
+                let (entities, components) = values.downcast_ref::<ComponentValues<C>>().unwrap();
+                let captured: EntityHashSet = entities.iter().copied().collect();
+                let removed: Vec<Entity> = entities_with(world, world.component_id::<C>())
+                    .filter(|entity| !captured.contains(entity))
+                    .collect();
+                for entity in removed {
+                    world.entity_mut(entity).remove::<C>();
+                }
+                for (&entity, component) in entities.iter().zip(components) {
+                    world.entity_mut(entity).insert(component.clone());
+                }
+            },
+        }
+    }
+}
+
+/// The entities having a component, and the values of that component.
+type ComponentValues<C> = (Vec<Entity>, Vec<C>);
+
+#[derive(Clone)]
+struct ResourceSnapshotFns {
+    type_id: TypeId,
+    capture: fn(&World) -> Option<ErasedValue>,
+    restore: fn(&mut World, Option<&ErasedValue>),
+}
+
+impl ResourceSnapshotFns {
+    fn of<R: Resource + Clone>() -> Self {
+        Self {
+            type_id: TypeId::of::<R>(),
+            capture: |world| {
+                world
+                    .get_resource::<R>()
+                    .map(|resource| Box::new(resource.clone()) as ErasedValue)
+            },
+            restore: |world, value| match value {
+                Some(value) => world.insert_resource(value.downcast_ref::<R>().unwrap().clone()),
+                None => {
+                    world.remove_resource::<R>();
+                }
+            },
+        }
+    }
+}
+
+/// Iterates over the entities having the component `component_id`.
+fn entities_with(
+    world: &World,
+    component_id: Option<ComponentId>,
+) -> impl Iterator<Item = Entity> + '_ {
+    component_id.into_iter().flat_map(|component_id| {
+        world
+            .archetypes()
+            .iter()
+            .filter(move |archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+    })
+}
+
+/// The values of the registered components and resources of a [`World`] at a given tick.
+pub struct WorldSnapshot {
+    tick: u64,
+    alive: EntityHashSet,
+    entities: EntityHashSet,
+    components: Vec<ErasedValue>,
+    resources: Vec<Option<ErasedValue>>,
+}
+
+impl WorldSnapshot {
+    /// Captures the values of the components and resources registered in `registry`.
+    pub fn capture(world: &World, registry: &SnapshotRegistry, tick: u64) -> Self {
+        let components: Vec<_> = registry
+            .components
+            .iter()
+            .map(|fns| (fns.capture)(world, (fns.component_id)(world)))
+            .collect();
+        let entities = registry
+            .components
+            .iter()
+            .zip(&components)
+            .flat_map(|(fns, values)| (fns.entities)(values).iter().copied())
+            .collect();
+        let resources = registry
+            .resources
+            .iter()
+            .map(|fns| (fns.capture)(world))
+            .collect();
+        let alive = world
+            .archetypes()
+            .iter()
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect();
+        Self {
+            tick,
+            alive,
+            entities,
+            components,
+            resources,
+        }
+    }
+
+    /// The tick this snapshot was captured at.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// The entities that had at least one of the captured components.
+    pub fn entities(&self) -> &EntityHashSet {
+        &self.entities
+    }
+
+    /// Restores the values of the captured components and resources.
+    ///
+    /// Entities spawned since the snapshot was captured are despawned if they have registered
+    /// components, or if they reuse the index of a captured entity. Entities that existed when the
+    /// snapshot was captured are kept, and only lose the registered components they didn't have.
+    /// Captured entities despawned since are respawned with their original [`Entity`].
+    ///
+    /// `registry` must be the registry the snapshot was captured with, or one with more types
+    /// registered since. Components and resources registered after the snapshot was captured are
+    /// left untouched.
+    pub fn restore(&self, world: &mut World, registry: &SnapshotRegistry) {
+        let components = &registry.components[..self.components.len()];
+        world.flush();
+
+        let mut spawned = EntityHashSet::default();
+        for fns in components {
+            spawned.extend(
+                entities_with(world, (fns.component_id)(world))
+                    .filter(|entity| !self.alive.contains(entity)),
+            );
+        }
+        // The index of a despawned entity can only have been reused since the capture.
+        spawned.extend(
+            self.entities
+                .iter()
+                .filter(|&&entity| world.entities().get(entity).is_none())
+                .filter_map(|entity| world.entities().resolve_from_id(entity.index()))
+                .filter(|&entity| world.entities().get(entity).is_some()),
+        );
+        for entity in spawned {
+            // Despawning an entity may have despawned others already.
+            if world.entities().get(entity).is_some() {
+                world.despawn(entity);
+            }
+        }
+
+        let despawned: Vec<Entity> = self
+            .entities
+            .iter()
+            .copied()
+            .filter(|&entity| world.entities().get(entity).is_none())
+            .collect();
+        world.spawn_empty_at(&despawned);
+
+        for (fns, values) in components.iter().zip(&self.components) {
+            (fns.restore)(world, values);
+        }
+
+        for (fns, value) in registry.resources.iter().zip(&self.resources) {
+            (fns.restore)(world, value.as_ref());
+        }
+    }
+}
+
+/// A ring buffer of the most recent [`WorldSnapshot`]s, ordered by tick.
+#[derive(Resource)]
+pub struct Snapshots {
+    snapshots: VecDeque<WorldSnapshot>,
+    capacity: usize,
+}
+
+impl Default for Snapshots {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl Snapshots {
+    /// The [`capacity`](Self::capacity) of the [default](Default) buffer.
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// Creates an empty buffer keeping the `capacity` most recent snapshots.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// The maximum number of snapshots kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of snapshots kept.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns `true` if no snapshot is kept.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Adds the `snapshot`, forgetting the oldest one if the buffer is full.
+    ///
+    /// Snapshots of the same or later ticks are forgotten, as they belong to a timeline the
+    /// `snapshot` replaces.
+    pub fn push(&mut self, snapshot: WorldSnapshot) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self
+            .snapshots
+            .back()
+            .is_some_and(|last| last.tick >= snapshot.tick)
+        {
+            self.snapshots.pop_back();
+        }
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    /// Returns the snapshot captured at `tick`, if it is kept.
+    pub fn get(&self, tick: u64) -> Option<&WorldSnapshot> {
+        let index = self
+            .snapshots
+            .binary_search_by_key(&tick, WorldSnapshot::tick)
+            .ok()?;
+        self.snapshots.get(index)
+    }
+
+    /// Returns the most recent snapshot, if any.
+    pub fn latest(&self) -> Option<&WorldSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Returns the oldest snapshot kept, if any.
+    pub fn oldest(&self) -> Option<&WorldSnapshot> {
+        self.snapshots.front()
+    }
+
+    /// Iterates over the snapshots kept, from the oldest to the most recent.
+    pub fn iter(&self) -> impl Iterator<Item = &WorldSnapshot> {
+        self.snapshots.iter()
+    }
+
+    /// Forgets every snapshot.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+/// An error that occurs when restoring a [`WorldSnapshot`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// No snapshot of the tick is kept in [`Snapshots`].
+    #[error("No snapshot of tick {0} is kept")]
+    MissingSnapshot(u64),
+}
+
+impl World {
+    /// Captures the components and resources registered in the [`SnapshotRegistry`] at `tick`,
+    /// and adds the snapshot to [`Snapshots`].
+    ///
+    /// Initializes both resources if they don't exist yet.
+    pub fn save_snapshot(&mut self, tick: u64) {
+        let registry = self.get_resource_or_init::<SnapshotRegistry>().clone();
+        let snapshot = WorldSnapshot::capture(self, &registry, tick);
+        self.get_resource_or_init::<Snapshots>().push(snapshot);
+    }
+
+    /// Restores the snapshot captured at `tick` from [`Snapshots`].
+    ///
+    /// See [`WorldSnapshot::restore`] for more information.
+    pub fn restore_snapshot(&mut self, tick: u64) -> Result<(), SnapshotError> {
+        let registry = self.get_resource_or_init::<SnapshotRegistry>().clone();
+        self.get_resource_or_init::<Snapshots>();
+        self.resource_scope(|world, snapshots: Mut<Snapshots>| {
+            snapshots
+                .get(tick)
+                .ok_or(SnapshotError::MissingSnapshot(tick))?
+                .restore(world, &registry);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Position(i32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Velocity(i32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct NotCaptured;
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct Score(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world
+            .get_resource_or_init::<SnapshotRegistry>()
+            .register_component::<Position>()
+            .register_component::<Velocity>()
+            .register_resource::<Score>();
+        world
+    }
+
+    #[test]
+    fn restore_components_and_resources() {
+        let mut world = world();
+        let a = world.spawn((Position(0), Velocity(1), NotCaptured)).id();
+        let b = world.spawn(Position(10)).id();
+        world.insert_resource(Score(0));
+        world.save_snapshot(0);
+
+        world.entity_mut(a).get_mut::<Position>().unwrap().0 = 5;
+        world.entity_mut(a).remove::<Velocity>();
+        world.entity_mut(b).insert(Velocity(2));
+        world.insert_resource(Score(3));
+
+        world.restore_snapshot(0).unwrap();
+        assert_eq!(world.get::<Position>(a), Some(&Position(0)));
+        assert_eq!(world.get::<Velocity>(a), Some(&Velocity(1)));
+        assert_eq!(world.get::<NotCaptured>(a), Some(&NotCaptured));
+        assert_eq!(world.get::<Velocity>(b), None);
+        assert_eq!(world.resource::<Score>(), &Score(0));
+
+        world.remove_resource::<Score>();
+        world.save_snapshot(1);
+        world.insert_resource(Score(3));
+        world.restore_snapshot(1).unwrap();
+        assert!(!world.contains_resource::<Score>());
+    }
+
+    #[test]
+    fn restore_respawns_entities() {
+        let mut world = world();
+        let kept = world.spawn(Position(0)).id();
+        let despawned = world.spawn(Position(1)).id();
+        let untracked = world.spawn(NotCaptured).id();
+        world.save_snapshot(0);
+
+        world.despawn(despawned);
+        let spawned = world.spawn(Position(2)).id();
+        world.restore_snapshot(0).unwrap();
+
+        assert_eq!(world.get::<Position>(kept), Some(&Position(0)));
+        assert_eq!(world.get::<Position>(despawned), Some(&Position(1)));
+        assert!(world.get_entity(spawned).is_err());
+        assert!(world.get_entity(untracked).is_ok());
+        assert_eq!(world.query::<&Position>().iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn restore_keeps_entities_existing_at_capture() {
+        let mut world = world();
+        let entity = world.spawn(NotCaptured).id();
+        world.save_snapshot(0);
+
+        world.entity_mut(entity).insert(Position(0));
+        let spawned = world.spawn((Position(1), NotCaptured)).id();
+        world.restore_snapshot(0).unwrap();
+
+        assert_eq!(world.get::<NotCaptured>(entity), Some(&NotCaptured));
+        assert_eq!(world.get::<Position>(entity), None);
+        assert!(world.get_entity(spawned).is_err());
+    }
+
+    #[test]
+    fn restore_preserves_entity_ids() {
+        #[derive(Component, Clone, Debug, PartialEq)]
+        struct Target(#[entities] Entity);
+
+        let mut world = world();
+        world
+            .resource_mut::<SnapshotRegistry>()
+            .register_component::<Target>();
+        let target = world.spawn(Position(1)).id();
+        let follower = world.spawn(Target(target)).id();
+        world.save_snapshot(0);
+        world.save_snapshot(1);
+
+        // The index of the despawned entity is reused by an entity without registered components.
+        world.despawn(target);
+        let reused = world.spawn(NotCaptured).id();
+        assert_eq!(reused.index(), target.index());
+
+        world.restore_snapshot(1).unwrap();
+        assert!(world.get_entity(reused).is_err());
+        assert_eq!(world.get::<Position>(target), Some(&Position(1)));
+        assert_eq!(world.get::<Target>(follower), Some(&Target(target)));
+
+        // Entities are allocated as usual after restoring.
+        let spawned = world.spawn_empty().id();
+        assert_ne!(spawned.index(), target.index());
+        assert_ne!(spawned.index(), follower.index());
+
+        world.despawn(target);
+        world.despawn(follower);
+        world.restore_snapshot(0).unwrap();
+        assert_eq!(world.get::<Position>(target), Some(&Position(1)));
+        assert_eq!(world.get::<Target>(follower), Some(&Target(target)));
+        assert_eq!(
+            world.restore_snapshot(2),
+            Err(SnapshotError::MissingSnapshot(2))
+        );
+    }
+
+    #[test]
+    fn ring_buffer() {
+        let mut world = world();
+        world.insert_resource(Snapshots::new(3));
+        for tick in 0..5 {
+            world.save_snapshot(tick);
+        }
+        let snapshots = world.resource::<Snapshots>();
+        assert_eq!(snapshots.len(), 3);
+        assert_eq!(snapshots.oldest().map(WorldSnapshot::tick), Some(2));
+        assert!(snapshots.get(1).is_none());
+        assert_eq!(snapshots.get(3).map(WorldSnapshot::tick), Some(3));
+
+        // Saving an earlier tick replaces the later ones.
+        world.save_snapshot(3);
+        let snapshots = world.resource::<Snapshots>();
+        let ticks: Vec<_> = snapshots.iter().map(WorldSnapshot::tick).collect();
+        assert_eq!(ticks, vec![2, 3]);
+    }
+}
@@ -1,10 +1,11 @@
 use super::*;
 use crate::{
-    change_detection::MaybeLocation,
+    change_detection::{MaybeLocation, CHECK_TICK_THRESHOLD},
     component::TickCells,
     storage::{blob_array::BlobArray, thin_array_ptr::ThinArrayPtr},
 };
 use alloc::vec::Vec;
+use bevy_platform::sync::atomic::{AtomicU32, Ordering};
 use bevy_ptr::PtrMut;
 use core::panic::Location;
 
@@ -20,6 +21,10 @@ pub struct ThinColumn {
     pub(super) added_ticks: ThinArrayPtr<UnsafeCell<Tick>>,
     pub(super) changed_ticks: ThinArrayPtr<UnsafeCell<Tick>>,
     pub(super) changed_by: MaybeLocation<ThinArrayPtr<UnsafeCell<&'static Location<'static>>>>,
+    // A tick at least as recent as every changed tick in this column, used to skip whole tables
+    // in change-filtered queries. It's atomic since it's updated when granting mutable access,
+    // which can happen from several threads for a single table during parallel iteration.
+    pub(super) max_changed_tick: AtomicU32,
 }
 
 impl ThinColumn {
@@ -33,9 +38,42 @@ impl ThinColumn {
             added_ticks: ThinArrayPtr::with_capacity(capacity),
             changed_ticks: ThinArrayPtr::with_capacity(capacity),
             changed_by: MaybeLocation::new_with(|| ThinArrayPtr::with_capacity(capacity)),
+            max_changed_tick: AtomicU32::new(0),
         }
     }
 
+    /// Returns a tick that is at least as recent as the changed tick of every component in this column.
+    ///
+    /// It is updated whenever mutable access to the column is granted, so it may be more recent than
+    /// any actual change. If it is not newer than a system's last run, no component in this column was
+    /// changed since then.
+    #[inline]
+    pub fn max_changed_tick(&self) -> Tick {
+        Tick::new(self.max_changed_tick.load(Ordering::Relaxed))
+    }
+
+    /// Records that components in this column may have been changed at `change_tick`.
+    ///
+    /// Systems running in parallel may mark the same column with their own `this_run` tick in any
+    /// order, so the most recent tick is kept.
+    #[inline]
+    pub(crate) fn mark_changed(&self, change_tick: Tick) {
+        // The ticks marked concurrently are close to each other, so a tick slightly ahead of
+        // `change_tick` is a valid reference to compare them across wraparound.
+        let this_run = Tick::new(change_tick.get().wrapping_add(CHECK_TICK_THRESHOLD));
+        // Only write when the tick is newer, to avoid contending on the cache line when many threads
+        // iterate the same column.
+        let _ = self.max_changed_tick.fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |max_changed_tick| {
+                change_tick
+                    .is_newer_than(Tick::new(max_changed_tick), this_run)
+                    .then_some(change_tick.get())
+            },
+        );
+    }
+
     /// Swap-remove and drop the removed element, but the component at `row` must not be the last element.
     ///
     /// # Safety
@@ -149,6 +187,7 @@ impl ThinColumn {
         caller: MaybeLocation,
     ) {
         self.data.initialize_unchecked(row.as_usize(), data);
+        *self.max_changed_tick.get_mut() = tick.get();
         *self.added_ticks.get_unchecked_mut(row.as_usize()).get_mut() = tick;
         *self
             .changed_ticks
@@ -174,6 +213,7 @@ impl ThinColumn {
         caller: MaybeLocation,
     ) {
         self.data.replace_unchecked(row.as_usize(), data);
+        *self.max_changed_tick.get_mut() = change_tick.get();
         *self
             .changed_ticks
             .get_unchecked_mut(row.as_usize())
@@ -194,6 +234,9 @@ impl ThinColumn {
     ///  - `dst_row` must be in bounds for `self`
     ///  - `other[src_row]` must be initialized to a valid value.
     ///  - `self[dst_row]` must not be initialized yet.
+    ///
+    /// `change_tick` is the current change tick of the world, used to compare the changed tick of the
+    /// moved component with [`ThinColumn::max_changed_tick`].
     #[inline]
     pub(crate) unsafe fn initialize_from_unchecked(
         &mut self,
@@ -201,6 +244,7 @@ impl ThinColumn {
         other_last_element_index: usize,
         src_row: TableRow,
         dst_row: TableRow,
+        change_tick: Tick,
     ) {
         debug_assert!(self.data.layout() == other.data.layout());
         // Init the data
@@ -217,9 +261,13 @@ impl ThinColumn {
         // Init changed_ticks
         let changed_tick = other
             .changed_ticks
-            .swap_remove_unchecked(src_row.as_usize(), other_last_element_index);
+            .swap_remove_unchecked(src_row.as_usize(), other_last_element_index)
+            .into_inner();
+        if changed_tick.is_newer_than(self.max_changed_tick(), change_tick) {
+            *self.max_changed_tick.get_mut() = changed_tick.get();
+        }
         self.changed_ticks
-            .initialize_unchecked(dst_row.as_usize(), changed_tick);
+            .initialize_unchecked(dst_row.as_usize(), UnsafeCell::new(changed_tick));
         self.changed_by.as_mut().zip(other.changed_by.as_mut()).map(
             |(self_changed_by, other_changed_by)| {
                 let changed_by = other_changed_by
@@ -235,6 +283,9 @@ impl ThinColumn {
     /// `len` is the actual length of this column
     #[inline]
     pub(crate) unsafe fn check_change_ticks(&mut self, len: usize, change_tick: Tick) {
+        let mut max_changed_tick = Tick::new(*self.max_changed_tick.get_mut());
+        max_changed_tick.check_tick(change_tick);
+        *self.max_changed_tick.get_mut() = max_changed_tick.get();
         for i in 0..len {
             // SAFETY:
             // - `i` < `len`
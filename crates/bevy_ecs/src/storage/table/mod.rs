@@ -257,6 +257,7 @@ impl Table {
     }
 
     /// Moves the `row` column values to `new_table`, for the columns shared between both tables.
+    /// `change_tick` must be the current change tick of the world.
     /// Returns the index of the new row in `new_table` and the entity in this table swapped in
     /// to replace it (if an entity was swapped in). missing columns will be "forgotten". It is
     /// the caller's responsibility to drop them.  Failure to do so may result in resources not
@@ -268,6 +269,7 @@ impl Table {
         &mut self,
         row: TableRow,
         new_table: &mut Table,
+        change_tick: Tick,
     ) -> TableMoveResult {
         debug_assert!(row.as_usize() < self.entity_count());
         let last_element_index = self.entity_count() - 1;
@@ -275,7 +277,13 @@ impl Table {
         let new_row = new_table.allocate(self.entities.swap_remove(row.as_usize()));
         for (component_id, column) in self.columns.iter_mut() {
             if let Some(new_column) = new_table.get_column_mut(*component_id) {
-                new_column.initialize_from_unchecked(column, last_element_index, row, new_row);
+                new_column.initialize_from_unchecked(
+                    column,
+                    last_element_index,
+                    row,
+                    new_row,
+                    change_tick,
+                );
             } else {
                 // It's the caller's responsibility to drop these cases.
                 column.swap_remove_and_forget_unchecked(last_element_index, row);
@@ -292,6 +300,7 @@ impl Table {
     }
 
     /// Moves the `row` column values to `new_table`, for the columns shared between both tables.
+    /// `change_tick` must be the current change tick of the world.
     /// Returns the index of the new row in `new_table` and the entity in this table swapped in
     /// to replace it (if an entity was swapped in).
     ///
@@ -301,6 +310,7 @@ impl Table {
         &mut self,
         row: TableRow,
         new_table: &mut Table,
+        change_tick: Tick,
     ) -> TableMoveResult {
         debug_assert!(row.as_usize() < self.entity_count());
         let last_element_index = self.entity_count() - 1;
@@ -308,7 +318,13 @@ impl Table {
         let new_row = new_table.allocate(self.entities.swap_remove(row.as_usize()));
         for (component_id, column) in self.columns.iter_mut() {
             if let Some(new_column) = new_table.get_column_mut(*component_id) {
-                new_column.initialize_from_unchecked(column, last_element_index, row, new_row);
+                new_column.initialize_from_unchecked(
+                    column,
+                    last_element_index,
+                    row,
+                    new_row,
+                    change_tick,
+                );
             } else {
                 column.swap_remove_and_drop_unchecked(last_element_index, row);
             }
@@ -324,6 +340,7 @@ impl Table {
     }
 
     /// Moves the `row` column values to `new_table`, for the columns shared between both tables.
+    /// `change_tick` must be the current change tick of the world.
     /// Returns the index of the new row in `new_table` and the entity in this table swapped in
     /// to replace it (if an entity was swapped in).
     ///
@@ -334,6 +351,7 @@ impl Table {
         &mut self,
         row: TableRow,
         new_table: &mut Table,
+        change_tick: Tick,
     ) -> TableMoveResult {
         debug_assert!(row.as_usize() < self.entity_count());
         let last_element_index = self.entity_count() - 1;
@@ -343,7 +361,7 @@ impl Table {
             new_table
                 .get_column_mut(*component_id)
                 .debug_checked_unwrap()
-                .initialize_from_unchecked(column, last_element_index, row, new_row);
+                .initialize_from_unchecked(column, last_element_index, row, new_row, change_tick);
         }
         TableMoveResult {
             new_row,
@@ -416,6 +434,15 @@ impl Table {
         )
     }
 
+    /// Get a [`change tick`](Tick) at least as recent as the change tick of every component matching
+    /// `component_id` in this table.
+    ///
+    /// See [`ThinColumn::max_changed_tick`] for details.
+    pub fn get_max_changed_tick(&self, component_id: ComponentId) -> Option<Tick> {
+        self.get_column(component_id)
+            .map(ThinColumn::max_changed_tick)
+    }
+
     /// Get the specific [`added tick`](Tick) of the component matching `component_id` in `row`.
     pub fn get_added_tick(
         &self,
@@ -876,4 +903,33 @@ mod tests {
         assert_eq!(table.entity_capacity(), 256);
         assert_eq!(table.entity_count(), 200);
     }
+
+    #[test]
+    fn mark_changed_keeps_most_recent_tick() {
+        let mut components = Components::default();
+        let mut componentids = ComponentIds::default();
+        // SAFETY: They are both new.
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut components, &mut componentids) };
+        let component_id = registrator.register_component::<W<u32>>();
+        let table = TableBuilder::with_capacity(0, 1)
+            .add_column(components.get_info(component_id).unwrap())
+            .build();
+        let column = table.get_column(component_id).unwrap();
+
+        // Systems running in parallel may mark the column out of order.
+        column.mark_changed(Tick::new(10));
+        column.mark_changed(Tick::new(5));
+        assert_eq!(
+            table.get_max_changed_tick(component_id),
+            Some(Tick::new(10))
+        );
+
+        // The comparison handles wraparound.
+        column.mark_changed(Tick::new(2_000_000_000));
+        column.mark_changed(Tick::new(4_000_000_000));
+        column.mark_changed(Tick::new(3));
+        column.mark_changed(Tick::new(u32::MAX));
+        assert_eq!(table.get_max_changed_tick(component_id), Some(Tick::new(3)));
+    }
 }
@@ -0,0 +1,377 @@
+use alloc::{boxed::Box, vec::Vec};
+use bevy_platform::sync::{Arc, Mutex};
+use bevy_tasks::{AsyncComputeTaskPool, Task, TaskPool};
+use concurrent_queue::ConcurrentQueue;
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    event::{Event, Events},
+    resource::Resource,
+    system::{IntoSystem, RegisteredSystemError, RunSystemError, RunSystemOnce, SystemId},
+    world::World,
+};
+
+/// A closure requesting exclusive access to the [`World`] on behalf of an async system.
+type WorldRequest = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A [`Resource`] storing the async systems spawned with [`World::spawn_async`] or
+/// [`Commands::spawn_async`](crate::system::Commands::spawn_async) that are still running.
+///
+/// Async systems only make progress when they are driven by [`World::run_async_systems`], usually once per frame.
+#[derive(Resource, Default)]
+pub struct AsyncSystems {
+    shared: Arc<AsyncShared>,
+    tasks: Vec<Task<()>>,
+}
+
+impl AsyncSystems {
+    /// Returns the number of async systems that are still running.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if no async system is running.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+/// The state shared between the [`World`] and the [`AsyncContext`]s of its async systems.
+struct AsyncShared {
+    /// The world accesses requested by async systems, applied by [`World::run_async_systems`].
+    requests: ConcurrentQueue<WorldRequest>,
+    /// The number of frames that have ended, and the async systems waiting for the next one.
+    frame: Mutex<(u64, Vec<Waker>)>,
+}
+
+impl Default for AsyncShared {
+    fn default() -> Self {
+        Self {
+            requests: ConcurrentQueue::unbounded(),
+            frame: Mutex::new((0, Vec::new())),
+        }
+    }
+}
+
+/// The handle given to async systems, used to await frames, [`World`] access and [events](Event).
+///
+/// Async systems are spawned with [`Commands::spawn_async`](crate::system::Commands::spawn_async)
+/// or [`World::spawn_async`], and run on the [`AsyncComputeTaskPool`] across as many frames as they need.
+/// They cannot hold on to the world: each access is requested through this context, and applied
+/// the next time the world runs its async systems.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// fn start_countdown(mut commands: Commands) {
+///     commands.spawn_async(|ctx| async move {
+///         for _ in 0..3 {
+///             ctx.next_frame().await;
+///         }
+///         ctx.with_world(|world| world.resource_mut::<Score>().0 += 1).await;
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(start_countdown);
+/// ```
+#[derive(Clone)]
+pub struct AsyncContext {
+    shared: Arc<AsyncShared>,
+}
+
+impl AsyncContext {
+    /// Waits until the end of the current frame, when [`World::run_async_systems`] next runs.
+    pub fn next_frame(&self) -> impl Future<Output = ()> + Send + 'static {
+        let shared = self.shared.clone();
+        let frame = shared.frame.lock().unwrap().0;
+        NextFrame { shared, frame }
+    }
+
+    /// Runs `f` with exclusive access to the [`World`] and returns its output.
+    ///
+    /// The access is granted the next time the world runs its async systems.
+    pub fn with_world<R, F>(&self, f: F) -> impl Future<Output = R> + Send + 'static
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut World) -> R + Send + 'static,
+    {
+        let slot = Arc::new(Mutex::new(Slot::Waiting(None)));
+        let request_slot = slot.clone();
+        let request: WorldRequest = Box::new(move |world| {
+            let output = f(world);
+            let previous = mem::replace(&mut *request_slot.lock().unwrap(), Slot::Ready(output));
+            if let Slot::Waiting(Some(waker)) = previous {
+                waker.wake();
+            }
+        });
+        // The queue is unbounded and never closed.
+        let _ = self.shared.requests.push(request);
+        WorldAccess { slot }
+    }
+
+    /// Runs the system corresponding to the given [`SystemId`] and returns its output.
+    ///
+    /// See [`World::run_system`] for more details.
+    pub fn run_system<O: Send + 'static>(
+        &self,
+        id: SystemId<(), O>,
+    ) -> impl Future<Output = Result<O, RegisteredSystemError<(), O>>> + Send + 'static {
+        self.with_world(move |world| world.run_system(id))
+    }
+
+    /// Runs `system` once and returns its output, which gives access to the part of the [`World`] described by its parameters.
+    ///
+    /// See [`RunSystemOnce::run_system_once`] for more details.
+    pub fn run_system_once<S, Out, Marker>(
+        &self,
+        system: S,
+    ) -> impl Future<Output = Result<Out, RunSystemError>> + Send + 'static
+    where
+        S: IntoSystem<(), Out, Marker> + Send + 'static,
+        Out: Send + 'static,
+    {
+        self.with_world(move |world| world.run_system_once(system))
+    }
+
+    /// Waits for the next event of type `E` sent after this is called, and returns a copy of it.
+    pub async fn event<E: Event + Clone>(&self) -> E {
+        let mut cursor = self
+            .with_world(|world| {
+                world
+                    .get_resource::<Events<E>>()
+                    .map(Events::get_cursor_current)
+                    .unwrap_or_default()
+            })
+            .await;
+        loop {
+            let (next_cursor, event) = self
+                .with_world(move |world| {
+                    let event = world
+                        .get_resource::<Events<E>>()
+                        .and_then(|events| cursor.read(events).next().cloned());
+                    (cursor, event)
+                })
+                .await;
+            if let Some(event) = event {
+                return event;
+            }
+            cursor = next_cursor;
+            self.next_frame().await;
+        }
+    }
+}
+
+/// The output of a [`WorldRequest`].
+enum Slot<R> {
+    Waiting(Option<Waker>),
+    Ready(R),
+    Taken,
+}
+
+/// The future returned by [`AsyncContext::with_world`].
+struct WorldAccess<R> {
+    slot: Arc<Mutex<Slot<R>>>,
+}
+
+impl<R> Future for WorldAccess<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut slot = self.slot.lock().unwrap();
+        match mem::replace(&mut *slot, Slot::Taken) {
+            Slot::Ready(output) => Poll::Ready(output),
+            Slot::Waiting(_) => {
+                *slot = Slot::Waiting(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            Slot::Taken => panic!("`WorldAccess` polled after completion"),
+        }
+    }
+}
+
+/// The future returned by [`AsyncContext::next_frame`].
+struct NextFrame {
+    shared: Arc<AsyncShared>,
+    frame: u64,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut frame = self.shared.frame.lock().unwrap();
+        if frame.0 > self.frame {
+            Poll::Ready(())
+        } else {
+            frame.1.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl World {
+    /// Spawns an async system, which runs the future returned by `f` on the [`AsyncComputeTaskPool`].
+    ///
+    /// The future can use the [`AsyncContext`] it is given to await frames, [`World`] access and events.
+    /// It makes progress each time [`World::run_async_systems`] runs, which `bevy_app` does once per frame.
+    ///
+    /// See [`Commands::spawn_async`](crate::system::Commands::spawn_async) to spawn it from a system.
+    pub fn spawn_async<F, Fut>(&mut self, f: F)
+    where
+        F: FnOnce(AsyncContext) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut systems = self.get_resource_or_init::<AsyncSystems>();
+        let context = AsyncContext {
+            shared: systems.shared.clone(),
+        };
+        let task = AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(f(context));
+        systems.tasks.push(task);
+    }
+
+    /// Grants the [`World`] access requested by async systems, then ends the frame for them,
+    /// resuming those waiting for [`AsyncContext::next_frame`].
+    ///
+    /// World access is granted until all async systems are waiting for something else,
+    /// so an async system that keeps requesting it without awaiting anything else stalls this call.
+    pub fn run_async_systems(&mut self) {
+        let Some(shared) = self
+            .get_resource::<AsyncSystems>()
+            .map(|systems| systems.shared.clone())
+        else {
+            return;
+        };
+
+        loop {
+            // Async systems running on this thread only make progress when its local executor is ticked.
+            if let Some(pool) = AsyncComputeTaskPool::try_get() {
+                pool.with_local_executor(|executor| while executor.try_tick() {});
+            }
+            if shared.requests.is_empty() {
+                break;
+            }
+            while let Ok(request) = shared.requests.pop() {
+                request(self);
+            }
+        }
+
+        let wakers = {
+            let mut frame = shared.frame.lock().unwrap();
+            frame.0 += 1;
+            mem::take(&mut frame.1)
+        };
+        wakers.into_iter().for_each(Waker::wake);
+
+        if let Some(mut systems) = self.get_resource_mut::<AsyncSystems>() {
+            systems.tasks.retain(|task| !task.is_finished());
+        }
+    }
+}
+
+/// Drives the async systems of the [`World`], see [`World::run_async_systems`].
+pub fn run_async_systems(world: &mut World) {
+    world.run_async_systems();
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{
+        prelude::*,
+        system::{AsyncSystems, RunSystemOnce},
+    };
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    #[derive(Event, Clone)]
+    struct Ping(u32);
+
+    /// Runs the async systems until `done` returns `true`, giving the tasks running on other threads time to progress.
+    fn run_until(world: &mut World, mut done: impl FnMut(&mut World) -> bool) {
+        for _ in 0..1000 {
+            world.run_async_systems();
+            if done(world) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("async systems did not complete");
+    }
+
+    #[test]
+    fn async_system_awaits_frames_and_world_access() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.spawn_async(|ctx| async move {
+            ctx.with_world(|world| world.resource_mut::<Counter>().0 += 1)
+                .await;
+            ctx.next_frame().await;
+            let value = ctx
+                .run_system_once(|counter: Res<Counter>| counter.0)
+                .await
+                .unwrap();
+            ctx.with_world(move |world| world.resource_mut::<Counter>().0 = value * 10)
+                .await;
+        });
+        assert_eq!(world.resource::<AsyncSystems>().len(), 1);
+
+        run_until(&mut world, |world| world.resource::<Counter>().0 > 0);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        run_until(&mut world, |world| {
+            world.resource::<AsyncSystems>().is_empty()
+        });
+        assert_eq!(world.resource::<Counter>().0, 10);
+    }
+
+    #[test]
+    fn async_system_awaits_events() {
+        let mut world = World::new();
+        world.init_resource::<Events<Ping>>();
+        world.init_resource::<Counter>();
+        world.spawn_async(|ctx| async move {
+            let Ping(value) = ctx.event::<Ping>().await;
+            ctx.with_world(move |world| world.resource_mut::<Counter>().0 = value)
+                .await;
+        });
+
+        for _ in 0..3 {
+            world.run_async_systems();
+        }
+        assert!(!world.resource::<AsyncSystems>().is_empty());
+
+        world.send_event(Ping(7));
+        run_until(&mut world, |world| {
+            world.resource::<AsyncSystems>().is_empty()
+        });
+        assert_eq!(world.resource::<Counter>().0, 7);
+    }
+
+    #[test]
+    fn commands_spawn_async() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world
+            .run_system_once(|mut commands: Commands| {
+                for _ in 0..3 {
+                    commands.spawn_async(|ctx| async move {
+                        ctx.with_world(|world| world.resource_mut::<Counter>().0 += 1)
+                            .await;
+                    });
+                }
+            })
+            .unwrap();
+
+        run_until(&mut world, |world| {
+            world.resource::<AsyncSystems>().is_empty()
+        });
+        assert_eq!(world.resource::<Counter>().0, 3);
+    }
+}
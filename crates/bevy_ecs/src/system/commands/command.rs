@@ -169,6 +169,18 @@ where
     }
 }
 
+/// A [`Command`] that spawns an async system running the future returned by `f`.
+///
+/// See [`World::spawn_async`] for more details.
+#[cfg(feature = "std")]
+pub fn spawn_async<F, Fut>(f: F) -> impl Command
+where
+    F: FnOnce(crate::system::AsyncContext) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    move |world: &mut World| world.spawn_async(f)
+}
+
 /// A [`Command`] that removes a system previously registered with
 /// [`Commands::register_system`](crate::system::Commands::register_system) or
 /// [`World::register_system`].
@@ -1097,6 +1097,41 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue(command::run_system_cached_with(system, input).handle_error_with(warn));
     }
 
+    /// Spawns an async system, which runs the future returned by `f` across as many frames as it needs.
+    ///
+    /// The future is given an [`AsyncContext`](crate::system::AsyncContext), which it can use to await
+    /// the next frame, exclusive access to the [`World`], one-shot systems, and events.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Event, Clone)]
+    /// struct LevelLoaded;
+    ///
+    /// #[derive(Component)]
+    /// struct Banner;
+    ///
+    /// fn show_banner_once_loaded(mut commands: Commands) {
+    ///     commands.spawn_async(|ctx| async move {
+    ///         ctx.event::<LevelLoaded>().await;
+    ///         ctx.with_world(|world| {
+    ///             world.spawn(Banner);
+    ///         })
+    ///         .await;
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(show_banner_once_loaded);
+    /// ```
+    ///
+    /// See [`World::spawn_async`] for more details.
+    #[cfg(feature = "std")]
+    pub fn spawn_async<F, Fut>(&mut self, f: F)
+    where
+        F: FnOnce(crate::system::AsyncContext) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.queue(command::spawn_async(f));
+    }
+
     /// Sends a "global" [`Trigger`](crate::observer::Trigger) without any targets.
     ///
     /// This will run any [`Observer`] of the given [`Event`] that isn't scoped to specific targets.
@@ -120,6 +120,8 @@
 //! [`Vec<P>`]: alloc::vec::Vec
 
 mod adapter_system;
+#[cfg(feature = "std")]
+mod async_system;
 mod builder;
 mod combinator;
 mod commands;
@@ -138,6 +140,8 @@ mod system_registry;
 use core::any::TypeId;
 
 pub use adapter_system::*;
+#[cfg(feature = "std")]
+pub use async_system::*;
 pub use builder::*;
 pub use combinator::*;
 pub use commands::*;
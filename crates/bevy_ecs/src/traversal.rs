@@ -1,6 +1,12 @@
 //! A trait for components that let you traverse the ECS.
 
-use crate::{entity::Entity, query::ReadOnlyQueryData, relationship::Relationship};
+use alloc::vec::Vec;
+
+use crate::{
+    entity::Entity,
+    query::{QueryData, ReadOnlyQueryData},
+    relationship::{Relationship, RelationshipTarget},
+};
 
 /// A component that can point to another entity, and which can be used to define a path through the ECS.
 ///
@@ -23,6 +29,14 @@ use crate::{entity::Entity, query::ReadOnlyQueryData, relationship::Relationship
 pub trait Traversal<D: ?Sized>: ReadOnlyQueryData {
     /// Returns the next entity to visit.
     fn traverse(item: Self::Item<'_>, data: &D) -> Option<Entity>;
+
+    /// Pushes every entity to visit next into `targets`, for traversals that can branch out,
+    /// such as [`RelationshipTargetTraversal`] which visits all the entities related to the current one.
+    ///
+    /// Defaults to the single entity returned by [`Traversal::traverse`].
+    fn traverse_all(item: Self::Item<'_>, data: &D, targets: &mut Vec<Entity>) {
+        targets.extend(Self::traverse(item, data));
+    }
 }
 
 impl<D> Traversal<D> for () {
@@ -43,3 +57,30 @@ impl<R: Relationship, D> Traversal<D> for &R {
         Some(item.get())
     }
 }
+
+/// A [`Traversal`] that goes down a relationship, from an entity to all the entities related to it
+/// through the [`RelationshipTarget`] `R`, for example from a parent to its [`Children`](crate::hierarchy::Children).
+///
+/// Events using this traversal [propagate] to every related entity, in the order of the relationship collection,
+/// visiting the descendants of each one before moving on to the next.
+///
+/// # Warning
+///
+/// Traversing in a loop could result in infinite loops for relationship graphs with loops.
+///
+/// [propagate]: crate::observer::Trigger::propagate
+#[derive(QueryData)]
+pub struct RelationshipTargetTraversal<R: RelationshipTarget> {
+    /// The entities related to the visited entity.
+    pub targets: &'static R,
+}
+
+impl<R: RelationshipTarget, D> Traversal<D> for RelationshipTargetTraversal<R> {
+    fn traverse(item: Self::Item<'_>, _data: &D) -> Option<Entity> {
+        item.targets.iter().next()
+    }
+
+    fn traverse_all(item: Self::Item<'_>, _data: &D, targets: &mut Vec<Entity>) {
+        targets.extend(item.targets.iter());
+    }
+}
@@ -0,0 +1,1007 @@
+//! Undo and redo of reflected changes to a [`World`].
+//!
+//! [`UndoableCommands`] is a [`SystemParam`] queuing commands that modify the world like
+//! [`Commands`] do, while recording how to revert them in the [`UndoHistory`] resource:
+//!
+//! - Inserting, removing and mutating components records their value before and after the
+//!   change, captured through reflection. These components must be registered in the
+//!   [`AppTypeRegistry`] with [`ReflectComponent`].
+//! - Despawning an entity clones it, along with its [linked](crate::relationship::RelationshipTarget::LINKED_SPAWN)
+//!   descendants, into [`Disabled`] stash entities using an [`EntityCloner`], so that undoing
+//!   the despawn can clone them back. Undoing a spawn stashes the spawned entity the same way.
+//!
+//! Entities restored from a stash are new entities: every reference to the despawned entities
+//! in the history is updated to the restored ones. References stored inside component values
+//! are left as they are.
+//!
+//! Changes are grouped in transactions, which are undone and redone as a whole. Each command
+//! queued outside of [`UndoableCommands::transaction`] is its own transaction.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::undo::UndoableCommands;
+//! # use bevy_reflect::Reflect;
+//! #[derive(Component, Reflect)]
+//! #[reflect(Component)]
+//! struct Health(u32);
+//!
+//! fn heal(mut commands: UndoableCommands, query: Query<Entity, With<Health>>) {
+//!     commands.transaction("heal all", |commands| {
+//!         for entity in &query {
+//!             commands.mutate(entity, |health: &mut Health| health.0 += 10);
+//!         }
+//!     });
+//! }
+//!
+//! fn undo_heal(mut commands: UndoableCommands) {
+//!     commands.undo();
+//! }
+//! # bevy_ecs::system::assert_is_system(heal);
+//! # bevy_ecs::system::assert_is_system(undo_heal);
+//! ```
+
+use crate::{
+    bundle::Bundle,
+    component::{Component, Mutable},
+    entity::{hash_map::EntityHashMap, Entity, EntityCloner, EntityDoesNotExistError},
+    entity_disabling::Disabled,
+    event::Event,
+    hierarchy::ChildOf,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    resource::Resource,
+    system::{Commands, EntityCommands, SystemParam},
+    world::{error::EntityMutableFetchError, Mut, World},
+};
+use alloc::{borrow::Cow, boxed::Box, collections::VecDeque, string::String, vec::Vec};
+use bevy_reflect::{PartialReflect, Reflect, TypeRegistration};
+use core::any::{type_name, TypeId};
+use thiserror::Error;
+
+/// A [`SystemParam`] queuing commands whose effects can be undone and redone.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(SystemParam)]
+pub struct UndoableCommands<'w, 's> {
+    commands: Commands<'w, 's>,
+}
+
+impl<'w, 's> UndoableCommands<'w, 's> {
+    /// Returns the underlying [`Commands`], whose changes are not recorded.
+    pub fn commands(&mut self) -> &mut Commands<'w, 's> {
+        &mut self.commands
+    }
+
+    /// Spawns an entity with the given `bundle`, which undoing removes from the world.
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) -> EntityCommands<'_> {
+        let entity = self.commands.spawn(bundle).id();
+        self.commands.queue(move |world: &mut World| {
+            with_history(world, |history, _| {
+                history.record(Change::Spawn(EntityState::Live(entity)));
+                Ok(())
+            })
+        });
+        self.commands.entity(entity)
+    }
+
+    /// Despawns the `entity` and its linked descendants, which undoing restores.
+    pub fn despawn(&mut self, entity: Entity) {
+        self.commands.queue(move |world: &mut World| {
+            with_history(world, |history, world| {
+                let stash = Stash::new(world, entity)?;
+                history.record(Change::Despawn(EntityState::Stashed(stash)));
+                Ok(())
+            })
+        });
+    }
+
+    /// Inserts the `component` into the `entity`, which undoing reverts to its previous value,
+    /// or removes if it didn't have one.
+    pub fn insert<C: Component + Reflect>(&mut self, entity: Entity, component: C) {
+        self.queue_set(
+            entity,
+            TypeId::of::<C>(),
+            type_name::<C>().into(),
+            Some(Box::new(component)),
+        );
+    }
+
+    /// Inserts the reflected `component` into the `entity`, which undoing reverts to its previous
+    /// value, or removes if it didn't have one.
+    pub fn insert_reflect(&mut self, entity: Entity, component: Box<dyn PartialReflect>) {
+        self.commands.queue(move |world: &mut World| {
+            let Some(info) = component.get_represented_type_info() else {
+                return Err(UndoError::UnregisteredComponent(
+                    component.reflect_type_path().into(),
+                ));
+            };
+            let (type_id, type_path) = (info.type_id(), info.type_path().into());
+            set_component(world, entity, type_id, type_path, Some(component))
+        });
+    }
+
+    /// Removes the component `C` from the `entity`, which undoing restores.
+    pub fn remove<C: Component + Reflect>(&mut self, entity: Entity) {
+        self.queue_set(entity, TypeId::of::<C>(), type_name::<C>().into(), None);
+    }
+
+    /// Removes the component with the given type path from the `entity`, which undoing restores.
+    pub fn remove_reflect(&mut self, entity: Entity, type_path: impl Into<Cow<'static, str>>) {
+        let type_path = type_path.into();
+        self.commands.queue(move |world: &mut World| {
+            let type_id = world
+                .get_resource::<AppTypeRegistry>()
+                .and_then(|registry| {
+                    registry
+                        .read()
+                        .get_with_type_path(&type_path)
+                        .map(TypeRegistration::type_id)
+                })
+                .ok_or_else(|| UndoError::UnregisteredComponent(type_path.clone().into()))?;
+            set_component(world, entity, type_id, type_path, None)
+        });
+    }
+
+    /// Mutates the component `C` of the `entity` with `f`, which undoing reverts.
+    pub fn mutate<C: Component<Mutability = Mutable> + Reflect>(
+        &mut self,
+        entity: Entity,
+        f: impl FnOnce(&mut C) + Send + 'static,
+    ) {
+        self.commands.queue(move |world: &mut World| {
+            with_history(world, |history, world| {
+                let reflect_component =
+                    reflect_component(world, TypeId::of::<C>(), type_name::<C>())?;
+                let before = read_component(world, entity, &reflect_component)?;
+                let mut entity_mut = world.get_entity_mut(entity)?;
+                let component = entity_mut
+                    .get_mut::<C>()
+                    .ok_or_else(|| UndoError::MissingComponent(entity, type_name::<C>().into()))?;
+                f(component.into_inner());
+                let after = read_component(world, entity, &reflect_component)?;
+                history.record(Change::Component {
+                    entity,
+                    reflect_component,
+                    before,
+                    after,
+                });
+                Ok(())
+            })
+        });
+    }
+
+    /// Groups the changes made by the commands queued in `f` into a single transaction with the
+    /// given `label`, which is undone and redone as a whole.
+    ///
+    /// Nested transactions are merged into the outermost one.
+    pub fn transaction(
+        &mut self,
+        label: impl Into<Cow<'static, str>>,
+        f: impl FnOnce(&mut Self),
+    ) -> &mut Self {
+        let label = label.into();
+        self.commands.queue(move |world: &mut World| {
+            world.get_resource_or_init::<UndoHistory>().begin(label);
+        });
+        f(self);
+        self.commands.queue(|world: &mut World| {
+            world.get_resource_or_init::<UndoHistory>().commit();
+        });
+        self
+    }
+
+    /// Undoes the most recent transaction, triggering [`Undone`].
+    ///
+    /// Does nothing if there is nothing to undo.
+    pub fn undo(&mut self) {
+        self.commands
+            .queue(|world: &mut World| with_history(world, UndoHistory::undo).map(|_| ()));
+    }
+
+    /// Redoes the most recently undone transaction, triggering [`Redone`].
+    ///
+    /// Does nothing if there is nothing to redo.
+    pub fn redo(&mut self) {
+        self.commands
+            .queue(|world: &mut World| with_history(world, UndoHistory::redo).map(|_| ()));
+    }
+
+    fn queue_set(
+        &mut self,
+        entity: Entity,
+        type_id: TypeId,
+        type_path: Cow<'static, str>,
+        value: Option<Box<dyn PartialReflect>>,
+    ) {
+        self.commands.queue(move |world: &mut World| {
+            set_component(world, entity, type_id, type_path, value)
+        });
+    }
+}
+
+/// The transactions that can be undone and redone, recorded by [`UndoableCommands`].
+///
+/// At most [`max_len`](Self::max_len) transactions are kept: recording a transaction beyond that
+/// forgets the oldest one. Recording a transaction also forgets every undone transaction.
+#[derive(Resource)]
+pub struct UndoHistory {
+    undo_stack: VecDeque<Transaction>,
+    redo_stack: Vec<Transaction>,
+    pending: Option<Transaction>,
+    depth: usize,
+    max_len: usize,
+    /// Stash entities of forgotten transactions, despawned when the world is next available.
+    discarded: Vec<Stash>,
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_LEN)
+    }
+}
+
+impl UndoHistory {
+    /// The [`max_len`](Self::max_len) of the [default](Default) history.
+    pub const DEFAULT_MAX_LEN: usize = 100;
+
+    /// Creates an empty history keeping at most `max_len` transactions.
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            pending: None,
+            depth: 0,
+            max_len,
+            discarded: Vec::new(),
+        }
+    }
+
+    /// The maximum number of transactions that can be undone.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Sets the maximum number of transactions that can be undone, forgetting the oldest ones
+    /// beyond it.
+    pub fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len;
+        self.truncate();
+    }
+
+    /// Returns the number of transactions that can be undone.
+    pub fn undo_len(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Returns the number of transactions that can be redone.
+    pub fn redo_len(&self) -> usize {
+        self.redo_stack.len()
+    }
+
+    /// Returns the label of the transaction [`undo`](Self::undo) would revert, if any.
+    pub fn next_undo(&self) -> Option<Option<&str>> {
+        self.undo_stack.back().map(Transaction::label)
+    }
+
+    /// Returns the label of the transaction [`redo`](Self::redo) would reapply, if any.
+    pub fn next_redo(&self) -> Option<Option<&str>> {
+        self.redo_stack.last().map(Transaction::label)
+    }
+
+    /// Starts a transaction with the given `label`, grouping every change recorded until the
+    /// matching [`commit`](Self::commit).
+    ///
+    /// Nested transactions are merged into the outermost one.
+    pub fn begin(&mut self, label: impl Into<Cow<'static, str>>) {
+        if self.depth == 0 {
+            self.pending = Some(Transaction {
+                label: Some(label.into()),
+                changes: Vec::new(),
+            });
+        }
+        self.depth += 1;
+    }
+
+    /// Ends the transaction started by the matching [`begin`](Self::begin).
+    ///
+    /// Does nothing if no transaction was started.
+    pub fn commit(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            if let Some(transaction) = self.pending.take() {
+                self.push(transaction);
+            }
+        }
+    }
+
+    /// Forgets every transaction, despawning the entities they stashed.
+    pub fn clear(&mut self, world: &mut World) {
+        let transactions = self.undo_stack.drain(..).chain(self.redo_stack.drain(..));
+        self.discarded
+            .extend(transactions.flat_map(Transaction::into_stashes));
+        self.flush(world);
+    }
+
+    /// Reverts the most recent transaction, triggering [`Undone`].
+    ///
+    /// Returns `false` if there was nothing to undo. If reverting a change fails, the
+    /// transaction is forgotten.
+    pub fn undo(&mut self, world: &mut World) -> Result<bool, UndoError> {
+        let Some(mut transaction) = self.undo_stack.pop_back() else {
+            return Ok(false);
+        };
+        for index in (0..transaction.changes.len()).rev() {
+            self.apply(world, &mut transaction, index, true)?;
+        }
+        let label = transaction.label.clone();
+        self.redo_stack.push(transaction);
+        world.trigger(Undone { label });
+        Ok(true)
+    }
+
+    /// Reapplies the most recently undone transaction, triggering [`Redone`].
+    ///
+    /// Returns `false` if there was nothing to redo. If reapplying a change fails, the
+    /// transaction is forgotten.
+    pub fn redo(&mut self, world: &mut World) -> Result<bool, UndoError> {
+        let Some(mut transaction) = self.redo_stack.pop() else {
+            return Ok(false);
+        };
+        for index in 0..transaction.changes.len() {
+            self.apply(world, &mut transaction, index, false)?;
+        }
+        let label = transaction.label.clone();
+        self.undo_stack.push_back(transaction);
+        self.truncate();
+        world.trigger(Redone { label });
+        Ok(true)
+    }
+
+    fn apply(
+        &mut self,
+        world: &mut World,
+        transaction: &mut Transaction,
+        index: usize,
+        undo: bool,
+    ) -> Result<(), UndoError> {
+        match transaction.changes[index].apply(world, undo) {
+            Ok(Some(restored)) => {
+                transaction.remap(&restored);
+                self.remap(&restored);
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(error) => {
+                self.discarded
+                    .extend(core::mem::take(transaction).into_stashes());
+                self.flush(world);
+                Err(error)
+            }
+        }
+    }
+
+    fn record(&mut self, change: Change) {
+        match &mut self.pending {
+            Some(transaction) => transaction.changes.push(change),
+            None => self.push(Transaction {
+                label: None,
+                changes: alloc::vec![change],
+            }),
+        }
+    }
+
+    fn push(&mut self, transaction: Transaction) {
+        if transaction.changes.is_empty() {
+            return;
+        }
+        let redone = self.redo_stack.drain(..);
+        self.discarded
+            .extend(redone.flat_map(Transaction::into_stashes));
+        self.undo_stack.push_back(transaction);
+        self.truncate();
+    }
+
+    fn truncate(&mut self) {
+        while self.undo_stack.len() > self.max_len {
+            if let Some(transaction) = self.undo_stack.pop_front() {
+                self.discarded.extend(transaction.into_stashes());
+            }
+        }
+    }
+
+    fn remap(&mut self, restored: &EntityHashMap<Entity>) {
+        self.undo_stack
+            .iter_mut()
+            .chain(self.redo_stack.iter_mut())
+            .chain(self.pending.as_mut())
+            .for_each(|transaction| transaction.remap(restored));
+    }
+
+    fn flush(&mut self, world: &mut World) {
+        for stash in self.discarded.drain(..) {
+            world.despawn(stash.root);
+        }
+    }
+}
+
+/// An [`Event`] triggered when [`UndoHistory::undo`] reverts a transaction.
+#[derive(Event, Debug, Clone)]
+pub struct Undone {
+    /// The label of the reverted transaction, if it has one.
+    pub label: Option<Cow<'static, str>>,
+}
+
+/// An [`Event`] triggered when [`UndoHistory::redo`] reapplies a transaction.
+#[derive(Event, Debug, Clone)]
+pub struct Redone {
+    /// The label of the reapplied transaction, if it has one.
+    pub label: Option<Cow<'static, str>>,
+}
+
+/// An error that occurs when recording, undoing or redoing a change.
+#[derive(Error, Debug)]
+pub enum UndoError {
+    /// The entity does not exist.
+    #[error(transparent)]
+    EntityDoesNotExist(#[from] EntityDoesNotExistError),
+    /// The entity could not be fetched mutably.
+    #[error(transparent)]
+    EntityMutableFetch(#[from] EntityMutableFetchError),
+    /// The component is not registered with [`ReflectComponent`] in the [`AppTypeRegistry`].
+    #[error("The component `{0}` is not registered with `ReflectComponent`")]
+    UnregisteredComponent(String),
+    /// The entity does not have the component.
+    #[error("The entity {0} does not have the component `{1}`")]
+    MissingComponent(Entity, String),
+}
+
+#[derive(Default)]
+struct Transaction {
+    label: Option<Cow<'static, str>>,
+    changes: Vec<Change>,
+}
+
+impl Transaction {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn into_stashes(self) -> impl Iterator<Item = Stash> {
+        self.changes.into_iter().filter_map(|change| match change {
+            Change::Spawn(EntityState::Stashed(stash))
+            | Change::Despawn(EntityState::Stashed(stash)) => Some(stash),
+            _ => None,
+        })
+    }
+
+    fn remap(&mut self, restored: &EntityHashMap<Entity>) {
+        let remap = |entity: &mut Entity| {
+            if let Some(&new) = restored.get(entity) {
+                *entity = new;
+            }
+        };
+        for change in &mut self.changes {
+            match change {
+                Change::Component { entity, .. }
+                | Change::Spawn(EntityState::Live(entity))
+                | Change::Despawn(EntityState::Live(entity)) => remap(entity),
+                Change::Spawn(EntityState::Stashed(stash))
+                | Change::Despawn(EntityState::Stashed(stash)) => {
+                    stash.parent.iter_mut().for_each(remap);
+                    stash
+                        .entities
+                        .iter_mut()
+                        .for_each(|(original, _)| remap(original));
+                }
+            }
+        }
+    }
+}
+
+enum Change {
+    /// A component was set from `before` to `after`, `None` meaning it was absent.
+    Component {
+        entity: Entity,
+        reflect_component: ReflectComponent,
+        before: Option<Box<dyn PartialReflect>>,
+        after: Option<Box<dyn PartialReflect>>,
+    },
+    Spawn(EntityState),
+    Despawn(EntityState),
+}
+
+impl Change {
+    /// Reverts or reapplies the change, returning the entities restored from a stash, if any.
+    fn apply(
+        &mut self,
+        world: &mut World,
+        undo: bool,
+    ) -> Result<Option<EntityHashMap<Entity>>, UndoError> {
+        match self {
+            Change::Component {
+                entity,
+                reflect_component,
+                before,
+                after,
+            } => {
+                let value = if undo { before } else { after };
+                write_component(world, *entity, reflect_component, value.as_deref())?;
+                Ok(None)
+            }
+            Change::Spawn(state) => state.toggle(world, !undo),
+            Change::Despawn(state) => state.toggle(world, undo),
+        }
+    }
+}
+
+enum EntityState {
+    Live(Entity),
+    Stashed(Stash),
+}
+
+impl EntityState {
+    /// Stashes or restores the entity so that it is live if `live` is set.
+    fn toggle(
+        &mut self,
+        world: &mut World,
+        live: bool,
+    ) -> Result<Option<EntityHashMap<Entity>>, UndoError> {
+        match self {
+            EntityState::Live(entity) if !live => {
+                *self = EntityState::Stashed(Stash::new(world, *entity)?);
+                Ok(None)
+            }
+            EntityState::Stashed(stash) if live => {
+                let restored = stash.restore(world);
+                *self = EntityState::Live(restored[&stash.entities[0].0]);
+                Ok(Some(restored))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Disabled clones of a despawned entity and its linked descendants.
+struct Stash {
+    root: Entity,
+    /// The parent of the despawned entity, if it had one.
+    parent: Option<Entity>,
+    /// The despawned entities with their clones, starting with the root.
+    entities: Vec<(Entity, Entity)>,
+    /// The clones that were disabled when stashing them.
+    disabled: Vec<Entity>,
+}
+
+impl Stash {
+    /// Stashes the `entity`, which is left untouched if this fails.
+    fn new(world: &mut World, entity: Entity) -> Result<Self, UndoError> {
+        let parent = world
+            .get_entity(entity)?
+            .get::<ChildOf>()
+            .map(ChildOf::parent);
+
+        let root = world.spawn_empty().id();
+        let mut map = EntityHashMap::default();
+        map.insert(entity, root);
+        let mut builder = EntityCloner::build(world);
+        builder.linked_cloning(true);
+        builder
+            .finish()
+            .clone_entity_mapped(world, entity, &mut map);
+        // The stash is not part of the hierarchy of the parent.
+        world.entity_mut(root).remove::<ChildOf>();
+
+        let mut entities = Vec::with_capacity(map.len());
+        entities.push((entity, root));
+        entities.extend(
+            map.iter()
+                .filter(|(original, _)| **original != entity)
+                .map(|(original, clone)| (*original, *clone)),
+        );
+
+        let mut disabled = Vec::new();
+        for &(_, clone) in &entities {
+            let mut clone_mut = world.entity_mut(clone);
+            if !clone_mut.contains::<Disabled>() {
+                clone_mut.insert(Disabled);
+                disabled.push(clone);
+            }
+        }
+
+        world.despawn(entity);
+        Ok(Self {
+            root,
+            parent,
+            entities,
+            disabled,
+        })
+    }
+
+    /// Clones the stash back into the world and despawns it, returning the restored entities
+    /// keyed by the despawned ones.
+    fn restore(&self, world: &mut World) -> EntityHashMap<Entity> {
+        let root = world.spawn_empty().id();
+        let mut map = EntityHashMap::default();
+        map.insert(self.root, root);
+        let mut builder = EntityCloner::build(world);
+        builder.linked_cloning(true);
+        builder
+            .finish()
+            .clone_entity_mapped(world, self.root, &mut map);
+
+        for clone in &self.disabled {
+            world.entity_mut(map[clone]).remove::<Disabled>();
+        }
+        if let Some(parent) = self
+            .parent
+            .filter(|&parent| world.entities().contains(parent))
+        {
+            world.entity_mut(root).insert(ChildOf(parent));
+        }
+        world.despawn(self.root);
+
+        self.entities
+            .iter()
+            .map(|(original, clone)| (*original, map[clone]))
+            .collect()
+    }
+}
+
+fn with_history<T>(
+    world: &mut World,
+    f: impl FnOnce(&mut UndoHistory, &mut World) -> Result<T, UndoError>,
+) -> Result<T, UndoError> {
+    world.get_resource_or_init::<UndoHistory>();
+    world.resource_scope(|world, mut history: Mut<UndoHistory>| {
+        let result = f(&mut history, world);
+        history.flush(world);
+        result
+    })
+}
+
+/// Sets the component to `value`, or removes it if `None`, recording the change.
+fn set_component(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    type_path: Cow<'static, str>,
+    value: Option<Box<dyn PartialReflect>>,
+) -> Result<(), UndoError> {
+    with_history(world, |history, world| {
+        let reflect_component = reflect_component(world, type_id, &type_path)?;
+        let before = read_component(world, entity, &reflect_component)?;
+        write_component(world, entity, &reflect_component, value.as_deref())?;
+        let after = read_component(world, entity, &reflect_component)?;
+        history.record(Change::Component {
+            entity,
+            reflect_component,
+            before,
+            after,
+        });
+        Ok(())
+    })
+}
+
+fn reflect_component(
+    world: &World,
+    type_id: TypeId,
+    type_path: &str,
+) -> Result<ReflectComponent, UndoError> {
+    world
+        .get_resource::<AppTypeRegistry>()
+        .and_then(|registry| {
+            registry
+                .read()
+                .get_type_data::<ReflectComponent>(type_id)
+                .cloned()
+        })
+        .ok_or_else(|| UndoError::UnregisteredComponent(type_path.into()))
+}
+
+/// Captures the value of the component, or `None` if the entity doesn't have it.
+fn read_component(
+    world: &World,
+    entity: Entity,
+    reflect_component: &ReflectComponent,
+) -> Result<Option<Box<dyn PartialReflect>>, UndoError> {
+    let value = reflect_component
+        .reflect(world.get_entity(entity)?)
+        .map(|component| {
+            component
+                .reflect_clone()
+                .map(<dyn Reflect>::into_partial_reflect)
+                .unwrap_or_else(|_| component.to_dynamic())
+        });
+    Ok(value)
+}
+
+fn write_component(
+    world: &mut World,
+    entity: Entity,
+    reflect_component: &ReflectComponent,
+    value: Option<&dyn PartialReflect>,
+) -> Result<(), UndoError> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let mut entity_mut = world.get_entity_mut(entity)?;
+    match value {
+        Some(value) => reflect_component.insert(&mut entity_mut, value, &registry.read()),
+        None => reflect_component.remove(&mut entity_mut),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hierarchy::Children,
+        observer::Trigger,
+        system::{ResMut, RunSystemOnce},
+    };
+    use alloc::{string::ToString, vec};
+    use bevy_reflect::TypePath;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct A(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct B(String);
+
+    fn world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<A>();
+            registry.register::<B>();
+            registry.register::<ChildOf>();
+            registry.register::<Children>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    fn run(world: &mut World, f: impl Fn(&mut UndoableCommands) + Send + Sync + 'static) {
+        world
+            .run_system_once(move |mut commands: UndoableCommands| f(&mut commands))
+            .unwrap();
+    }
+
+    fn undo(world: &mut World) {
+        world
+            .run_system_once(|mut commands: UndoableCommands| commands.undo())
+            .unwrap();
+    }
+
+    fn redo(world: &mut World) {
+        world
+            .run_system_once(|mut commands: UndoableCommands| commands.redo())
+            .unwrap();
+    }
+
+    #[test]
+    fn undo_redo_components() {
+        let mut world = world();
+        let entity = world.spawn(A(1)).id();
+
+        run(&mut world, move |commands| commands.insert(entity, A(2)));
+        run(&mut world, move |commands| {
+            commands.insert_reflect(entity, Box::new(B("b".to_string())));
+        });
+        run(&mut world, move |commands| commands.remove::<A>(entity));
+        assert_eq!(world.get::<A>(entity), None);
+        assert_eq!(world.resource::<UndoHistory>().undo_len(), 3);
+
+        undo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(2)));
+        undo(&mut world);
+        assert_eq!(world.get::<B>(entity), None);
+        undo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(1)));
+        assert_eq!(world.resource::<UndoHistory>().redo_len(), 3);
+
+        redo(&mut world);
+        redo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(2)));
+        assert_eq!(world.get::<B>(entity), Some(&B("b".to_string())));
+
+        run(&mut world, move |commands| {
+            commands.remove_reflect(entity, B::type_path());
+        });
+        assert_eq!(world.get::<B>(entity), None);
+        // Recording a change forgets the undone transactions.
+        assert_eq!(world.resource::<UndoHistory>().redo_len(), 0);
+        undo(&mut world);
+        assert_eq!(world.get::<B>(entity), Some(&B("b".to_string())));
+    }
+
+    #[test]
+    fn undo_redo_mutation() {
+        let mut world = world();
+        let entity = world.spawn(A(1)).id();
+
+        run(&mut world, move |commands| {
+            commands.mutate(entity, |a: &mut A| a.0 += 10);
+        });
+        assert_eq!(world.get::<A>(entity), Some(&A(11)));
+        undo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(1)));
+        redo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(11)));
+    }
+
+    #[test]
+    fn unregistered_component_is_an_error() {
+        #[derive(Component, Reflect)]
+        struct Unregistered;
+
+        let mut world = world();
+        let entity = world.spawn_empty().id();
+        world.init_resource::<UndoHistory>();
+        let result = set_component(
+            &mut world,
+            entity,
+            TypeId::of::<Unregistered>(),
+            type_name::<Unregistered>().into(),
+            Some(Box::new(Unregistered)),
+        );
+        assert!(matches!(result, Err(UndoError::UnregisteredComponent(_))));
+        assert!(!world.entity(entity).contains::<Unregistered>());
+        assert_eq!(world.resource::<UndoHistory>().undo_len(), 0);
+    }
+
+    #[test]
+    fn undo_redo_spawn_and_despawn() {
+        let mut world = world();
+        let parent = world.spawn(A(0)).id();
+        let entity = world.spawn((A(1), ChildOf(parent))).id();
+        let child = world.spawn((B("child".to_string()), ChildOf(entity))).id();
+
+        run(&mut world, move |commands| {
+            commands.insert(child, B("changed".to_string()));
+        });
+        run(&mut world, move |commands| commands.despawn(entity));
+        assert!(world.get_entity(entity).is_err());
+        assert!(world.get_entity(child).is_err());
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 0);
+
+        undo(&mut world);
+        let restored = world.entity(parent).get::<Children>().unwrap()[0];
+        assert_eq!(world.get::<A>(restored), Some(&A(1)));
+        let restored_child = world.entity(restored).get::<Children>().unwrap()[0];
+        assert_eq!(
+            world.get::<B>(restored_child),
+            Some(&B("changed".to_string()))
+        );
+        assert!(!world.entity(restored).contains::<Disabled>());
+
+        // Earlier changes apply to the restored entities.
+        undo(&mut world);
+        assert_eq!(
+            world.get::<B>(restored_child),
+            Some(&B("child".to_string()))
+        );
+
+        redo(&mut world);
+        redo(&mut world);
+        assert!(world.get_entity(restored).is_err());
+        assert_eq!(world.query::<&B>().iter(&world).count(), 0);
+
+        let spawned = world
+            .run_system_once(|mut commands: UndoableCommands| commands.spawn(A(5)).id())
+            .unwrap();
+        assert_eq!(world.get::<A>(spawned), Some(&A(5)));
+        undo(&mut world);
+        assert!(world.get_entity(spawned).is_err());
+        redo(&mut world);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn despawn_missing_entity_is_an_error() {
+        let mut world = world();
+        let parent = world.spawn(A(0)).id();
+        let entity = world.spawn((A(1), ChildOf(parent))).id();
+        let missing = world.spawn(ChildOf(parent)).id();
+        world.despawn(missing);
+
+        assert!(matches!(
+            Stash::new(&mut world, missing),
+            Err(UndoError::EntityDoesNotExist(_))
+        ));
+        assert_eq!(world.get::<ChildOf>(entity), Some(&ChildOf(parent)));
+        assert_eq!(
+            world.get::<Children>(parent).map(|children| &children[..]),
+            Some(&[entity][..])
+        );
+        assert_eq!(world.entities().len(), 2);
+    }
+
+    #[test]
+    fn transactions() {
+        let mut world = world();
+        let entity = world.spawn(A(1)).id();
+
+        run(&mut world, move |commands| {
+            commands.transaction("outer", |commands| {
+                commands.insert(entity, A(2));
+                commands.transaction("inner", |commands| {
+                    commands.insert(entity, B("b".to_string()));
+                });
+                commands.mutate(entity, |a: &mut A| a.0 *= 10);
+            });
+        });
+        let history = world.resource::<UndoHistory>();
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.next_undo(), Some(Some("outer")));
+        assert_eq!(world.get::<A>(entity), Some(&A(20)));
+
+        undo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(1)));
+        assert_eq!(world.get::<B>(entity), None);
+        assert_eq!(
+            world.resource::<UndoHistory>().next_redo(),
+            Some(Some("outer"))
+        );
+
+        redo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(20)));
+        assert_eq!(world.get::<B>(entity), Some(&B("b".to_string())));
+    }
+
+    #[test]
+    fn bounded_history() {
+        let mut world = world();
+        world.insert_resource(UndoHistory::new(2));
+        let entity = world.spawn(A(0)).id();
+        let despawned = world.spawn(A(1)).id();
+
+        run(&mut world, move |commands| commands.despawn(despawned));
+        for i in 2..5 {
+            run(&mut world, move |commands| commands.insert(entity, A(i)));
+        }
+        assert_eq!(world.resource::<UndoHistory>().undo_len(), 2);
+        // The stash of the forgotten despawn was despawned.
+        assert_eq!(world.entities().len(), 1);
+
+        undo(&mut world);
+        undo(&mut world);
+        undo(&mut world);
+        assert_eq!(world.get::<A>(entity), Some(&A(2)));
+
+        world
+            .run_system_once(|mut history: ResMut<UndoHistory>| history.set_max_len(0))
+            .unwrap();
+        assert_eq!(world.resource::<UndoHistory>().undo_len(), 0);
+    }
+
+    #[test]
+    fn undo_redo_events() {
+        #[derive(Resource, Default)]
+        struct Log(Vec<(bool, Option<Cow<'static, str>>)>);
+
+        let mut world = world();
+        world.init_resource::<Log>();
+        world.add_observer(|trigger: Trigger<Undone>, mut log: ResMut<Log>| {
+            log.0.push((true, trigger.label.clone()));
+        });
+        world.add_observer(|trigger: Trigger<Redone>, mut log: ResMut<Log>| {
+            log.0.push((false, trigger.label.clone()));
+        });
+        let entity = world.spawn(A(0)).id();
+
+        run(&mut world, move |commands| {
+            commands.transaction("set", |commands| commands.insert(entity, A(1)));
+        });
+        undo(&mut world);
+        redo(&mut world);
+        // Nothing left to redo.
+        redo(&mut world);
+        assert_eq!(
+            world.resource::<Log>().0,
+            vec![(true, Some("set".into())), (false, Some("set".into()))]
+        );
+    }
+}
@@ -1,12 +1,15 @@
+use alloc::{vec, vec::Vec};
 use core::ops::Deref;
 
+use smallvec::SmallVec;
+
 use crate::{
     archetype::Archetype,
     change_detection::{MaybeLocation, MutUntyped},
     component::{ComponentId, HookContext, Mutable},
-    entity::Entity,
+    entity::{Entity, EntityHashSet},
     event::{Event, EventId, Events, SendBatchIds},
-    observer::{Observers, TriggerTargets},
+    observer::{CachedObservers, Observers, PropagationPhase, TriggerTargets},
     prelude::{Component, QueryState},
     query::{QueryData, QueryFilter},
     relationship::RelationshipHookMode,
@@ -97,18 +100,35 @@ impl<'w> DeferredWorld<'w> {
         &mut self,
         entity: Entity,
         f: impl FnOnce(&mut T) -> R,
+    ) -> Result<Option<R>, EntityMutableFetchError> {
+        self.modify_component_with_relationship_hook_mode(entity, RelationshipHookMode::Run, f)
+    }
+
+    /// Same as [`modify_component`](DeferredWorld::modify_component), but the [`RelationshipHookMode`]
+    /// passed to the hooks can be configured.
+    #[inline]
+    pub(crate) fn modify_component_with_relationship_hook_mode<T: Component, R>(
+        &mut self,
+        entity: Entity,
+        relationship_hook_mode: RelationshipHookMode,
+        f: impl FnOnce(&mut T) -> R,
     ) -> Result<Option<R>, EntityMutableFetchError> {
         // If the component is not registered, then it doesn't exist on this entity, so no action required.
         let Some(component_id) = self.component_id::<T>() else {
             return Ok(None);
         };
 
-        self.modify_component_by_id(entity, component_id, move |component| {
-            // SAFETY: component matches the component_id collected in the above line
-            let mut component = unsafe { component.with_type::<T>() };
+        self.modify_component_by_id_with_relationship_hook_mode(
+            entity,
+            component_id,
+            relationship_hook_mode,
+            move |component| {
+                // SAFETY: component matches the component_id collected in the above line
+                let mut component = unsafe { component.with_type::<T>() };
 
-            f(&mut component)
-        })
+                f(&mut component)
+            },
+        )
     }
 
     /// Temporarily removes a [`Component`] identified by the provided
@@ -131,6 +151,24 @@ impl<'w> DeferredWorld<'w> {
         entity: Entity,
         component_id: ComponentId,
         f: impl for<'a> FnOnce(MutUntyped<'a>) -> R,
+    ) -> Result<Option<R>, EntityMutableFetchError> {
+        self.modify_component_by_id_with_relationship_hook_mode(
+            entity,
+            component_id,
+            RelationshipHookMode::Run,
+            f,
+        )
+    }
+
+    /// Same as [`modify_component_by_id`](DeferredWorld::modify_component_by_id), but the
+    /// [`RelationshipHookMode`] passed to the hooks can be configured.
+    #[inline]
+    pub(crate) fn modify_component_by_id_with_relationship_hook_mode<R>(
+        &mut self,
+        entity: Entity,
+        component_id: ComponentId,
+        relationship_hook_mode: RelationshipHookMode,
+        f: impl for<'a> FnOnce(MutUntyped<'a>) -> R,
     ) -> Result<Option<R>, EntityMutableFetchError> {
         let entity_cell = self.get_entity_mut(entity)?;
 
@@ -152,7 +190,7 @@ impl<'w> DeferredWorld<'w> {
                 entity,
                 [component_id].into_iter(),
                 MaybeLocation::caller(),
-                RelationshipHookMode::Run,
+                relationship_hook_mode,
             );
             if archetype.has_replace_observer() {
                 self.trigger_observers(
@@ -192,7 +230,7 @@ impl<'w> DeferredWorld<'w> {
                 entity,
                 [component_id].into_iter(),
                 MaybeLocation::caller(),
-                RelationshipHookMode::Run,
+                relationship_hook_mode,
             );
             if archetype.has_insert_observer() {
                 self.trigger_observers(
@@ -749,6 +787,7 @@ impl<'w> DeferredWorld<'w> {
             components,
             &mut (),
             &mut false,
+            PropagationPhase::Target,
             caller,
         );
     }
@@ -761,15 +800,47 @@ impl<'w> DeferredWorld<'w> {
     pub(crate) unsafe fn trigger_observers_with_data<E, T>(
         &mut self,
         event: ComponentId,
-        mut target: Entity,
+        target: Entity,
         components: impl Iterator<Item = ComponentId> + Clone,
         data: &mut E,
-        mut propagate: bool,
+        propagate: bool,
         caller: MaybeLocation,
     ) where
         T: Traversal<E>,
     {
-        loop {
+        if target != Entity::PLACEHOLDER
+            && self
+                .observers
+                .try_get_observers(event)
+                .is_some_and(CachedObservers::has_capture)
+        {
+            // The capture phase goes through the propagation path in reverse, towards the target.
+            for entity in self
+                .propagation_path::<E, T>(target, data)
+                .into_iter()
+                .rev()
+            {
+                let mut proceed = true;
+                Observers::invoke::<_>(
+                    self.reborrow(),
+                    event,
+                    entity,
+                    components.clone(),
+                    data,
+                    &mut proceed,
+                    PropagationPhase::Capture,
+                    caller,
+                );
+                if !proceed {
+                    return;
+                }
+            }
+        }
+
+        let mut next = Vec::new();
+        let mut stack: SmallVec<[(Entity, bool, PropagationPhase); 4]> = SmallVec::new();
+        stack.push((target, propagate, PropagationPhase::Target));
+        while let Some((target, mut propagate, phase)) = stack.pop() {
             Observers::invoke::<_>(
                 self.reborrow(),
                 event,
@@ -777,22 +848,59 @@ impl<'w> DeferredWorld<'w> {
                 components.clone(),
                 data,
                 &mut propagate,
+                phase,
                 caller,
             );
             if !propagate {
-                break;
+                continue;
             }
-            if let Some(traverse_to) = self
+            if let Some(item) = self
                 .get_entity(target)
                 .ok()
                 .and_then(|entity| entity.get_components::<T>())
-                .and_then(|item| T::traverse(item, data))
             {
-                target = traverse_to;
-            } else {
-                break;
+                T::traverse_all(item, data, &mut next);
             }
+            // Pushed in reverse so that the first branch is visited first.
+            stack.extend(
+                next.drain(..)
+                    .rev()
+                    .map(|entity| (entity, propagate, PropagationPhase::Bubble)),
+            );
+        }
+    }
+
+    /// Returns the entities an event triggered for `target` can propagate to along the traversal `T`,
+    /// excluding `target`, in the order they would be visited if every observer let it propagate.
+    ///
+    /// Each entity is only visited once, so that this terminates even if the traversal loops.
+    fn propagation_path<E, T>(&self, target: Entity, data: &E) -> Vec<Entity>
+    where
+        T: Traversal<E>,
+    {
+        let mut path = Vec::new();
+        let mut visited = EntityHashSet::default();
+        visited.insert(target);
+        let mut next = Vec::new();
+        let mut stack = vec![target];
+        while let Some(entity) = stack.pop() {
+            if entity != target {
+                path.push(entity);
+            }
+            if let Some(item) = self
+                .get_entity(entity)
+                .ok()
+                .and_then(|entity| entity.get_components::<T>())
+            {
+                T::traverse_all(item, data, &mut next);
+            }
+            stack.extend(
+                next.drain(..)
+                    .rev()
+                    .filter(|&entity| visited.insert(entity)),
+            );
         }
+        path
     }
 
     /// Sends a "global" [`Trigger`](crate::observer::Trigger) without any targets.
@@ -1190,6 +1190,20 @@ impl World {
         entity
     }
 
+    /// Spawns empty entities with the given ids, for example to restore entities despawned since a
+    /// [`WorldSnapshot`](crate::snapshot::WorldSnapshot) was captured.
+    ///
+    /// The entities must not exist, or share the same index.
+    #[track_caller]
+    pub(crate) fn spawn_empty_at(&mut self, entities: &[Entity]) {
+        self.flush();
+        self.entities.alloc_many_at(entities);
+        for &entity in entities {
+            // SAFETY: entity was just allocated
+            unsafe { self.spawn_at_empty_internal(entity, MaybeLocation::caller()) };
+        }
+    }
+
     /// # Safety
     /// must be called on an entity that was just allocated
     unsafe fn spawn_at_empty_internal(
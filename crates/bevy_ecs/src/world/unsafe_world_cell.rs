@@ -937,12 +937,13 @@ impl<'w> UnsafeEntityCell<'w> {
         // - `location` is valid
         // - aliasing rules are ensured by caller
         unsafe {
-            get_component_and_ticks(
+            get_component_and_ticks_mut(
                 self.world,
                 component_id,
                 T::STORAGE_TYPE,
                 self.entity,
                 self.location,
+                change_tick,
             )
             .map(|(value, cells, caller)| Mut {
                 // SAFETY: returned component is of type T
@@ -1060,12 +1061,13 @@ impl<'w> UnsafeEntityCell<'w> {
 
         // SAFETY: entity_location is valid, component_id is valid as checked by the line above
         unsafe {
-            get_component_and_ticks(
+            get_component_and_ticks_mut(
                 self.world,
                 component_id,
                 info.storage_type(),
                 self.entity,
                 self.location,
+                self.world.change_tick(),
             )
             .map(|(value, cells, caller)| MutUntyped {
                 // SAFETY: world access validated by caller and ties world lifetime to `MutUntyped` lifetime
@@ -1108,12 +1110,13 @@ impl<'w> UnsafeEntityCell<'w> {
 
         // SAFETY: entity_location is valid, component_id is valid as checked by the line above
         unsafe {
-            get_component_and_ticks(
+            get_component_and_ticks_mut(
                 self.world,
                 component_id,
                 info.storage_type(),
                 self.entity,
                 self.location,
+                self.world.change_tick(),
             )
             .map(|(value, cells, caller)| MutUntyped {
                 // SAFETY: world access validated by caller and ties world lifetime to `MutUntyped` lifetime
@@ -1247,6 +1250,43 @@ unsafe fn get_component_and_ticks(
     }
 }
 
+/// Get an untyped pointer to a particular [`Component`] and its [`ComponentTicks`], to be accessed mutably.
+///
+/// If the component is stored in a table, its column is marked as changed at `change_tick`
+/// so that change-filtered queries don't skip it.
+///
+/// # Safety
+/// - `location` must refer to an archetype that contains `entity`
+/// - `component_id` must be valid
+/// - `storage_type` must accurately reflect where the components for `component_id` are stored.
+/// - the caller must ensure that no aliasing rules are violated
+#[inline]
+unsafe fn get_component_and_ticks_mut(
+    world: UnsafeWorldCell<'_>,
+    component_id: ComponentId,
+    storage_type: StorageType,
+    entity: Entity,
+    location: EntityLocation,
+    change_tick: Tick,
+) -> Option<(
+    Ptr<'_>,
+    TickCells<'_>,
+    MaybeLocation<&UnsafeCell<&'static Location<'static>>>,
+)> {
+    let result =
+        // SAFETY: caller ensures that no aliasing rules are violated
+        unsafe { get_component_and_ticks(world, component_id, storage_type, entity, location) }?;
+    if storage_type == StorageType::Table {
+        // SAFETY: the component was found above, so `location` is valid, and only the atomic
+        // `max_changed_tick` of the column is written to.
+        let table = unsafe { world.fetch_table(location) };
+        if let Some(column) = table.and_then(|table| table.get_column(component_id)) {
+            column.mark_changed(change_tick);
+        }
+    }
+    Some(result)
+}
+
 /// Get an untyped pointer to the [`ComponentTicks`] on a particular [`Entity`]
 ///
 /// # Safety
@@ -9,7 +9,7 @@ use bevy_reflect::TypePath;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 
-use bevy_asset::{Asset, RenderAssetUsages};
+use bevy_asset::{Asset, AssetSize, RenderAssetUsages};
 use bevy_color::{Color, ColorToComponents, Gray, LinearRgba, Srgba, Xyza};
 use bevy_math::{AspectRatio, UVec2, UVec3, Vec2};
 use core::hash::Hash;
@@ -705,6 +705,12 @@ impl Default for Image {
     }
 }
 
+impl AssetSize for Image {
+    fn estimated_size(&self) -> usize {
+        size_of::<Self>() + self.data.as_ref().map_or(0, Vec::capacity)
+    }
+}
+
 impl Image {
     /// Creates a new image from raw binary data and the corresponding metadata.
     ///
@@ -0,0 +1,277 @@
+use crate::{
+    serde::{de::error_utils::make_custom_error, ReflectDeserializerProcessor},
+    FromReflect, FromType, PartialReflect, TypePath, TypeRegistration, TypeRegistry,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_platform::{collections::HashMap, sync::Arc};
+use core::{any::TypeId, cmp::Ordering};
+use serde::de::DeserializeSeed;
+
+use super::TypedReflectDeserializer;
+
+/// A type whose serialized form is versioned, and which can be upgraded from its older versions.
+///
+/// Each older version of the serialized form is described by a [`Migration`]: a registered type
+/// that the old data is deserialized as, and a function upgrading it to the next version.
+/// Together they form a chain that upgrades data serialized with any older version to the
+/// current [`VERSION`].
+///
+/// Registering the [`ReflectMigrate`] type data, with `#[reflect(Migrate)]`, makes
+/// the [`MigrationProcessor`] apply this chain during deserialization.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{Migrate, Migration, ReflectMigrate};
+/// // The serialized form of `Player` before `hp` was renamed to `health`.
+/// #[derive(Reflect)]
+/// struct PlayerV0 {
+///     hp: u32,
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(Migrate)]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// impl Migrate for Player {
+///     const VERSION: u32 = 1;
+///
+///     fn migrations() -> Vec<Migration> {
+///         vec![Migration::new(0, |old: PlayerV0| Player { health: old.hp })]
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<PlayerV0>();
+/// registry.register::<Player>();
+/// ```
+///
+/// [`VERSION`]: Migrate::VERSION
+pub trait Migrate: FromReflect + TypePath {
+    /// The current version of the serialized form of this type.
+    const VERSION: u32;
+
+    /// Returns the migrations upgrading each older version of this type to the next one.
+    ///
+    /// There must be exactly one migration for each version below [`VERSION`](Migrate::VERSION)
+    /// that serialized data may still use.
+    fn migrations() -> Vec<Migration>;
+}
+
+/// Upgrades the serialized form of a [`Migrate`] type from one version to the next.
+#[derive(Clone)]
+pub struct Migration {
+    version: u32,
+    type_id: TypeId,
+    type_path: &'static str,
+    upgrade: Arc<dyn Fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>> + Send + Sync>,
+}
+
+impl Migration {
+    /// Creates a migration from `version`, whose serialized form is the one of `Old`, to the
+    /// next version, whose serialized form is the one of `New`.
+    ///
+    /// `Old` must be registered in the [`TypeRegistry`] used for deserialization.
+    pub fn new<Old: FromReflect + TypePath, New: PartialReflect>(
+        version: u32,
+        upgrade: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            version,
+            type_id: TypeId::of::<Old>(),
+            type_path: Old::type_path(),
+            upgrade: Arc::new(move |value| {
+                Old::from_reflect(value)
+                    .map(|old| Box::new(upgrade(old)) as Box<dyn PartialReflect>)
+            }),
+        }
+    }
+
+    /// The version this migration upgrades from.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The [`TypeId`] of the type describing the serialized form of [`version`](Self::version).
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Upgrades a value of the serialized form of [`version`](Self::version) to the next version.
+    ///
+    /// Returns `None` if the value can't be converted to the type describing that form.
+    pub fn upgrade(&self, value: &dyn PartialReflect) -> Option<Box<dyn PartialReflect>> {
+        (self.upgrade)(value)
+    }
+}
+
+/// A struct used to upgrade reflected values serialized with older versions of a [`Migrate`]
+/// type.
+///
+/// A [`ReflectMigrate`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectMigrate {
+    version: u32,
+    migrations: Vec<Migration>,
+}
+
+impl ReflectMigrate {
+    /// The current version of the serialized form of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The migrations upgrading each older version of the type to the next one.
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// Deserializes a value serialized with an older `version` of the type, and upgrades it to
+    /// the current version.
+    ///
+    /// Nested values are deserialized with the given `processor`, if any.
+    pub fn migrate<'de, D, P>(
+        &self,
+        version: u32,
+        registry: &TypeRegistry,
+        deserializer: D,
+        processor: Option<&mut P>,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        P: ReflectDeserializerProcessor,
+    {
+        let migration = self.get_migration::<D::Error>(version)?;
+        let registration = registry.get(migration.type_id).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "no registration found for `{}`, the serialized form of version {version}",
+                migration.type_path
+            ))
+        })?;
+        let mut value = TypedReflectDeserializer::new_internal(registration, registry, processor)
+            .deserialize(deserializer)?;
+
+        for version in version..self.version {
+            let migration = self.get_migration::<D::Error>(version)?;
+            value = migration.upgrade(value.as_ref()).ok_or_else(|| {
+                make_custom_error(format_args!(
+                    "failed to convert the value of version {version} to `{}`",
+                    migration.type_path
+                ))
+            })?;
+        }
+
+        Ok(value)
+    }
+
+    fn get_migration<E: serde::de::Error>(&self, version: u32) -> Result<&Migration, E> {
+        self.migrations
+            .iter()
+            .find(|migration| migration.version == version)
+            .ok_or_else(|| make_custom_error(format_args!("no migration from version {version}")))
+    }
+}
+
+impl<T: Migrate> FromType<T> for ReflectMigrate {
+    fn from_type() -> Self {
+        ReflectMigrate {
+            version: T::VERSION,
+            migrations: T::migrations(),
+        }
+    }
+}
+
+/// A [`ReflectDeserializerProcessor`] that upgrades values serialized with older versions of
+/// types registering [`ReflectMigrate`].
+///
+/// The processor is given the versions the types were serialized with, keyed by type path.
+/// Types registering [`ReflectMigrate`] that are missing from these versions are assumed to have
+/// been serialized before they were versioned, i.e. with version `0`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypePath, TypeRegistry};
+/// # use bevy_reflect::serde::{Migrate, Migration, MigrationProcessor, ReflectMigrate, TypedReflectDeserializer};
+/// # use core::any::TypeId;
+/// # use serde::de::DeserializeSeed;
+/// # #[derive(Reflect)]
+/// # struct PlayerV0 {
+/// #     hp: u32,
+/// # }
+/// # #[derive(Reflect, PartialEq, Debug)]
+/// # #[reflect(Migrate)]
+/// # struct Player {
+/// #     health: u32,
+/// # }
+/// # impl Migrate for Player {
+/// #     const VERSION: u32 = 1;
+/// #     fn migrations() -> Vec<Migration> {
+/// #         vec![Migration::new(0, |old: PlayerV0| Player { health: old.hp })]
+/// #     }
+/// # }
+/// # let mut registry = TypeRegistry::new();
+/// # registry.register::<PlayerV0>();
+/// # registry.register::<Player>();
+/// // `Player` was serialized with version 0, before `hp` was renamed to `health`.
+/// let mut processor = MigrationProcessor::new([(Player::type_path().to_string(), 0)]);
+///
+/// let registration = registry.get(TypeId::of::<Player>()).unwrap();
+/// let mut deserializer = ron::Deserializer::from_str("(hp: 10)").unwrap();
+/// let value = TypedReflectDeserializer::with_processor(registration, &registry, &mut processor)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// let player = Player::from_reflect(value.as_ref()).unwrap();
+/// assert_eq!(player, Player { health: 10 });
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct MigrationProcessor {
+    versions: HashMap<String, u32>,
+}
+
+impl MigrationProcessor {
+    /// Creates a processor from the versions the types were serialized with, keyed by type path.
+    pub fn new(versions: impl IntoIterator<Item = (String, u32)>) -> Self {
+        Self {
+            versions: versions.into_iter().collect(),
+        }
+    }
+
+    /// The versions the types were serialized with, keyed by type path.
+    pub fn versions(&self) -> &HashMap<String, u32> {
+        &self.versions
+    }
+}
+
+impl ReflectDeserializerProcessor for MigrationProcessor {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let Some(reflect_migrate) = registration.data::<ReflectMigrate>() else {
+            return Ok(Err(deserializer));
+        };
+
+        let type_path = registration.type_info().type_path();
+        let version = self.versions.get(type_path).copied().unwrap_or(0);
+        match version.cmp(&reflect_migrate.version()) {
+            Ordering::Equal => Ok(Err(deserializer)),
+            Ordering::Less => reflect_migrate
+                .migrate(version, registry, deserializer, Some(self))
+                .map(Ok),
+            Ordering::Greater => Err(make_custom_error(format_args!(
+                "`{type_path}` was serialized with version {version}, which is newer than its current version {}",
+                reflect_migrate.version()
+            ))),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub use deserialize_with_registry::*;
 pub use deserializer::*;
+pub use migrate::*;
 pub use processor::*;
 pub use registrations::*;
 
@@ -11,6 +12,7 @@ mod error_utils;
 mod helpers;
 mod lists;
 mod maps;
+mod migrate;
 mod options;
 mod processor;
 mod registration_utils;
@@ -38,11 +40,12 @@ mod tests {
 
     use crate::{
         serde::{
-            ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializer,
+            Migrate, Migration, MigrationProcessor, ReflectDeserializer,
+            ReflectDeserializerProcessor, ReflectMigrate, ReflectSerializer,
             TypedReflectDeserializer,
         },
-        DynamicEnum, FromReflect, PartialReflect, Reflect, ReflectDeserialize, TypeRegistration,
-        TypeRegistry,
+        DynamicEnum, FromReflect, PartialReflect, Reflect, ReflectDeserialize, TypePath,
+        TypeRegistration, TypeRegistry,
     };
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -714,6 +717,84 @@ mod tests {
         assert_eq!(1, values_found);
     }
 
+    #[test]
+    fn should_migrate_through_migration_chain() {
+        #[derive(Reflect)]
+        struct PlayerV0 {
+            hp: u32,
+        }
+
+        #[derive(Reflect)]
+        struct PlayerV1 {
+            health: u32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Migrate)]
+        struct Player {
+            health: u32,
+            max_health: u32,
+        }
+
+        impl Migrate for Player {
+            const VERSION: u32 = 2;
+
+            fn migrations() -> Vec<Migration> {
+                vec![
+                    Migration::new(0, |old: PlayerV0| PlayerV1 { health: old.hp }),
+                    Migration::new(1, |old: PlayerV1| Player {
+                        health: old.health,
+                        max_health: 100,
+                    }),
+                ]
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Team {
+            players: Vec<Player>,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<PlayerV0>();
+        registry.register::<PlayerV1>();
+        registry.register::<Player>();
+        registry.register::<Team>();
+        let registration = registry.get(TypeId::of::<Team>()).unwrap();
+
+        let expected = Team {
+            players: vec![Player {
+                health: 10,
+                max_health: 100,
+            }],
+        };
+        for (version, input) in [
+            (0, "(players: [(hp: 10)])"),
+            (1, "(players: [(health: 10)])"),
+            (2, "(players: [(health: 10, max_health: 100)])"),
+        ] {
+            let mut processor =
+                MigrationProcessor::new([(Player::type_path().to_string(), version)]);
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let value =
+                TypedReflectDeserializer::with_processor(registration, &registry, &mut processor)
+                    .deserialize(&mut deserializer)
+                    .unwrap();
+            assert_eq!(expected, Team::from_reflect(value.as_ref()).unwrap());
+        }
+
+        let mut processor = MigrationProcessor::new([(Player::type_path().to_string(), 3)]);
+        let mut deserializer =
+            ron::de::Deserializer::from_str("(players: [(health: 10)])").unwrap();
+        let error =
+            TypedReflectDeserializer::with_processor(registration, &registry, &mut processor)
+                .deserialize(&mut deserializer)
+                .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("newer than its current version 2"));
+    }
+
     #[test]
     fn should_fail_from_reflect_if_processor_returns_wrong_typed_value() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -4,20 +4,21 @@ use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
-    component::ComponentId,
+    component::{ComponentId, Tick},
     entity::Entity,
     event::EventCursor,
     hierarchy::ChildOf,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
     removal_detection::RemovedComponentEntity,
+    schedule::{InternedScheduleLabel, NodeId, Schedules, Stepping},
     system::{In, Local},
-    world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
+    world::{EntityRef, EntityWorldMut, FilteredEntityRef, Mut, World},
 };
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{
     serde::{ReflectSerializer, TypedReflectDeserializer},
-    GetPath, PartialReflect, TypeRegistration, TypeRegistry,
+    GetPath, PartialReflect, Reflect, TypeRegistration, TypeRegistry,
 };
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -31,6 +32,20 @@ use crate::{
 #[cfg(all(feature = "http", not(target_family = "wasm")))]
 use {crate::schemas::open_rpc::ServerObject, bevy_utils::default};
 
+#[cfg(feature = "bevy_asset")]
+use bevy_asset::AssetServer;
+
+#[cfg(feature = "reflect_functions")]
+use {
+    crate::schemas::json_schema::{referent_type_path, JsonSchemaBevyFunction},
+    bevy_ecs::reflect::AppFunctionRegistry,
+    bevy_reflect::{
+        func::{args::ArgInfo, args::Ownership, ArgList, DynamicFunction, Return, SignatureInfo},
+        serde::TypedReflectSerializer,
+        ReflectFromReflect,
+    },
+};
+
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
 
@@ -85,6 +100,54 @@ pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger` request.
+pub const BRP_TRIGGER_METHOD: &str = "bevy/trigger";
+
+/// The method path for a `bevy/events+watch` request.
+pub const BRP_EVENTS_AND_WATCH_METHOD: &str = "bevy/events+watch";
+
+/// The method path for a `bevy/schedule/list` request.
+pub const BRP_SCHEDULE_LIST_METHOD: &str = "bevy/schedule/list";
+
+/// The method path for a `bevy/schedule/graph` request.
+pub const BRP_SCHEDULE_GRAPH_METHOD: &str = "bevy/schedule/graph";
+
+/// The method path for a `bevy/stepping/state` request.
+pub const BRP_STEPPING_STATE_METHOD: &str = "bevy/stepping/state";
+
+/// The method path for a `bevy/stepping/enable` request.
+pub const BRP_STEPPING_ENABLE_METHOD: &str = "bevy/stepping/enable";
+
+/// The method path for a `bevy/stepping/disable` request.
+pub const BRP_STEPPING_DISABLE_METHOD: &str = "bevy/stepping/disable";
+
+/// The method path for a `bevy/stepping/step_frame` request.
+pub const BRP_STEPPING_STEP_FRAME_METHOD: &str = "bevy/stepping/step_frame";
+
+/// The method path for a `bevy/stepping/continue` request.
+pub const BRP_STEPPING_CONTINUE_METHOD: &str = "bevy/stepping/continue";
+
+/// The method path for a `bevy/stepping/set_breakpoint` request.
+pub const BRP_STEPPING_SET_BREAKPOINT_METHOD: &str = "bevy/stepping/set_breakpoint";
+
+/// The method path for a `bevy/stepping/clear_breakpoint` request.
+pub const BRP_STEPPING_CLEAR_BREAKPOINT_METHOD: &str = "bevy/stepping/clear_breakpoint";
+
+/// The method path for a `bevy/call` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_CALL_METHOD: &str = "bevy/call";
+
+/// The method path for a `bevy/function/schema` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_FUNCTION_SCHEMA_METHOD: &str = "bevy/function/schema";
+
+/// The method path for a `bevy/asset/graph` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_GRAPH_METHOD: &str = "bevy/asset/graph";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -295,6 +358,131 @@ pub struct BrpMutateResourceParams {
     pub value: Value,
 }
 
+/// `bevy/send_event`: Sends an event, to be read by `EventReader`s.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type to send.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event to send.
+    pub value: Value,
+}
+
+/// `bevy/trigger`: Triggers an event, running the observers watching for it.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpTriggerParams {
+    /// The [full path] of the event type to trigger.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event to trigger.
+    pub value: Value,
+
+    /// The entity to target with the event.
+    ///
+    /// If this is `None`, only the observers watching for the event globally will run.
+    #[serde(default)]
+    pub entity: Option<Entity>,
+}
+
+/// `bevy/events+watch`: Reports the events sent since the last frame.
+///
+/// The server responds with a [`BrpEventsWatchingResponse`] each frame in which
+/// at least one of the events was sent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpEventsWatchingParams {
+    /// The [full paths] of the event types to watch.
+    ///
+    /// [full paths]: bevy_reflect::TypePath::type_path
+    pub events: Vec<String>,
+}
+
+/// `bevy/schedule/graph`: Describes the systems, system sets and ordering of a schedule.
+///
+/// The server responds with a [`BrpScheduleGraphResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleGraphParams {
+    /// The name of the schedule, as listed by `bevy/schedule/list`.
+    pub schedule: String,
+}
+
+/// `bevy/stepping/enable`: Enables stepping, optionally adding schedules to step through.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpSteppingEnableParams {
+    /// The names of the schedules to enable stepping for, as listed by `bevy/schedule/list`.
+    #[serde(default)]
+    pub schedules: Vec<String>,
+}
+
+/// `bevy/stepping/set_breakpoint` and `bevy/stepping/clear_breakpoint`: Sets or clears a
+/// breakpoint on a single system of a schedule.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingBreakpointParams {
+    /// The name of the schedule containing the system.
+    pub schedule: String,
+
+    /// The index of the system within the schedule, as reported by `bevy/schedule/graph`.
+    pub system: usize,
+}
+
+/// `bevy/asset/graph`: Describes the dependencies between the assets tracked by the [`AssetServer`].
+///
+/// The server responds with the serialized [`AssetDependencyGraph`](bevy_asset::AssetDependencyGraph),
+/// or with its DOT export if the [`BrpAssetGraphFormat::Dot`] format is requested.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpAssetGraphParams {
+    /// The path of an asset. If set, only this asset and the assets it depends on,
+    /// directly or not, are described. Otherwise, all of the tracked assets are.
+    #[serde(default)]
+    pub path: Option<String>,
+
+    /// The format of the response.
+    #[serde(default)]
+    pub format: BrpAssetGraphFormat,
+}
+
+/// The format of the response to a `bevy/asset/graph` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpAssetGraphFormat {
+    /// The graph as a JSON object.
+    #[default]
+    Json,
+    /// The graph as a string in the DOT format.
+    Dot,
+}
+
+/// `bevy/call`: Calls a function registered in the [`AppFunctionRegistry`].
+///
+/// The server responds with the serialized return value of the function, or a null if the
+/// function returns nothing.
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpCallParams {
+    /// The name the function was registered with.
+    pub function: String,
+
+    /// The serialized values of the arguments to pass to the function, in order.
+    ///
+    /// Arguments that borrow a reflected resource (`&R` or `&mut R`) are filled in from the
+    /// world and must be left out.
+    #[serde(default)]
+    pub args: Vec<Value>,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -458,6 +646,105 @@ pub struct BrpQueryRow {
     pub has: HashMap<String, Value>,
 }
 
+/// A single response from a `bevy/events+watch` request.
+///
+/// This maps the full path of each event type to the serialized values of the events of that
+/// type that were sent during the last frame, in the order they were sent.
+pub type BrpEventsWatchingResponse = HashMap<String, Vec<Value>>;
+
+/// The response to a `bevy/schedule/list` request.
+pub type BrpScheduleListResponse = Vec<String>;
+
+/// A node of a schedule graph: either a system or a system set, identified by its index.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpScheduleNode {
+    /// The index of a system.
+    System(usize),
+    /// The index of a system set.
+    Set(usize),
+}
+
+impl From<NodeId> for BrpScheduleNode {
+    fn from(node: NodeId) -> Self {
+        match node {
+            NodeId::System(index) => Self::System(index),
+            NodeId::Set(index) => Self::Set(index),
+        }
+    }
+}
+
+/// A system or system set of a schedule, as part of a [`BrpScheduleGraphResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleGraphNode {
+    /// The index of the system or system set within the schedule.
+    pub id: usize,
+
+    /// The name of the system or system set.
+    pub name: String,
+}
+
+/// A pair of systems with conflicting data access and no ordering between them.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleAmbiguity {
+    /// The indices of the two conflicting systems.
+    pub systems: (usize, usize),
+
+    /// The names of the components and resources the systems conflict on.
+    ///
+    /// If this is empty, the systems conflict on access to the whole `World`.
+    pub conflicts: Vec<String>,
+}
+
+/// The response to a `bevy/schedule/graph` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpScheduleGraphResponse {
+    /// All systems in the schedule.
+    pub systems: Vec<BrpScheduleGraphNode>,
+
+    /// All system sets in the schedule.
+    pub sets: Vec<BrpScheduleGraphNode>,
+
+    /// Pairs of `(set, member)`, where `member` is a system or set contained in `set`.
+    pub hierarchy: Vec<(BrpScheduleNode, BrpScheduleNode)>,
+
+    /// Pairs of `(before, after)`, where `before` has to run before `after`.
+    pub dependency: Vec<(BrpScheduleNode, BrpScheduleNode)>,
+
+    /// The ambiguities found the last time the schedule was built.
+    pub ambiguities: Vec<BrpScheduleAmbiguity>,
+}
+
+/// The position of [`Stepping`] within the frame, as part of a [`BrpSteppingStateResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingCursor {
+    /// The name of the schedule containing the next system to run.
+    pub schedule: String,
+
+    /// The index of the next system to run.
+    pub system: usize,
+}
+
+/// The response to a `bevy/stepping/state` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpSteppingStateResponse {
+    /// Whether stepping is currently enabled.
+    pub enabled: bool,
+
+    /// The names of the schedules with stepping enabled, in the order they run.
+    ///
+    /// This is `None` until all of these schedules have run at least once.
+    pub schedules: Option<Vec<String>>,
+
+    /// The next system that will run when stepping, if any.
+    pub cursor: Option<BrpSteppingCursor>,
+}
+
+/// A response from the world to the client that specifies the schemas of every registered
+/// function, by name.
+#[cfg(feature = "reflect_functions")]
+pub type BrpFunctionSchemaResponse = HashMap<String, JsonSchemaBevyFunction>;
+
 /// A helper function used to parse a `serde_json::Value`.
 fn parse<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, BrpError> {
     serde_json::from_value(value).map_err(|err| BrpError {
@@ -1248,6 +1535,420 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     serde_json::to_value(schemas).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams {
+        event: event_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflected_event =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+
+    if reflect_event
+        .send(world, &*reflected_event, &type_registry)
+        .is_none()
+    {
+        return Err(BrpError::event_error(format!(
+            "Event `{event_path}` was not added to the app"
+        )));
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/trigger` request coming from a client.
+pub fn process_remote_trigger_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerParams {
+        event: event_path,
+        value,
+        entity,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflected_event =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+
+    let targets = match entity {
+        Some(entity) => {
+            get_entity(world, entity)?;
+            vec![entity]
+        }
+        None => vec![],
+    };
+    reflect_event.trigger(world, &*reflected_event, &targets, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// The range of event ids of a single event type that are reported by `bevy/events+watch`
+/// during one frame.
+#[derive(Debug, Clone, Copy)]
+pub struct EventWatchingWindow {
+    /// The frame this window was computed for, identified by the world's last change tick.
+    frame: Tick,
+    /// The id of the first event to report.
+    start: usize,
+    /// One past the id of the last event to report.
+    end: usize,
+}
+
+/// Handles a `bevy/events+watch` request coming from a client.
+pub fn process_remote_events_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+    mut windows: Local<HashMap<TypeId, EventWatchingWindow>>,
+) -> BrpResult<Option<Value>> {
+    let BrpEventsWatchingParams { events } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    // The window of each event type is shared between all watching requests, and only
+    // moves forward once per frame, so that every request sees every event exactly once.
+    let frame = world.last_change_tick();
+    let mut response = BrpEventsWatchingResponse::default();
+    for event_path in events {
+        let registration = get_event_type_registration(&type_registry, &event_path)
+            .map_err(BrpError::event_error)?;
+        let reflect_event =
+            get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+        let Some(event_count) = reflect_event.event_count(world) else {
+            return Err(BrpError::event_error(format!(
+                "Event `{event_path}` was not added to the app"
+            )));
+        };
+
+        let window = windows
+            .entry(registration.type_id())
+            .or_insert(EventWatchingWindow {
+                frame,
+                start: event_count,
+                end: event_count,
+            });
+        if window.frame != frame {
+            *window = EventWatchingWindow {
+                frame,
+                start: window.end,
+                end: event_count,
+            };
+        }
+
+        let mut values = Vec::new();
+        for id in window.start..window.end {
+            let Some(reflected) = reflect_event.get(world, id) else {
+                continue;
+            };
+            values.push(serialize_event(&type_registry, &event_path, reflected)?);
+        }
+        if !values.is_empty() {
+            response.insert(event_path, values);
+        }
+    }
+
+    if response.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        serde_json::to_value(response).map_err(BrpError::internal)?,
+    ))
+}
+
+/// Handles a `bevy/schedule/list` request coming from a client.
+pub fn process_remote_schedule_list_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let schedules = world.resource::<Schedules>();
+    let mut response: BrpScheduleListResponse = schedules
+        .iter()
+        .map(|(label, _)| format!("{label:?}"))
+        .collect();
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/schedule/graph` request coming from a client.
+pub fn process_remote_schedule_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpScheduleGraphParams {
+        schedule: schedule_name,
+    } = parse_some(params)?;
+
+    let label = get_schedule_label(world, &schedule_name)?;
+    let schedules = world.resource::<Schedules>();
+    let Some(schedule) = schedules.get(label) else {
+        return Err(BrpError::schedule_not_found(&schedule_name));
+    };
+    let graph = schedule.graph();
+
+    // Once a schedule has been built, its systems are owned by the executor
+    // rather than the graph.
+    let systems = match schedule.systems() {
+        Ok(systems) => systems
+            .map(|(node, system)| BrpScheduleGraphNode {
+                id: node.index(),
+                name: system.name().into_owned(),
+            })
+            .collect(),
+        Err(_) => graph
+            .systems()
+            .map(|(node, system, _)| BrpScheduleGraphNode {
+                id: node.index(),
+                name: system.name().into_owned(),
+            })
+            .collect(),
+    };
+
+    let mut response = BrpScheduleGraphResponse {
+        systems,
+        sets: graph
+            .system_sets()
+            .map(|(node, set, _)| BrpScheduleGraphNode {
+                id: node.index(),
+                name: format!("{set:?}"),
+            })
+            .collect(),
+        hierarchy: graph
+            .hierarchy()
+            .graph()
+            .all_edges()
+            .map(|(set, member)| (set.into(), member.into()))
+            .collect(),
+        dependency: graph
+            .dependency()
+            .graph()
+            .all_edges()
+            .map(|(before, after)| (before.into(), after.into()))
+            .collect(),
+        ambiguities: graph
+            .conflicting_systems()
+            .iter()
+            .map(|(a, b, conflicts)| BrpScheduleAmbiguity {
+                systems: (a.index(), b.index()),
+                conflicts: conflicts
+                    .iter()
+                    .filter_map(|id| world.components().get_name(*id))
+                    .map(|name| name.to_string())
+                    .collect(),
+            })
+            .collect(),
+    };
+    response.sets.sort_by_key(|set| set.id);
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/state` request coming from a client.
+pub fn process_remote_stepping_state_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let response = match world.get_resource::<Stepping>() {
+        None => BrpSteppingStateResponse::default(),
+        Some(stepping) => BrpSteppingStateResponse {
+            enabled: stepping.is_enabled(),
+            schedules: stepping
+                .schedules()
+                .ok()
+                .map(|labels| labels.iter().map(|label| format!("{label:?}")).collect()),
+            cursor: stepping.cursor().map(|(label, node)| BrpSteppingCursor {
+                schedule: format!("{label:?}"),
+                system: node.index(),
+            }),
+        },
+    };
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/enable` request coming from a client.
+///
+/// This inserts the [`Stepping`] resource if it isn't present yet.
+pub fn process_remote_stepping_enable_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingEnableParams { schedules } = match params {
+        None => Default::default(),
+        Some(params) => parse(params)?,
+    };
+
+    let labels = schedules
+        .iter()
+        .map(|schedule| get_schedule_label(world, schedule))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stepping = world.get_resource_or_init::<Stepping>();
+    for label in labels {
+        stepping.add_schedule(label);
+    }
+    stepping.enable();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/disable` request coming from a client.
+pub fn process_remote_stepping_disable_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.disable();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/step_frame` request coming from a client.
+pub fn process_remote_stepping_step_frame_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.step_frame();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/continue` request coming from a client.
+pub fn process_remote_stepping_continue_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.continue_frame();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/set_breakpoint` request coming from a client.
+pub fn process_remote_stepping_set_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams { schedule, system } = parse_some(params)?;
+
+    let (label, node) = get_schedule_system(world, &schedule, system)?;
+    get_stepping_mut(world)?.set_breakpoint_node(label, node);
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/clear_breakpoint` request coming from a client.
+pub fn process_remote_stepping_clear_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams { schedule, system } = parse_some(params)?;
+
+    let (label, node) = get_schedule_system(world, &schedule, system)?;
+    get_stepping_mut(world)?.clear_breakpoint_node(label, node);
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/asset/graph` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetGraphParams { path, format } = match params {
+        None => Default::default(),
+        Some(params) => parse(params)?,
+    };
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present("AssetServer"))?;
+    let graph = match path {
+        Some(path) => {
+            let id = asset_server
+                .get_path_id(&path)
+                .ok_or_else(|| BrpError::asset_not_found(&path))?;
+            asset_server.dependency_graph_of(id)
+        }
+        None => asset_server.dependency_graph(),
+    };
+
+    match format {
+        BrpAssetGraphFormat::Json => serde_json::to_value(graph).map_err(BrpError::internal),
+        BrpAssetGraphFormat::Dot => Ok(Value::String(graph.to_dot())),
+    }
+}
+
+/// Handles a `bevy/call` request coming from a client.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_call_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let BrpCallParams {
+        function: function_name,
+        args,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let app_function_registry = world.resource::<AppFunctionRegistry>().clone();
+    let function_registry = app_function_registry.read();
+
+    let function = function_registry
+        .get(&function_name)
+        .ok_or_else(|| BrpError::function_not_found(&function_name))?;
+    let mut function_args =
+        deserialize_function_args(world, &type_registry, &function_name, function, &args)
+            .map_err(BrpError::function_error)?;
+
+    let mut arg_list = ArgList::new();
+    for function_arg in &mut function_args {
+        match function_arg {
+            FunctionArg::Owned(value) => {
+                if let Some(value) = value.take() {
+                    arg_list.push_boxed(value);
+                }
+            }
+            FunctionArg::Ref(value) => arg_list.push_ref(&**value),
+            FunctionArg::Mut(value, _) => arg_list.push_mut(&mut **value),
+        }
+    }
+    let result = function
+        .call(arg_list)
+        .map_err(BrpError::function_error)
+        .and_then(|ret| serialize_return(&type_registry, ret));
+
+    // Write the resources the function mutably borrowed back into the world.
+    for function_arg in function_args {
+        if let FunctionArg::Mut(value, Some(reflect_resource)) = function_arg {
+            reflect_resource.apply(world, &*value);
+        }
+    }
+
+    result
+}
+
+/// Handles a `bevy/function/schema` request (list the schemas of all registered functions)
+/// coming from a client.
+#[cfg(feature = "reflect_functions")]
+pub fn export_registry_functions(In(_params): In<Option<Value>>, world: &World) -> BrpResult {
+    let functions = world.resource::<AppFunctionRegistry>();
+    let functions = functions.read();
+    let schemas = functions
+        .iter()
+        .map(crate::schemas::json_schema::export_function)
+        .collect::<BrpFunctionSchemaResponse>();
+
+    serde_json::to_value(schemas).map_err(BrpError::internal)
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -1470,6 +2171,283 @@ fn get_resource_type_registration<'r>(
         .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))
 }
 
+/// Given an event path and an associated serialized value (`value`), return the
+/// deserialized value.
+fn deserialize_event(
+    type_registry: &TypeRegistry,
+    event_path: &str,
+    value: Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let event_type = get_event_type_registration(type_registry, event_path)?;
+    let reflected: Box<dyn PartialReflect> =
+        TypedReflectDeserializer::new(event_type, type_registry)
+            .deserialize(&value)
+            .map_err(|err| anyhow!("{event_path} is invalid: {err}"))?;
+    Ok(reflected)
+}
+
+/// Serialize a reflected event into the value sent to the client.
+fn serialize_event(
+    type_registry: &TypeRegistry,
+    event_path: &str,
+    reflected: &dyn Reflect,
+) -> BrpResult {
+    // The `ReflectSerializer` produces a map with a single item.
+    let reflect_serializer = ReflectSerializer::new(reflected.as_partial_reflect(), type_registry);
+    let Value::Object(serialized_object) =
+        serde_json::to_value(&reflect_serializer).map_err(BrpError::event_error)?
+    else {
+        return Err(BrpError::event_error(format!(
+            "Event `{event_path}` could not be serialized"
+        )));
+    };
+
+    serialized_object
+        .into_values()
+        .next()
+        .ok_or_else(|| BrpError::internal(anyhow!("Unexpected format of serialized event value")))
+}
+
+/// Given an event's type path, return the associated [`ReflectEvent`] from the given
+/// `type_registry` if possible.
+fn get_reflect_event<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r ReflectEvent> {
+    let event_registration = get_event_type_registration(type_registry, event_path)?;
+
+    event_registration
+        .data::<ReflectEvent>()
+        .ok_or_else(|| anyhow!("Event `{}` isn't reflectable", event_path))
+}
+
+/// Given an event's type path, return the associated [`TypeRegistration`] from the given
+/// `type_registry` if possible.
+fn get_event_type_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r TypeRegistration> {
+    type_registry
+        .get_with_type_path(event_path)
+        .ok_or_else(|| anyhow!("Unknown event type: `{}`", event_path))
+}
+
+/// An argument of a function called by `bevy/call`, holding the value that is passed.
+#[cfg(feature = "reflect_functions")]
+enum FunctionArg<'r> {
+    /// The value is moved into the function, after which this is `None`.
+    Owned(Option<Box<dyn PartialReflect>>),
+    /// The value is borrowed by the function.
+    Ref(Box<dyn PartialReflect>),
+    /// The value is mutably borrowed by the function.
+    ///
+    /// If the value is a copy of a resource, its [`ReflectResource`] is used to write it back.
+    Mut(Box<dyn PartialReflect>, Option<&'r ReflectResource>),
+}
+
+/// Given the serialized arguments (`args`) of a `bevy/call` request, return the deserialized
+/// arguments of the first signature of `function` they match.
+#[cfg(feature = "reflect_functions")]
+fn deserialize_function_args<'r>(
+    world: &World,
+    type_registry: &'r TypeRegistry,
+    function_name: &str,
+    function: &DynamicFunction,
+    args: &[Value],
+) -> AnyhowResult<Vec<FunctionArg<'r>>> {
+    let mut errors = Vec::new();
+    for signature in function.info().signatures() {
+        match deserialize_signature_args(world, type_registry, signature, args) {
+            Ok(function_args) => return Ok(function_args),
+            Err(err) => errors.push(err.to_string()),
+        }
+    }
+
+    Err(anyhow!(
+        "Invalid arguments for `{function_name}`: {}",
+        errors.join("; ")
+    ))
+}
+
+/// Given the serialized arguments (`args`) of a `bevy/call` request, return the deserialized
+/// arguments of a single function `signature`.
+///
+/// Arguments borrowing a reflected resource are copied from the `world` instead.
+#[cfg(feature = "reflect_functions")]
+fn deserialize_signature_args<'r>(
+    world: &World,
+    type_registry: &'r TypeRegistry,
+    signature: &SignatureInfo,
+    args: &[Value],
+) -> AnyhowResult<Vec<FunctionArg<'r>>> {
+    let registrations = signature
+        .args()
+        .iter()
+        .map(|arg_info| {
+            let registration = get_function_arg_type_registration(type_registry, arg_info)?;
+            Ok((
+                arg_info,
+                registration,
+                get_borrowed_resource(arg_info, registration),
+            ))
+        })
+        .collect::<AnyhowResult<Vec<_>>>()?;
+
+    let expected = registrations
+        .iter()
+        .filter(|(_, _, reflect_resource)| reflect_resource.is_none())
+        .count();
+    if args.len() != expected {
+        return Err(anyhow!("expected {expected} arguments, got {}", args.len()));
+    }
+
+    let mut args = args.iter();
+    let mut function_args = Vec::with_capacity(registrations.len());
+    for (arg_info, registration, reflect_resource) in registrations {
+        let value = match reflect_resource {
+            Some(reflect_resource) => {
+                let resource = reflect_resource.reflect(world).map_err(|err| {
+                    anyhow!(
+                        "Resource `{}` is unavailable: {err}",
+                        registration.type_info().type_path()
+                    )
+                })?;
+                from_reflect_with_registration(registration, resource.as_partial_reflect())?
+            }
+            None => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("argument {} is missing", arg_info.index()))?;
+                let reflected: Box<dyn PartialReflect> =
+                    TypedReflectDeserializer::new(registration, type_registry)
+                        .deserialize(value)
+                        .map_err(|err| {
+                            anyhow!("argument {} is invalid: {err}", arg_info.index())
+                        })?;
+                from_reflect_with_registration(registration, &*reflected)?
+            }
+        };
+
+        function_args.push(match arg_info.ownership() {
+            Ownership::Owned => FunctionArg::Owned(Some(value)),
+            Ownership::Ref => FunctionArg::Ref(value),
+            Ownership::Mut => FunctionArg::Mut(value, reflect_resource),
+        });
+    }
+
+    Ok(function_args)
+}
+
+/// Given a reflected value, return a value of the concrete type described by `registration`,
+/// as expected by [`DynamicFunction::call`].
+#[cfg(feature = "reflect_functions")]
+fn from_reflect_with_registration(
+    registration: &TypeRegistration,
+    value: &dyn PartialReflect,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    registration
+        .data::<ReflectFromReflect>()
+        .and_then(|reflect_from_reflect| reflect_from_reflect.from_reflect(value))
+        .map(PartialReflect::into_partial_reflect)
+        .ok_or_else(|| {
+            anyhow!(
+                "`{}` could not be created from its reflected value",
+                registration.type_info().type_path()
+            )
+        })
+}
+
+/// Serialize the value returned by a function into the value sent to the client.
+#[cfg(feature = "reflect_functions")]
+fn serialize_return(type_registry: &TypeRegistry, ret: Return) -> BrpResult {
+    if ret.is_unit() {
+        return Ok(Value::Null);
+    }
+
+    let value = match &ret {
+        Return::Owned(value) => &**value,
+        Return::Ref(value) => *value,
+        Return::Mut(value) => &**value,
+    };
+    serde_json::to_value(TypedReflectSerializer::new(value, type_registry))
+        .map_err(BrpError::function_error)
+}
+
+/// Given a function argument, return the [`TypeRegistration`] of its type from the given
+/// `type_registry` if possible.
+///
+/// For references, this is the registration of the referenced type.
+#[cfg(feature = "reflect_functions")]
+fn get_function_arg_type_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    arg_info: &ArgInfo,
+) -> AnyhowResult<&'r TypeRegistration> {
+    let type_path = referent_type_path(arg_info.type_path(), arg_info.ownership());
+    type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| anyhow!("Unknown argument type: `{}`", type_path))
+}
+
+/// Given a function argument, return the [`ReflectResource`] of the resource it borrows from the
+/// world, if it's a reference to a reflected resource.
+#[cfg(feature = "reflect_functions")]
+fn get_borrowed_resource<'r>(
+    arg_info: &ArgInfo,
+    registration: &'r TypeRegistration,
+) -> Option<&'r ReflectResource> {
+    match arg_info.ownership() {
+        Ownership::Owned => None,
+        Ownership::Ref | Ownership::Mut => registration.data::<ReflectResource>(),
+    }
+}
+
+/// Given a schedule's name, return its label from the [`Schedules`] resource.
+///
+/// The name is the [`Debug`](core::fmt::Debug) representation of the label, e.g. `Update`.
+fn get_schedule_label(world: &World, schedule: &str) -> Result<InternedScheduleLabel, BrpError> {
+    world
+        .resource::<Schedules>()
+        .iter()
+        .find(|(label, _)| format!("{label:?}") == schedule)
+        .map(|(_, schedule)| schedule.label())
+        .ok_or_else(|| BrpError::schedule_not_found(schedule))
+}
+
+/// Given a schedule's name and the index of one of its systems, return the schedule's label
+/// and the [`NodeId`] of the system.
+fn get_schedule_system(
+    world: &World,
+    schedule: &str,
+    system: usize,
+) -> Result<(InternedScheduleLabel, NodeId), BrpError> {
+    let label = get_schedule_label(world, schedule)?;
+    let node = NodeId::System(system);
+    let exists = world
+        .resource::<Schedules>()
+        .get(label)
+        .is_some_and(|schedule| match schedule.systems() {
+            Ok(mut systems) => systems.any(|(id, _)| id == node),
+            Err(_) => schedule.graph().get_system_at(node).is_some(),
+        });
+    if !exists {
+        return Err(BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: format!("System {system} not found in schedule `{schedule}`"),
+            data: None,
+        });
+    }
+
+    Ok((label, node))
+}
+
+/// Mutably retrieves the [`Stepping`] resource, returning an error if stepping
+/// was never enabled.
+fn get_stepping_mut(world: &mut World) -> Result<Mut<'_, Stepping>, BrpError> {
+    world
+        .get_resource_mut::<Stepping>()
+        .ok_or_else(|| BrpError::resource_not_present(core::any::type_name::<Stepping>()))
+}
+
 #[cfg(test)]
 mod tests {
     /// A generic function that tests serialization and deserialization of any type
@@ -1512,5 +2490,206 @@ mod tests {
         test_serialize_deserialize(BrpListParams {
             entity: Entity::from_raw(0),
         });
+        test_serialize_deserialize(BrpScheduleGraphResponse {
+            hierarchy: vec![(BrpScheduleNode::Set(0), BrpScheduleNode::System(1))],
+            ..Default::default()
+        });
+        test_serialize_deserialize(BrpSteppingEnableParams::default());
+        test_serialize_deserialize(BrpSteppingStateResponse::default());
+        #[cfg(feature = "bevy_asset")]
+        test_serialize_deserialize(BrpAssetGraphParams {
+            path: Some("levels/1.scn.ron".to_owned()),
+            format: BrpAssetGraphFormat::Dot,
+        });
+        #[cfg(feature = "reflect_functions")]
+        test_serialize_deserialize(BrpCallParams {
+            function: "scale".to_owned(),
+            args: vec![Value::from(2.0)],
+        });
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    mod asset_graph {
+        use super::*;
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_asset::{
+            io::{
+                memory::{Dir, MemoryAssetReader},
+                AssetSource, AssetSourceId, Reader,
+            },
+            Asset, AssetApp, AssetLoader, AssetPlugin, Handle, LoadContext,
+        };
+        use bevy_reflect::TypePath;
+        use serde_json::json;
+        use std::path::Path;
+
+        /// An asset depending on the assets whose paths are listed on each line of its file.
+        #[derive(Asset, TypePath)]
+        struct Listing {
+            #[dependency]
+            dependencies: Vec<Handle<Listing>>,
+        }
+
+        #[derive(TypePath)]
+        struct ListingLoader;
+
+        impl AssetLoader for ListingLoader {
+            type Asset = Listing;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load(
+                &self,
+                reader: &mut dyn Reader,
+                _settings: &Self::Settings,
+                load_context: &mut LoadContext<'_>,
+            ) -> Result<Self::Asset, Self::Error> {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await?;
+                Ok(Listing {
+                    dependencies: String::from_utf8_lossy(&bytes)
+                        .lines()
+                        .map(|path| load_context.load(path))
+                        .collect(),
+                })
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["list"]
+            }
+        }
+
+        /// Creates an app which loaded `a.list`, depending on `b.list`, and `c.list`.
+        fn loaded_app() -> (App, Handle<Listing>) {
+            let dir = Dir::default();
+            dir.insert_asset_text(Path::new("a.list"), "b.list");
+            dir.insert_asset_text(Path::new("b.list"), "");
+            dir.insert_asset_text(Path::new("c.list"), "");
+            let reader = MemoryAssetReader { root: dir };
+
+            let mut app = App::new();
+            app.register_asset_source(
+                AssetSourceId::Default,
+                AssetSource::build().with_reader(move || Box::new(reader.clone())),
+            )
+            .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Listing>()
+            .register_asset_loader(ListingLoader);
+
+            let asset_server = app.world().resource::<AssetServer>().clone();
+            let a = asset_server.load("a.list");
+            let c: Handle<Listing> = asset_server.load("c.list");
+            let loaded = (0..10_000).any(|_| {
+                app.update();
+                asset_server.is_loaded_with_dependencies(&a) && asset_server.is_loaded(&c)
+            });
+            assert!(loaded, "the assets did not load");
+            (app, a)
+        }
+
+        fn request(app: &mut App, params: Option<Value>) -> BrpResult {
+            app.world_mut()
+                .run_system_cached_with(process_remote_asset_graph_request, params)
+                .unwrap()
+        }
+
+        #[test]
+        fn asset_graph() {
+            let (mut app, a) = loaded_app();
+
+            let graph = request(&mut app, None).unwrap();
+            let paths: Vec<_> = graph["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|node| node["path"].clone())
+                .collect();
+            assert_eq!(paths, [json!("a.list"), json!("b.list"), json!("c.list")]);
+
+            let graph = request(&mut app, Some(json!({ "path": "a.list" }))).unwrap();
+            let nodes = graph["nodes"].as_array().unwrap();
+            assert_eq!(nodes.len(), 2);
+            assert_eq!(nodes[0]["id"], json!(a.id().untyped().to_string()));
+            assert_eq!(nodes[0]["path"], json!("a.list"));
+            assert_eq!(nodes[0]["load_state"], json!("Loaded"));
+            assert_eq!(nodes[0]["dependencies"], json!([1]));
+            assert_eq!(nodes[1]["path"], json!("b.list"));
+            assert_eq!(nodes[1]["dependents"], json!([0]));
+        }
+
+        #[test]
+        fn asset_graph_dot() {
+            let (mut app, _) = loaded_app();
+
+            let dot =
+                request(&mut app, Some(json!({ "path": "a.list", "format": "dot" }))).unwrap();
+            let dot = dot.as_str().unwrap();
+            assert!(dot.starts_with("digraph assets {"), "{dot}");
+            assert!(dot.contains("0 -> 1;"), "{dot}");
+            assert!(!dot.contains("c.list"), "{dot}");
+        }
+
+        #[test]
+        fn asset_graph_unknown_path() {
+            let (mut app, _) = loaded_app();
+
+            let error = request(&mut app, Some(json!({ "path": "missing.list" }))).unwrap_err();
+            assert_eq!(error.code, error_codes::ASSET_NOT_FOUND);
+        }
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    mod call {
+        use super::*;
+        use bevy_app::App;
+        use serde_json::json;
+
+        fn call(app: &mut App, params: Value) -> BrpResult {
+            app.world_mut()
+                .run_system_cached_with(process_remote_call_request, Some(params))
+                .unwrap()
+        }
+
+        fn scale_app() -> App {
+            let mut app = App::new();
+            app.register_function_with_name("scale", |value: f32, factor: f32| value * factor);
+            app
+        }
+
+        #[test]
+        fn call_function() {
+            let mut app = scale_app();
+
+            let result = call(&mut app, json!({ "function": "scale", "args": [1.5, 2.0] }));
+            assert_eq!(result.unwrap(), json!(3.0));
+        }
+
+        #[test]
+        fn call_unknown_function() {
+            let mut app = scale_app();
+
+            let error = call(&mut app, json!({ "function": "rotate", "args": [1.5] })).unwrap_err();
+            assert_eq!(error.code, error_codes::FUNCTION_NOT_FOUND);
+        }
+
+        #[test]
+        fn call_with_invalid_arguments() {
+            let mut app = scale_app();
+
+            let error = call(
+                &mut app,
+                json!({ "function": "scale", "args": [1.5, "twice"] }),
+            )
+            .unwrap_err();
+            assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+            assert!(
+                error.message.starts_with("Invalid arguments for `scale`"),
+                "{}",
+                error.message
+            );
+
+            let error = call(&mut app, json!({ "function": "scale", "args": [1.5] })).unwrap_err();
+            assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+        }
     }
 }
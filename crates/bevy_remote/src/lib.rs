@@ -3,8 +3,9 @@
 //! Adding the [`RemotePlugin`] to your [`App`] will setup everything needed without
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
-//! over HTTP. These *remote clients* can inspect and alter the state of the
-//! entity-component system.
+//! over HTTP, or the `RemoteWebSocketPlugin` (behind the `websocket` feature) to multiplex many
+//! requests and watching subscriptions over a single WebSocket connection. These *remote clients*
+//! can inspect and alter the state of the entity-component system.
 //!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
@@ -321,6 +322,142 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ### `bevy/send_event`
+//!
+//! Send an event, to be read by `EventReader`s. The event type must be registered with
+//! `#[reflect(Event)]`, and added to the app.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value`: The value of the event to send.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/trigger`
+//!
+//! Trigger an event, running the observers watching for it. The event type must be registered
+//! with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value`: The value of the event to trigger.
+//! - `entity` (optional): The ID of the entity to target. If omitted, only the observers
+//!   watching for the event globally will run.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/events+watch`
+//!
+//! Watch for sent events. The event types must be registered with `#[reflect(Event)]`, and added
+//! to the app.
+//!
+//! `params`:
+//! - `events`: An array of [fully-qualified type names] of events to watch.
+//!
+//! `result`: A map associating each type name with an array of the values of the events of that
+//! type sent during the last frame. A result is only produced for frames in which at least one
+//! event was sent.
+//!
+//! ### `bevy/schedule/list`
+//!
+//! List the names of all schedules in the world. This method has no parameters.
+//!
+//! Schedules that are running while the request is processed (such as `Main`) are not listed.
+//!
+//! `result`: An array of schedule names, such as `Update`.
+//!
+//! ### `bevy/schedule/graph`
+//!
+//! Describe the systems, system sets and ordering of a schedule.
+//!
+//! `params`:
+//! - `schedule`: The name of the schedule, as listed by `bevy/schedule/list`.
+//!
+//! `result`:
+//! - `systems`: An array of objects containing the `id` and `name` of each system, in the order
+//!   they were last run in.
+//! - `sets`: An array of objects containing the `id` and `name` of each system set.
+//! - `hierarchy`: An array of `[set, member]` pairs, where each node is either `{ "system": id }`
+//!   or `{ "set": id }`.
+//! - `dependency`: An array of `[before, after]` pairs, using the same node format.
+//! - `ambiguities`: An array of objects containing the ids of two conflicting `systems` and the
+//!   names of the components and resources they `conflicts` on.
+//!
+//! ### `bevy/stepping/state`
+//!
+//! Report the state of system [`Stepping`](bevy_ecs::schedule::Stepping). This method has no
+//! parameters.
+//!
+//! `result`:
+//! - `enabled`: Whether stepping is enabled.
+//! - `schedules`: The names of the schedules with stepping enabled, in the order they run, or null
+//!   if they have not all run yet.
+//! - `cursor`: The `schedule` and `system` id of the next system to run, or null.
+//!
+//! ### `bevy/stepping/enable`
+//!
+//! Enable stepping at the start of the next frame. Stepping requires the `bevy_debug_stepping`
+//! feature.
+//!
+//! `params` (optional):
+//! - `schedules` (optional): An array of names of schedules to enable stepping for.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/disable`, `bevy/stepping/step_frame`, `bevy/stepping/continue`
+//!
+//! Disable stepping, run the next system, or run all remaining systems of the stepping frame.
+//! These methods have no parameters.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/set_breakpoint`, `bevy/stepping/clear_breakpoint`
+//!
+//! Set or clear a breakpoint on a system.
+//!
+//! `params`:
+//! - `schedule`: The name of the schedule containing the system.
+//! - `system`: The id of the system, as reported by `bevy/schedule/graph`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/call`
+//!
+//! Call a function registered in the `AppFunctionRegistry`. This method requires the
+//! `reflect_functions` feature.
+//!
+//! `params`:
+//! - `function`: The name the function was registered with.
+//! - `args` (optional): An array of the values of the arguments to pass to the function. Arguments
+//!   that are references to a reflected resource, such as `&mut Gravity`, are borrowed from the
+//!   world instead and must be left out.
+//!
+//! `result`: The value returned by the function, or null if it returns nothing.
+//!
+//! ### `bevy/function/schema`
+//!
+//! Retrieve the signatures of all registered functions. This method has no parameters and
+//! requires the `reflect_functions` feature.
+//!
+//! `result`: A map associating each function name with its schema. Each schema contains an array
+//! of `signatures`, which describe the `prefixItems` accepted as `args` and the `returnType`,
+//! along with the `ownership` of each.
+//!
+//! ### `bevy/asset/graph`
+//!
+//! Retrieve the dependency graph of the assets tracked by the `AssetServer`. This method requires
+//! the `bevy_asset` feature.
+//!
+//! `params` (optional):
+//! - `path` (optional): The path of an asset. If provided, only this asset and the assets it
+//!   depends on, directly or not, are included.
+//! - `format` (optional): Either `json` (the default) or `dot`.
+//!
+//! `result`: If the format is `json`, an object with an array of `nodes`. Each node contains the
+//! `type_name`, `path`, `load_state`, `dep_load_state` and `rec_dep_load_state` of an asset, the
+//! indices of its `dependencies` and `dependents` in `nodes`, and the paths of its
+//! `loader_dependencies`. If the format is `dot`, a string describing the graph in the DOT format.
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -364,6 +501,8 @@
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
 
+extern crate alloc;
+
 use async_channel::{Receiver, Sender};
 use bevy_app::{prelude::*, MainScheduleOrder};
 use bevy_derive::{Deref, DerefMut};
@@ -384,6 +523,8 @@ pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod schemas;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -438,7 +579,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -511,6 +652,73 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
             )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_METHOD,
+                builtin_methods::process_remote_trigger_request,
+            )
+            .with_watching_method(
+                builtin_methods::BRP_EVENTS_AND_WATCH_METHOD,
+                builtin_methods::process_remote_events_watching_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SCHEDULE_LIST_METHOD,
+                builtin_methods::process_remote_schedule_list_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SCHEDULE_GRAPH_METHOD,
+                builtin_methods::process_remote_schedule_graph_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STATE_METHOD,
+                builtin_methods::process_remote_stepping_state_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_ENABLE_METHOD,
+                builtin_methods::process_remote_stepping_enable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_DISABLE_METHOD,
+                builtin_methods::process_remote_stepping_disable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STEP_FRAME_METHOD,
+                builtin_methods::process_remote_stepping_step_frame_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CONTINUE_METHOD,
+                builtin_methods::process_remote_stepping_continue_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_SET_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_set_breakpoint_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CLEAR_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_clear_breakpoint_request,
+            );
+
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_ASSET_GRAPH_METHOD,
+            builtin_methods::process_remote_asset_graph_request,
+        );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_CALL_METHOD,
+                builtin_methods::process_remote_call_request,
+            )
+            .with_method(
+                builtin_methods::BRP_FUNCTION_SCHEMA_METHOD,
+                builtin_methods::export_registry_functions,
+            );
+
+        plugin
     }
 }
 
@@ -833,6 +1041,56 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Schedule was not found in the world.
+    #[must_use]
+    pub fn schedule_not_found(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_FOUND,
+            message: format!("Schedule `{schedule}` not found"),
+            data: None,
+        }
+    }
+
+    /// Asset was not found in the asset server.
+    #[must_use]
+    pub fn asset_not_found(path: &str) -> Self {
+        Self {
+            code: error_codes::ASSET_NOT_FOUND,
+            message: format!("Asset `{path}` not found"),
+            data: None,
+        }
+    }
+
+    /// Function was not found in the function registry.
+    #[must_use]
+    pub fn function_not_found(function: &str) -> Self {
+        Self {
+            code: error_codes::FUNCTION_NOT_FOUND,
+            message: format!("Function `{function}` not found"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary function error. Possibly related to reflection.
+    #[must_use]
+    pub fn function_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::FUNCTION_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -874,6 +1132,21 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find schedule in the world.
+    pub const SCHEDULE_NOT_FOUND: i16 = -23601;
+
+    /// Could not reflect, find or send event.
+    pub const EVENT_ERROR: i16 = -23701;
+
+    /// Could not find function in the function registry.
+    pub const FUNCTION_NOT_FOUND: i16 = -23801;
+
+    /// Could not prepare the arguments of, call, or serialize the result of a function.
+    pub const FUNCTION_ERROR: i16 = -23802;
+
+    /// Could not find asset in the asset server.
+    pub const ASSET_NOT_FOUND: i16 = -23901;
 }
 
 /// The result of a request.
@@ -10,11 +10,30 @@ use core::any::TypeId;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 
+#[cfg(feature = "reflect_functions")]
+use bevy_reflect::func::{args::Ownership, DynamicFunction, SignatureInfo};
+
 /// Exports schema info for a given type
 pub fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
     (reg.type_info().type_path().to_owned(), reg.into())
 }
 
+/// Exports schema info for a given function
+#[cfg(feature = "reflect_functions")]
+pub fn export_function(function: &DynamicFunction) -> (String, JsonSchemaBevyFunction) {
+    let name = function.name().map(ToString::to_string).unwrap_or_default();
+    let schema = JsonSchemaBevyFunction {
+        name: name.clone(),
+        signatures: function
+            .info()
+            .signatures()
+            .iter()
+            .map(JsonSchemaBevySignature::from)
+            .collect(),
+    };
+    (name, schema)
+}
+
 fn get_registered_reflect_types(reg: &TypeRegistration) -> Vec<String> {
     // Vec could be moved to allow registering more types by game maker.
     let registered_reflect_types: [(TypeId, &str); 5] = [
@@ -239,6 +258,89 @@ pub struct JsonSchemaBevyType {
     pub items: Option<Value>,
 }
 
+/// JSON Schema for a function registered in the `AppFunctionRegistry`.
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevyFunction {
+    /// Bevy specific field, name the function is registered with.
+    pub name: String,
+    /// Bevy specific field, signatures of the function.
+    ///
+    /// Overloaded functions have one signature per overload.
+    pub signatures: Vec<JsonSchemaBevySignature>,
+}
+
+/// JSON Schema for a single function signature.
+///
+/// The schema validates the array of arguments passed to the function, with one item per
+/// argument.
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevySignature {
+    /// The type keyword is fundamental to JSON Schema. It specifies the data type for a schema.
+    #[serde(rename = "type")]
+    pub schema_type: SchemaType,
+    /// Validation succeeds if each element of the instance validates against the schema at the same position, if any.
+    ///
+    /// Each item also contains the Bevy specific `ownership` of the argument and its `name`, if any.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub prefix_items: Vec<Value>,
+    /// Always `false`, as functions take a fixed number of arguments.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub items: Option<Value>,
+    /// Bevy specific field, the type and `ownership` of the returned value.
+    pub return_type: Value,
+}
+
+#[cfg(feature = "reflect_functions")]
+impl From<&SignatureInfo> for JsonSchemaBevySignature {
+    fn from(signature: &SignatureInfo) -> Self {
+        let return_info = signature.return_info();
+        JsonSchemaBevySignature {
+            schema_type: SchemaType::Array,
+            prefix_items: signature
+                .args()
+                .iter()
+                .map(|arg| {
+                    let mut schema = function_type_ref(arg.type_path(), arg.ownership());
+                    if let (Some(name), Some(schema)) = (arg.name(), schema.as_object_mut()) {
+                        schema.insert("name".to_owned(), name.into());
+                    }
+                    schema
+                })
+                .collect(),
+            items: Some(false.into()),
+            return_type: function_type_ref(return_info.type_path(), return_info.ownership()),
+        }
+    }
+}
+
+/// Reference to the type of a function argument or return value, along with its ownership.
+#[cfg(feature = "reflect_functions")]
+fn function_type_ref(type_path: &'static str, ownership: Ownership) -> Value {
+    let path = referent_type_path(type_path, ownership);
+    let ownership = match ownership {
+        Ownership::Owned => "owned",
+        Ownership::Ref => "ref",
+        Ownership::Mut => "mut",
+    };
+    json!({"type": json!({ "$ref": format!("#/$defs/{path}") }), "ownership": ownership})
+}
+
+/// Returns the type path of a function argument or return value, without the `&` or `&mut` of
+/// references.
+#[cfg(feature = "reflect_functions")]
+pub(crate) fn referent_type_path(type_path: &'static str, ownership: Ownership) -> &'static str {
+    match ownership {
+        Ownership::Owned => Some(type_path),
+        Ownership::Ref => type_path.strip_prefix('&'),
+        Ownership::Mut => type_path.strip_prefix("&mut "),
+    }
+    .unwrap_or(type_path)
+}
+
 /// Kind of json schema, maps [`TypeInfo`] type
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub enum SchemaKind {
@@ -540,4 +642,51 @@ mod tests {
         });
         assert_eq!(schema_as_value, value);
     }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn reflect_export_function() {
+        use bevy_reflect::func::IntoFunction;
+
+        fn scale(value: &mut f32, factor: f32) -> f32 {
+            *value *= factor;
+            *value
+        }
+
+        let function = scale.into_function().with_name("scale");
+        let (name, schema) = export_function(&function);
+        assert_eq!(name, "scale");
+
+        let schema_as_value = serde_json::to_value(&schema).expect("Should serialize");
+        let value = json!({
+          "name": "scale",
+          "signatures": [
+            {
+              "type": "array",
+              "prefixItems": [
+                {
+                  "type": {
+                    "$ref": "#/$defs/f32"
+                  },
+                  "ownership": "mut"
+                },
+                {
+                  "type": {
+                    "$ref": "#/$defs/f32"
+                  },
+                  "ownership": "owned"
+                }
+              ],
+              "items": false,
+              "returnType": {
+                "type": {
+                  "$ref": "#/$defs/f32"
+                },
+                "ownership": "owned"
+              }
+            }
+          ]
+        });
+        assert_eq!(schema_as_value, value);
+    }
 }
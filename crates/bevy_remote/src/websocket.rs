@@ -0,0 +1,647 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//!
+//! Unlike the HTTP transport, a single WebSocket connection can carry any number of
+//! concurrent requests. Clients send requests (or batches of requests) as text frames
+//! and receive each [`BrpResponse`] as a separate text frame, matched up by its `id`.
+//!
+//! ## Subscriptions
+//!
+//! Calling a watching method (`bevy/get+watch`, `bevy/list+watch`, etc.) creates a
+//! *subscription*. The immediate response to the request contains the ID of the new
+//! subscription:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "id": 0,
+//!     "result": {
+//!         "subscription": 3
+//!     }
+//! }
+//! ```
+//!
+//! Every time the watching method produces a value, it is pushed to the client as a
+//! JSON-RPC notification with the `bevy/subscription` method:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "method": "bevy/subscription",
+//!     "params": {
+//!         "subscription": 3,
+//!         "result": { "components": {}, "removed": [] }
+//!     }
+//! }
+//! ```
+//!
+//! A subscription stays active until the client calls `bevy/unsubscribe` with
+//! `{ "subscription": 3 }` as its `params`, or until the connection is closed.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    error_codes, BrpBatch, BrpError, BrpMessage, BrpPayload, BrpRequest, BrpResponse, BrpResult,
+    BrpSender,
+};
+use alloc::sync::Arc;
+use anyhow::Result as AnyhowResult;
+use async_channel::{Receiver, Sender};
+use async_io::Async;
+use async_tungstenite::{tungstenite::Message, WebSocketStream};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::Res;
+use bevy_platform::collections::HashMap;
+use bevy_tasks::{
+    futures_lite::{future, StreamExt},
+    IoTaskPool,
+};
+use core::{
+    net::{IpAddr, Ipv4Addr},
+    sync::atomic::{AtomicU64, Ordering},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is one above the default port of the HTTP transport, so both can be used at once.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The name of the method that ends a subscription.
+///
+/// This method is handled by the WebSocket transport itself.
+pub const BRP_UNSUBSCRIBE_METHOD: &str = "bevy/unsubscribe";
+
+/// The method name of the notifications that carry the values produced by a subscription.
+pub const BRP_SUBSCRIPTION_NOTIFICATION: &str = "bevy/subscription";
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HostAddress(self.address))
+            .insert_resource(HostPort(self.port))
+            .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+/// A resource containing the IP address that the WebSocket server will host on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the IP address that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct HostAddress(pub IpAddr);
+
+/// A resource containing the port number that the WebSocket server will listen on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the host that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct HostPort(pub u16);
+
+/// The response to a request for a watching method made over WebSocket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSubscribeResponse {
+    /// The ID of the newly created subscription.
+    ///
+    /// This is unique within the connection, and is used to match notifications
+    /// to their subscription and to unsubscribe.
+    pub subscription: u64,
+}
+
+/// `bevy/unsubscribe`: Ends a subscription created by a watching method.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpUnsubscribeParams {
+    /// The ID of the subscription to end.
+    pub subscription: u64,
+}
+
+/// A notification pushed to the client whenever a subscription produces a value.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpSubscriptionNotification {
+    /// This field is mandatory and must be set to `"2.0"`.
+    pub jsonrpc: &'static str,
+
+    /// Always [`BRP_SUBSCRIPTION_NOTIFICATION`].
+    pub method: &'static str,
+
+    /// The subscription and the value it produced.
+    pub params: BrpSubscriptionParams,
+}
+
+impl BrpSubscriptionNotification {
+    /// Generates a [`BrpSubscriptionNotification`] from a subscription ID and a `Result`.
+    #[must_use]
+    pub fn new(subscription: u64, result: BrpResult) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            method: BRP_SUBSCRIPTION_NOTIFICATION,
+            params: BrpSubscriptionParams {
+                subscription,
+                payload: BrpPayload::from(result),
+            },
+        }
+    }
+}
+
+/// The `params` of a [`BrpSubscriptionNotification`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpSubscriptionParams {
+    /// The ID of the subscription that produced this value.
+    pub subscription: u64,
+
+    /// The value produced by the watching method.
+    #[serde(flatten)]
+    pub payload: BrpPayload,
+}
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(
+    request_sender: Res<BrpSender>,
+    address: Res<HostAddress>,
+    remote_port: Res<HostPort>,
+) {
+    IoTaskPool::get()
+        .spawn(server_main(
+            address.0,
+            remote_port.0,
+            request_sender.clone(),
+        ))
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+/// Something that happened on a WebSocket connection.
+enum ConnectionEvent {
+    /// A frame arrived from the client.
+    Incoming(Option<Result<Message, async_tungstenite::tungstenite::Error>>),
+    /// A serialized response or notification is ready to be sent to the client.
+    Outgoing(Option<String>),
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let mut websocket = async_tungstenite::accept_async(client).await?;
+    let (outgoing_sender, outgoing_receiver) = async_channel::unbounded();
+    let connection = Connection {
+        request_sender,
+        outgoing_sender,
+        subscriptions: Arc::new(Mutex::new(HashMap::default())),
+        next_subscription: Arc::new(AtomicU64::new(0)),
+    };
+
+    let result = connection_loop(&mut websocket, &connection, &outgoing_receiver).await;
+
+    // Closing the receivers lets the `RemotePlugin` know that nobody is
+    // listening to these watching requests anymore.
+    for (_, receiver) in connection.subscriptions.lock().unwrap().drain() {
+        receiver.close();
+    }
+
+    result
+}
+
+async fn connection_loop(
+    websocket: &mut WebSocketStream<Async<TcpStream>>,
+    connection: &Connection,
+    outgoing_receiver: &Receiver<String>,
+) -> AnyhowResult<()> {
+    loop {
+        let event = future::or(
+            async { ConnectionEvent::Incoming(websocket.next().await) },
+            async { ConnectionEvent::Outgoing(outgoing_receiver.recv().await.ok()) },
+        )
+        .await;
+
+        match event {
+            ConnectionEvent::Incoming(None) | ConnectionEvent::Outgoing(None) => return Ok(()),
+            ConnectionEvent::Incoming(Some(message)) => {
+                let batch_bytes = match message? {
+                    Message::Text(text) => text.as_bytes().to_owned(),
+                    Message::Binary(bytes) => bytes.to_vec(),
+                    Message::Close(_) => return Ok(()),
+                    // Pings are answered by `tungstenite` itself.
+                    Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => continue,
+                };
+
+                let connection = connection.clone();
+                IoTaskPool::get()
+                    .spawn(async move {
+                        let _ = connection.process_request_batch(&batch_bytes).await;
+                    })
+                    .detach();
+            }
+            ConnectionEvent::Outgoing(Some(serialized)) => {
+                websocket.send(Message::text(serialized)).await?;
+            }
+        }
+    }
+}
+
+/// The state shared by all tasks serving a single WebSocket connection.
+#[derive(Clone)]
+struct Connection {
+    /// The channel to the [`RemotePlugin`](crate::RemotePlugin) mailbox.
+    request_sender: Sender<BrpMessage>,
+    /// Serialized messages that should be sent to the client.
+    outgoing_sender: Sender<String>,
+    /// The receivers of all ongoing subscriptions, keyed by subscription ID.
+    subscriptions: Arc<Mutex<HashMap<u64, Receiver<BrpResult>>>>,
+    /// The ID that will be assigned to the next subscription.
+    next_subscription: Arc<AtomicU64>,
+}
+
+/// A subscription that has been registered but whose values are not being
+/// forwarded to the client yet.
+struct PendingSubscription {
+    id: u64,
+    receiver: Receiver<BrpResult>,
+}
+
+impl Connection {
+    /// Handles a batch of requests coming from the client, and queues the responses.
+    async fn process_request_batch(&self, batch_bytes: &[u8]) -> AnyhowResult<()> {
+        let batch: Result<BrpBatch, _> = serde_json::from_slice(batch_bytes);
+
+        let mut subscriptions = Vec::new();
+        let serialized = match batch {
+            Ok(BrpBatch::Single(request)) => {
+                let (response, subscription) = self.process_single_request(request).await;
+                subscriptions.extend(subscription);
+                serde_json::to_string(&response)?
+            }
+            Ok(BrpBatch::Batch(requests)) => {
+                let mut responses = Vec::new();
+                for request in requests {
+                    let (response, subscription) = self.process_single_request(request).await;
+                    subscriptions.extend(subscription);
+                    responses.push(response);
+                }
+                serde_json::to_string(&responses)?
+            }
+            Err(err) => serde_json::to_string(&BrpResponse::new(
+                None,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            ))?,
+        };
+        self.outgoing_sender.send(serialized).await?;
+
+        // Only start forwarding notifications once the client knows the subscription IDs.
+        for subscription in subscriptions {
+            let connection = self.clone();
+            IoTaskPool::get()
+                .spawn(async move { connection.forward_subscription(subscription).await })
+                .detach();
+        }
+
+        Ok(())
+    }
+
+    /// Handles a single request coming from the client.
+    ///
+    /// If the request created a subscription, it is returned alongside the response.
+    async fn process_single_request(
+        &self,
+        request: Value,
+    ) -> (BrpResponse, Option<PendingSubscription>) {
+        // Reach in and get the request ID early so that we can report it even when parsing fails.
+        let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+        let request: BrpRequest = match serde_json::from_value(request) {
+            Ok(v) => v,
+            Err(err) => {
+                let error = BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                };
+                return (BrpResponse::new(id, Err(error)), None);
+            }
+        };
+
+        if request.jsonrpc != "2.0" {
+            let error = BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                data: None,
+            };
+            return (BrpResponse::new(id, Err(error)), None);
+        }
+
+        if request.method == BRP_UNSUBSCRIBE_METHOD {
+            let result = self.unsubscribe(request.params);
+            return (BrpResponse::new(request.id, result), None);
+        }
+
+        let watch = request.method.contains("+watch");
+        let size = if watch { 8 } else { 1 };
+        let (result_sender, result_receiver) = async_channel::bounded(size);
+
+        let _ = self
+            .request_sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                sender: result_sender,
+            })
+            .await;
+
+        if watch {
+            let subscription = self.next_subscription.fetch_add(1, Ordering::Relaxed);
+            self.subscriptions
+                .lock()
+                .unwrap()
+                .insert(subscription, result_receiver.clone());
+
+            let result = serde_json::to_value(BrpSubscribeResponse { subscription })
+                .map_err(BrpError::internal);
+            let pending = PendingSubscription {
+                id: subscription,
+                receiver: result_receiver,
+            };
+            (BrpResponse::new(request.id, result), Some(pending))
+        } else {
+            let result = result_receiver
+                .recv()
+                .await
+                .unwrap_or_else(|err| Err(BrpError::internal(err)));
+            (BrpResponse::new(request.id, result), None)
+        }
+    }
+
+    /// Handles a `bevy/unsubscribe` request coming from the client.
+    fn unsubscribe(&self, params: Option<Value>) -> BrpResult {
+        let BrpUnsubscribeParams { subscription } =
+            serde_json::from_value(params.unwrap_or_default()).map_err(|err| BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: err.to_string(),
+                data: None,
+            })?;
+
+        let Some(receiver) = self.subscriptions.lock().unwrap().remove(&subscription) else {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("Subscription {subscription} not found"),
+                data: None,
+            });
+        };
+
+        // This lets the `RemotePlugin` drop the watching request.
+        receiver.close();
+        Ok(Value::Null)
+    }
+
+    /// Sends every value produced by a subscription to the client as a notification,
+    /// until the subscription is closed.
+    async fn forward_subscription(&self, subscription: PendingSubscription) {
+        while let Ok(result) = subscription.receiver.recv().await {
+            let notification = BrpSubscriptionNotification::new(subscription.id, result);
+            let Ok(serialized) = serde_json::to_string(&notification) else {
+                continue;
+            };
+            if self.outgoing_sender.send(serialized).await.is_err() {
+                break;
+            }
+        }
+
+        subscription.receiver.close();
+        self.subscriptions.lock().unwrap().remove(&subscription.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_io::Timer;
+    use async_tungstenite::client_async;
+    use bevy_tasks::{
+        tick_global_task_pools_on_main_thread, AsyncComputeTaskPool, ComputeTaskPool, TaskPool,
+    };
+    use core::time::Duration;
+    use serde_json::json;
+
+    /// Answers `test/echo` with its params, and `test/count+watch` with increasing numbers
+    /// until the subscription is closed, which is reported to `closed`.
+    async fn mailbox(requests: Receiver<BrpMessage>, closed: Sender<()>) {
+        while let Ok(message) = requests.recv().await {
+            match message.method.as_str() {
+                "test/echo" => {
+                    let _ = message.sender.send(Ok(message.params.unwrap())).await;
+                }
+                "test/count+watch" => {
+                    let closed = closed.clone();
+                    IoTaskPool::get()
+                        .spawn(async move {
+                            let mut count = 0;
+                            while message.sender.send(Ok(json!(count))).await.is_ok() {
+                                count += 1;
+                                Timer::after(Duration::from_millis(10)).await;
+                            }
+                            let _ = closed.send(()).await;
+                        })
+                        .detach();
+                }
+                method => panic!("unexpected method {method}"),
+            }
+        }
+    }
+
+    async fn receive(websocket: &mut WebSocketStream<Async<TcpStream>>) -> Value {
+        match websocket.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str(text.as_str()).unwrap(),
+            message => panic!("unexpected message {message:?}"),
+        }
+    }
+
+    /// Receives frames until the response with the given `id`, skipping notifications.
+    async fn receive_response(websocket: &mut WebSocketStream<Async<TcpStream>>, id: u64) -> Value {
+        loop {
+            let message = receive(websocket).await;
+            if message["id"] == id {
+                return message;
+            }
+            assert_eq!(message["method"], BRP_SUBSCRIPTION_NOTIFICATION);
+        }
+    }
+
+    #[test]
+    fn websocket_round_trip() {
+        ComputeTaskPool::get_or_init(TaskPool::new);
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        IoTaskPool::get_or_init(TaskPool::new);
+        let (request_sender, request_receiver) = async_channel::unbounded();
+        let (closed_sender, closed_receiver) = async_channel::unbounded();
+        IoTaskPool::get()
+            .spawn(mailbox(request_receiver, closed_sender))
+            .detach();
+
+        let test = async {
+            let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+            let address = listener.get_ref().local_addr().unwrap();
+            let client = Async::<TcpStream>::connect(address).await.unwrap();
+            let (server, _) = listener.accept().await.unwrap();
+            IoTaskPool::get()
+                .spawn(handle_client(server, request_sender))
+                .detach();
+            let (mut websocket, _) = client_async(format!("ws://{address}"), client)
+                .await
+                .unwrap();
+
+            // single requests and batches are answered in their own frame
+            let request =
+                json!({ "jsonrpc": "2.0", "id": 0, "method": "test/echo", "params": [1] });
+            websocket
+                .send(Message::text(request.to_string()))
+                .await
+                .unwrap();
+            assert_eq!(
+                receive(&mut websocket).await,
+                json!({ "jsonrpc": "2.0", "id": 0, "result": [1] })
+            );
+
+            let batch = json!([
+                { "jsonrpc": "2.0", "id": 1, "method": "test/echo", "params": "a" },
+                { "jsonrpc": "1.0", "id": 2, "method": "test/echo", "params": "b" },
+            ]);
+            websocket
+                .send(Message::text(batch.to_string()))
+                .await
+                .unwrap();
+            let responses = receive(&mut websocket).await;
+            assert_eq!(
+                responses[0],
+                json!({ "jsonrpc": "2.0", "id": 1, "result": "a" })
+            );
+            assert_eq!(responses[1]["id"], 2);
+            assert_eq!(responses[1]["error"]["code"], error_codes::INVALID_REQUEST);
+
+            // watching methods create subscriptions, whose values are pushed as notifications
+            let request = json!({ "jsonrpc": "2.0", "id": 3, "method": "test/count+watch" });
+            websocket
+                .send(Message::text(request.to_string()))
+                .await
+                .unwrap();
+            assert_eq!(
+                receive(&mut websocket).await,
+                json!({ "jsonrpc": "2.0", "id": 3, "result": { "subscription": 0 } })
+            );
+            for count in 0..2 {
+                assert_eq!(
+                    receive(&mut websocket).await,
+                    json!({
+                        "jsonrpc": "2.0",
+                        "method": BRP_SUBSCRIPTION_NOTIFICATION,
+                        "params": { "subscription": 0, "result": count },
+                    })
+                );
+            }
+
+            // unsubscribing closes the watching request
+            let request = json!({
+                "jsonrpc": "2.0",
+                "id": 4,
+                "method": BRP_UNSUBSCRIBE_METHOD,
+                "params": { "subscription": 0 },
+            });
+            websocket
+                .send(Message::text(request.to_string()))
+                .await
+                .unwrap();
+            assert_eq!(
+                receive_response(&mut websocket, 4).await,
+                json!({ "jsonrpc": "2.0", "id": 4, "result": null })
+            );
+            closed_receiver.recv().await.unwrap();
+
+            websocket
+                .send(Message::text(request.to_string()))
+                .await
+                .unwrap();
+            let response = receive_response(&mut websocket, 4).await;
+            assert_eq!(response["error"]["code"], error_codes::INVALID_PARAMS);
+        };
+        // Without the `multi_threaded` feature, spawned tasks only run when the pools are ticked.
+        let tick = async {
+            loop {
+                tick_global_task_pools_on_main_thread();
+                future::yield_now().await;
+            }
+        };
+        async_io::block_on(future::or(test, tick));
+    }
+}
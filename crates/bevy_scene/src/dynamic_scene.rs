@@ -1,4 +1,4 @@
-use crate::{DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{DynamicSceneBuilder, DynamicScenePatch, Scene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::reflect::{ReflectMapEntities, ReflectResource};
 use bevy_ecs::{
@@ -6,7 +6,7 @@ use bevy_ecs::{
     reflect::{AppTypeRegistry, ReflectComponent},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath};
+use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
 
 use crate::reflect_utils::clone_reflect_value;
 use bevy_ecs::component::ComponentCloneBehavior;
@@ -15,7 +15,6 @@ use bevy_ecs::relationship::RelationshipHookMode;
 #[cfg(feature = "serialize")]
 use {
     crate::{ron, serde::SceneSerializer},
-    bevy_reflect::TypeRegistry,
     serde::Serialize,
 };
 
@@ -59,6 +58,15 @@ impl DynamicScene {
             .build()
     }
 
+    /// Compute the changes that turn `baseline` into this scene.
+    ///
+    /// Entities are matched by their [`DynamicEntity::entity`] identifier, and components and
+    /// resources by their type. Values are compared with [`PartialReflect::reflect_partial_eq`],
+    /// and values that can't be compared are considered changed.
+    pub fn diff(&self, baseline: &DynamicScene) -> DynamicScenePatch {
+        DynamicScenePatch::from_diff(baseline, self)
+    }
+
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
@@ -70,107 +78,15 @@ impl DynamicScene {
         entity_map: &mut EntityHashMap<Entity>,
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
-        let type_registry = type_registry.read();
-
-        // First ensure that every entity in the scene has a corresponding world
-        // entity in the entity map.
-        for scene_entity in &self.entities {
-            // Fetch the entity with the given entity id from the `entity_map`
-            // or spawn a new entity with a transiently unique id if there is
-            // no corresponding entry.
-            entity_map
-                .entry(scene_entity.entity)
-                .or_insert_with(|| world.spawn_empty().id());
-        }
-
-        for scene_entity in &self.entities {
-            // Fetch the entity with the given entity id from the `entity_map`.
-            let entity = *entity_map
-                .get(&scene_entity.entity)
-                .expect("should have previously spawned an empty entity");
-
-            // Apply/ add each component to the given entity.
-            for component in &scene_entity.components {
-                let type_info = component.get_represented_type_info().ok_or_else(|| {
-                    SceneSpawnError::NoRepresentedType {
-                        type_path: component.reflect_type_path().to_string(),
-                    }
-                })?;
-                let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                    SceneSpawnError::UnregisteredButReflectedType {
-                        type_path: type_info.type_path().to_string(),
-                    }
-                })?;
-                let reflect_component =
-                    registration.data::<ReflectComponent>().ok_or_else(|| {
-                        SceneSpawnError::UnregisteredComponent {
-                            type_path: type_info.type_path().to_string(),
-                        }
-                    })?;
-
-                {
-                    let component_id = reflect_component.register_component(world);
-                    // SAFETY: we registered the component above. the info exists
-                    #[expect(unsafe_code, reason = "this is faster")]
-                    let component_info =
-                        unsafe { world.components().get_info_unchecked(component_id) };
-                    if *component_info.clone_behavior() == ComponentCloneBehavior::Ignore {
-                        continue;
-                    }
-                }
-
-                SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
-                    reflect_component.apply_or_insert_mapped(
-                        &mut world.entity_mut(entity),
-                        component.as_partial_reflect(),
-                        &type_registry,
-                        mapper,
-                        RelationshipHookMode::Skip,
-                    );
-                });
-            }
-        }
-
-        // Insert resources after all entities have been added to the world.
-        // This ensures the entities are available for the resources to reference during mapping.
-        for resource in &self.resources {
-            let type_info = resource.get_represented_type_info().ok_or_else(|| {
-                SceneSpawnError::NoRepresentedType {
-                    type_path: resource.reflect_type_path().to_string(),
-                }
-            })?;
-            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                SceneSpawnError::UnregisteredButReflectedType {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
-                SceneSpawnError::UnregisteredResource {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-
-            // If this component references entities in the scene, update
-            // them to the entities in the world.
-            let mut cloned_resource;
-            let partial_reflect_resource = if let Some(map_entities) =
-                registration.data::<ReflectMapEntities>()
-            {
-                cloned_resource = clone_reflect_value(resource.as_partial_reflect(), registration);
-                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
-                    map_entities.map_entities(cloned_resource.as_partial_reflect_mut(), mapper);
-                });
-                cloned_resource.as_partial_reflect()
-            } else {
-                resource.as_partial_reflect()
-            };
-
-            // If the world already contains an instance of the given resource
-            // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, partial_reflect_resource, &type_registry);
-        }
-
-        Ok(())
+        write_to_world_mapped(
+            world,
+            entity_map,
+            &type_registry.read(),
+            self.entities
+                .iter()
+                .map(|scene_entity| (scene_entity.entity, scene_entity.components.as_slice())),
+            &self.resources,
+        )
     }
 
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
@@ -199,6 +115,128 @@ impl DynamicScene {
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the binary Bevy scene format (`.scn.bin`).
+    ///
+    /// The binary format holds the same data as [`DynamicScene::serialize`], encoded with
+    /// [postcard]. It is much smaller and faster to load than RON, but not human-readable.
+    /// To deserialize the scene, use the [`BinarySceneLoader`].
+    ///
+    /// [`BinarySceneLoader`]: crate::BinarySceneLoader
+    /// [postcard]: https://crates.io/crates/postcard
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, postcard::Error> {
+        postcard::to_allocvec(&SceneSerializer::new(self, registry))
+    }
+}
+
+/// Write the given resources and the components of the given entities to the world, mapping
+/// entities through `entity_map`.
+///
+/// Entities that are missing from `entity_map` are spawned and added to it.
+pub(crate) fn write_to_world_mapped<'a>(
+    world: &mut World,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+    entities: impl Iterator<Item = (Entity, &'a [Box<dyn PartialReflect>])> + Clone,
+    resources: &[Box<dyn PartialReflect>],
+) -> Result<(), SceneSpawnError> {
+    // First ensure that every entity in the scene has a corresponding world
+    // entity in the entity map.
+    for (scene_entity, _) in entities.clone() {
+        // Fetch the entity with the given entity id from the `entity_map`
+        // or spawn a new entity with a transiently unique id if there is
+        // no corresponding entry.
+        entity_map
+            .entry(scene_entity)
+            .or_insert_with(|| world.spawn_empty().id());
+    }
+
+    for (scene_entity, components) in entities {
+        // Fetch the entity with the given entity id from the `entity_map`.
+        let entity = *entity_map
+            .get(&scene_entity)
+            .expect("should have previously spawned an empty entity");
+
+        // Apply/ add each component to the given entity.
+        for component in components {
+            let type_info = component.get_represented_type_info().ok_or_else(|| {
+                SceneSpawnError::NoRepresentedType {
+                    type_path: component.reflect_type_path().to_string(),
+                }
+            })?;
+            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+                SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+            let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredComponent {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+
+            {
+                let component_id = reflect_component.register_component(world);
+                // SAFETY: we registered the component above. the info exists
+                #[expect(unsafe_code, reason = "this is faster")]
+                let component_info = unsafe { world.components().get_info_unchecked(component_id) };
+                if *component_info.clone_behavior() == ComponentCloneBehavior::Ignore {
+                    continue;
+                }
+            }
+
+            SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+                reflect_component.apply_or_insert_mapped(
+                    &mut world.entity_mut(entity),
+                    component.as_partial_reflect(),
+                    type_registry,
+                    mapper,
+                    RelationshipHookMode::Skip,
+                );
+            });
+        }
+    }
+
+    // Insert resources after all entities have been added to the world.
+    // This ensures the entities are available for the resources to reference during mapping.
+    for resource in resources {
+        let type_info = resource.get_represented_type_info().ok_or_else(|| {
+            SceneSpawnError::NoRepresentedType {
+                type_path: resource.reflect_type_path().to_string(),
+            }
+        })?;
+        let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+            SceneSpawnError::UnregisteredButReflectedType {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+        let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+            SceneSpawnError::UnregisteredResource {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+
+        // If this component references entities in the scene, update
+        // them to the entities in the world.
+        let mut cloned_resource;
+        let partial_reflect_resource =
+            if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                cloned_resource = clone_reflect_value(resource.as_partial_reflect(), registration);
+                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                    map_entities.map_entities(cloned_resource.as_partial_reflect_mut(), mapper);
+                });
+                cloned_resource.as_partial_reflect()
+            } else {
+                resource.as_partial_reflect()
+            };
+
+        // If the world already contains an instance of the given resource
+        // just apply the (possibly) new value, otherwise insert the resource
+        reflect_resource.apply_or_insert(world, partial_reflect_resource, type_registry);
+    }
+
+    Ok(())
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
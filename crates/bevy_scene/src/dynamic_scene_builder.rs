@@ -1,7 +1,7 @@
 use core::any::TypeId;
 
 use crate::reflect_utils::clone_reflect_value;
-use crate::{DynamicEntity, DynamicScene, SceneFilter};
+use crate::{DynamicEntity, DynamicScene, DynamicScenePatch, SceneFilter};
 use alloc::collections::BTreeMap;
 use bevy_ecs::{
     component::{Component, ComponentId},
@@ -216,6 +216,15 @@ impl<'w> DynamicSceneBuilder<'w> {
         }
     }
 
+    /// Consume the builder, producing a [`DynamicScenePatch`] of the changes from `baseline`
+    /// to the built scene.
+    ///
+    /// See [`DynamicScene::diff`].
+    #[must_use]
+    pub fn build_patch(self, baseline: &DynamicScene) -> DynamicScenePatch {
+        self.build().diff(baseline)
+    }
+
     /// Extract one entity from the builder's [`World`].
     ///
     /// Re-extracting an entity that was already extracted will have no effect.
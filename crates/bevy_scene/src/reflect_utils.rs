@@ -1,4 +1,4 @@
-use bevy_reflect::{PartialReflect, ReflectFromReflect, TypeRegistration};
+use bevy_reflect::{PartialReflect, ReflectFromReflect, TypeInfo, TypeRegistration};
 
 /// Attempts to clone a [`PartialReflect`] value using various methods.
 ///
@@ -23,3 +23,11 @@ pub(super) fn clone_reflect_value(
                 .unwrap_or_else(|| value.to_dynamic())
         })
 }
+
+/// The type path of the type represented by a value, which may be a dynamic type.
+pub(super) fn represented_type_path(value: &dyn PartialReflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| value.reflect_type_path())
+}
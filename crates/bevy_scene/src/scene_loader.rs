@@ -8,14 +8,26 @@ use thiserror::Error;
 
 #[cfg(feature = "serialize")]
 use {
-    crate::{serde::SceneDeserializer, DynamicScene},
-    bevy_asset::{io::Reader, AssetLoader, LoadContext},
+    crate::{
+        serde::{
+            SceneDeserializer, SceneOrVariant, SceneOrVariantDeserializer, SceneVariantDeserializer,
+        },
+        DynamicScene,
+    },
+    bevy_asset::{io::Reader, AssetLoader, LoadContext, LoadDirectError, ParseAssetPathError},
     serde::de::DeserializeSeed,
 };
 
 /// Asset loader for a Bevy dynamic scene (`.scn` / `.scn.ron`).
 ///
 /// The loader handles assets serialized with [`DynamicScene::serialize`].
+///
+/// It also handles scene variants, which reference a `parent` scene and override some of its
+/// fields, as described in [`SceneVariantDeserializer`]. The parent path is relative to the
+/// variant, and the parent is loaded as a dependency of the variant: when hot-reloading is
+/// enabled, editing the parent scene reloads all of its variants.
+///
+/// [`SceneVariantDeserializer`]: crate::serde::SceneVariantDeserializer
 #[derive(Debug)]
 pub struct SceneLoader {
     #[cfg_attr(
@@ -44,6 +56,18 @@ pub enum SceneLoaderError {
     /// A [RON Error](ron::error::SpannedError)
     #[error("Could not parse RON: {0}")]
     RonSpannedError(#[from] ron::error::SpannedError),
+    /// A [postcard Error](postcard::Error)
+    #[cfg(feature = "serialize")]
+    #[error("Could not parse binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+    /// The path to the parent of a scene variant is invalid.
+    #[cfg(feature = "serialize")]
+    #[error("Invalid parent scene path: {0}")]
+    ParentPath(#[from] ParseAssetPathError),
+    /// The parent of a scene variant could not be loaded.
+    #[cfg(feature = "serialize")]
+    #[error("Could not load the parent scene: {0}")]
+    Parent(#[from] LoadDirectError),
 }
 
 #[cfg(feature = "serialize")]
@@ -56,12 +80,35 @@ impl AssetLoader for SceneLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
+
+        let scene = {
+            let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+            let scene_deserializer = SceneOrVariantDeserializer {
+                type_registry: &self.type_registry.read(),
+            };
+            scene_deserializer
+                .deserialize(&mut deserializer)
+                .map_err(|e| deserializer.span_error(e))?
+        };
+        let parent = match scene {
+            SceneOrVariant::Scene(scene) => return Ok(scene),
+            SceneOrVariant::Variant { parent } => parent,
+        };
+
+        let parent_path = load_context.asset_path().resolve_embed(&parent)?;
+        let parent = load_context
+            .loader()
+            .immediate()
+            .load::<DynamicScene>(parent_path)
+            .await?;
+
         let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
-        let scene_deserializer = SceneDeserializer {
+        let scene_deserializer = SceneVariantDeserializer {
+            parent: parent.get(),
             type_registry: &self.type_registry.read(),
         };
         Ok(scene_deserializer
@@ -73,3 +120,145 @@ impl AssetLoader for SceneLoader {
         &["scn", "scn.ron"]
     }
 }
+
+/// Asset loader for a Bevy dynamic scene in the binary format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`DynamicScene::serialize_binary`], such as the
+/// ones written by the [`BinarySceneSaver`](crate::BinarySceneSaver).
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    #[cfg_attr(
+        not(feature = "serialize"),
+        expect(dead_code, reason = "only used with `serialize` feature")
+    )]
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = SceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &self.type_registry.read(),
+        };
+        Ok(scene_deserializer.deserialize(&mut deserializer)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use crate::{DynamicScene, ScenePlugin};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState,
+    };
+    use bevy_ecs::{component::Component, reflect::ReflectComponent};
+    use bevy_reflect::Reflect;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u32,
+        speed: f32,
+    }
+
+    #[test]
+    fn load_scene_variant() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("prefabs/base.scn.ron"),
+            r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::scene_loader::tests::Stats": (
+          health: 100,
+          speed: 1.0,
+        ),
+      },
+    ),
+  },
+)"#,
+        );
+        dir.insert_asset_text(
+            Path::new("variants/fast.scn.ron"),
+            r#"(
+  parent: "../prefabs/base.scn.ron",
+  overrides: {
+    4294967296: {
+      "bevy_scene::scene_loader::tests::Stats": {
+        "speed": 3.0,
+      },
+    },
+  },
+)"#,
+        );
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Stats>();
+
+        let handle: Handle<DynamicScene> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("variants/fast.scn.ron");
+        for _ in 0..10000 {
+            app.update();
+            let load_state = app.world().resource::<AssetServer>().load_state(&handle);
+            if load_state.is_loaded() {
+                break;
+            }
+            assert!(
+                !matches!(load_state, LoadState::Failed(_)),
+                "{load_state:?}"
+            );
+        }
+
+        let scenes = app.world().resource::<Assets<DynamicScene>>();
+        let scene = scenes.get(&handle).unwrap();
+        let stats = scene.entities[0].components[0]
+            .try_downcast_ref::<Stats>()
+            .unwrap();
+        assert_eq!(100, stats.health);
+        assert_eq!(3.0, stats.speed);
+    }
+}
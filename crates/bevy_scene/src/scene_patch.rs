@@ -0,0 +1,379 @@
+use crate::{
+    dynamic_scene::write_to_world_mapped, reflect_utils::represented_type_path, DynamicScene,
+    SceneSpawnError,
+};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{PartialReflect, TypeRegistration, TypeRegistry};
+
+#[cfg(feature = "serialize")]
+use crate::{dynamic_scene::serialize_ron, ron, serde::ScenePatchSerializer};
+
+/// The changes that turn a baseline [`DynamicScene`] into another one.
+///
+/// A patch is computed with [`DynamicScene::diff`] or [`DynamicSceneBuilder::build_patch`], and
+/// only holds the resources, entities and components that were added, changed or removed.
+/// This makes it much smaller than the full scene when little has changed, e.g. for save games
+/// or network snapshots.
+///
+/// Writing the patch to a world that the baseline was written to, using the same entity map,
+/// makes the world match the newer scene.
+///
+/// [`DynamicSceneBuilder::build_patch`]: crate::DynamicSceneBuilder::build_patch
+#[derive(Default)]
+pub struct DynamicScenePatch {
+    /// Resources that were added or changed.
+    pub resources: Vec<Box<dyn PartialReflect>>,
+    /// The type paths of the resources that were removed.
+    pub removed_resources: Vec<String>,
+    /// Entities that were added, or whose components were added, changed or removed.
+    pub entities: Vec<DynamicEntityPatch>,
+    /// Entities that were removed.
+    pub removed_entities: Vec<Entity>,
+}
+
+/// The changes to a single entity of a [`DynamicScenePatch`].
+pub struct DynamicEntityPatch {
+    /// The identifier of the entity, unique within the scenes the patch was computed from.
+    ///
+    /// This is the same identifier as [`DynamicEntity::entity`](crate::DynamicEntity::entity).
+    pub entity: Entity,
+    /// Components that were added or changed.
+    pub components: Vec<Box<dyn PartialReflect>>,
+    /// The type paths of the components that were removed.
+    pub removed_components: Vec<String>,
+}
+
+impl DynamicScenePatch {
+    /// Compute the changes that turn `baseline` into `scene`.
+    ///
+    /// See [`DynamicScene::diff`].
+    pub fn from_diff(baseline: &DynamicScene, scene: &DynamicScene) -> Self {
+        let baseline_entities = baseline
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity.components.as_slice()))
+            .collect::<HashMap<_, _>>();
+
+        let mut entities = Vec::new();
+        for entity in &scene.entities {
+            let baseline_components = baseline_entities
+                .get(&entity.entity)
+                .copied()
+                .unwrap_or_default();
+            let (components, removed_components) =
+                diff_values(baseline_components, &entity.components);
+            if !components.is_empty()
+                || !removed_components.is_empty()
+                || !baseline_entities.contains_key(&entity.entity)
+            {
+                entities.push(DynamicEntityPatch {
+                    entity: entity.entity,
+                    components,
+                    removed_components,
+                });
+            }
+        }
+
+        let scene_entities = scene
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .collect::<HashSet<_>>();
+        let removed_entities = baseline
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .filter(|entity| !scene_entities.contains(entity))
+            .collect();
+
+        let (resources, removed_resources) = diff_values(&baseline.resources, &scene.resources);
+
+        DynamicScenePatch {
+            resources,
+            removed_resources,
+            entities,
+            removed_entities,
+        }
+    }
+
+    /// Returns `true` if the patch doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+            && self.removed_resources.is_empty()
+            && self.entities.is_empty()
+            && self.removed_entities.is_empty()
+    }
+
+    /// Write the changes of this patch to the given world.
+    ///
+    /// Entities are mapped through `entity_map` like in [`DynamicScene::write_to_world_with`]:
+    /// added entities are spawned and added to the map, and removed entities are despawned and
+    /// removed from it.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    pub fn write_to_world_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let type_registry = type_registry.read();
+
+        for scene_entity in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(scene_entity) {
+                world.despawn(entity);
+            }
+        }
+
+        for entity_patch in &self.entities {
+            let Some(&entity) = entity_map.get(&entity_patch.entity) else {
+                continue;
+            };
+            for type_path in &entity_patch.removed_components {
+                let registration = get_registration(&type_registry, type_path)?;
+                let reflect_component =
+                    registration.data::<ReflectComponent>().ok_or_else(|| {
+                        SceneSpawnError::UnregisteredComponent {
+                            type_path: type_path.clone(),
+                        }
+                    })?;
+                if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                    reflect_component.remove(&mut entity_mut);
+                }
+            }
+        }
+
+        write_to_world_mapped(
+            world,
+            entity_map,
+            &type_registry,
+            self.entities
+                .iter()
+                .map(|entity_patch| (entity_patch.entity, entity_patch.components.as_slice())),
+            &self.resources,
+        )?;
+
+        for type_path in &self.removed_resources {
+            let registration = get_registration(&type_registry, type_path)?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: type_path.clone(),
+                }
+            })?;
+            reflect_resource.remove(world);
+        }
+
+        Ok(())
+    }
+
+    /// Write the changes of this patch to the given world.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the world's [`AppTypeRegistry`] resource, or doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    pub fn write_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        self.write_to_world_with(world, entity_map, &registry)
+    }
+
+    /// Serialize this patch into the RON format used by Bevy scenes.
+    ///
+    /// To deserialize the patch, use the [`ScenePatchDeserializer`].
+    ///
+    /// [`ScenePatchDeserializer`]: crate::serde::ScenePatchDeserializer
+    #[cfg(feature = "serialize")]
+    pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(ScenePatchSerializer::new(self, registry))
+    }
+
+    /// Serialize this patch into the binary format used by Bevy scenes.
+    ///
+    /// To deserialize the patch, use the [`ScenePatchDeserializer`].
+    ///
+    /// [`ScenePatchDeserializer`]: crate::serde::ScenePatchDeserializer
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, postcard::Error> {
+        postcard::to_allocvec(&ScenePatchSerializer::new(self, registry))
+    }
+}
+
+/// Compare two lists of values with unique types, returning the values of `values` that are
+/// missing from or different in `baseline`, and the type paths of the values of `baseline` that
+/// are missing from `values`.
+fn diff_values(
+    baseline: &[Box<dyn PartialReflect>],
+    values: &[Box<dyn PartialReflect>],
+) -> (Vec<Box<dyn PartialReflect>>, Vec<String>) {
+    let changed = values
+        .iter()
+        .filter(|value| {
+            let type_path = represented_type_path(value.as_ref());
+            !baseline.iter().any(|baseline_value| {
+                represented_type_path(baseline_value.as_ref()) == type_path
+                    && baseline_value
+                        .reflect_partial_eq(value.as_partial_reflect())
+                        .unwrap_or(false)
+            })
+        })
+        .map(|value| {
+            value
+                .reflect_clone()
+                .map(PartialReflect::into_partial_reflect)
+                .unwrap_or_else(|_| value.to_dynamic())
+        })
+        .collect();
+
+    let removed = baseline
+        .iter()
+        .map(|baseline_value| represented_type_path(baseline_value.as_ref()))
+        .filter(|type_path| {
+            !values
+                .iter()
+                .any(|value| represented_type_path(value.as_ref()) == *type_path)
+        })
+        .map(ToString::to_string)
+        .collect();
+
+    (changed, removed)
+}
+
+fn get_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<&'r TypeRegistration, SceneSpawnError> {
+    type_registry.get_with_type_path(type_path).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_path.to_string(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DynamicScene, DynamicSceneBuilder};
+    use bevy_ecs::{
+        component::Component,
+        entity::EntityHashMap,
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        resource::Resource,
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Poisoned;
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Poisoned>();
+            registry.register::<Score>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn diff_and_patch() {
+        let mut world = create_world();
+        let unchanged = world.spawn(Health(10)).id();
+        let changed = world.spawn((Health(10), Poisoned)).id();
+        let removed = world.spawn(Health(1)).id();
+        world.insert_resource(Score(0));
+
+        let baseline = DynamicScene::from_world(&world);
+        let mut dst_world = create_world();
+        let mut entity_map = EntityHashMap::default();
+        baseline
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+
+        world
+            .entity_mut(changed)
+            .insert(Health(5))
+            .remove::<Poisoned>();
+        world.despawn(removed);
+        let added = world.spawn(Health(20)).id();
+        world.remove_resource::<Score>();
+
+        let patch = DynamicSceneBuilder::from_world(&world)
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources()
+            .build_patch(&baseline);
+
+        assert!(patch.resources.is_empty());
+        assert_eq!(
+            vec!["bevy_scene::scene_patch::tests::Score"],
+            patch.removed_resources
+        );
+        assert_eq!(vec![removed], patch.removed_entities);
+        let patched_entities = patch
+            .entities
+            .iter()
+            .map(|entity_patch| entity_patch.entity)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![changed, added], patched_entities);
+        assert!(!patched_entities.contains(&unchanged));
+        assert_eq!(
+            vec!["bevy_scene::scene_patch::tests::Poisoned"],
+            patch.entities[0].removed_components
+        );
+
+        patch
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+
+        assert!(dst_world.get_resource::<Score>().is_none());
+        assert!(dst_world.get_entity(entity_map[&unchanged]).is_ok());
+        assert!(!entity_map.contains_key(&removed));
+        assert_eq!(
+            Some(&Health(5)),
+            dst_world.get::<Health>(entity_map[&changed])
+        );
+        assert!(dst_world.get::<Poisoned>(entity_map[&changed]).is_none());
+        assert_eq!(
+            Some(&Health(20)),
+            dst_world.get::<Health>(entity_map[&added])
+        );
+        assert_eq!(3, dst_world.query::<&Health>().iter(&dst_world).count());
+    }
+
+    #[test]
+    fn diff_without_changes_is_empty() {
+        let mut world = create_world();
+        world.spawn((Health(10), Poisoned));
+        world.insert_resource(Score(3));
+
+        let baseline = DynamicScene::from_world(&world);
+        let patch = DynamicScene::from_world(&world).diff(&baseline);
+        assert!(patch.is_empty());
+
+        world.spawn_empty();
+        let patch = DynamicScene::from_world(&world).diff(&baseline);
+        assert_eq!(1, patch.entities.len());
+        assert!(patch.entities[0].components.is_empty());
+    }
+}
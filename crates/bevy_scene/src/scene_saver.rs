@@ -0,0 +1,71 @@
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use thiserror::Error;
+
+#[cfg(feature = "serialize")]
+use {
+    crate::{BinarySceneLoader, DynamicScene},
+    bevy_asset::{
+        io::Writer,
+        saver::{AssetSaver, SavedAsset},
+        AsyncWriteExt,
+    },
+};
+
+/// Asset saver for a Bevy dynamic scene in the binary format (`.scn.bin`).
+///
+/// The saver writes assets with [`DynamicScene::serialize_binary`], to be loaded by the
+/// [`BinarySceneLoader`]. It can be used in an asset processor to convert RON scenes into
+/// the binary format.
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    #[cfg_attr(
+        not(feature = "serialize"),
+        expect(dead_code, reason = "only used with `serialize` feature")
+    )]
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [postcard Error](postcard::Error)
+    #[cfg(feature = "serialize")]
+    #[error("Could not serialize binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+#[cfg(feature = "serialize")]
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &(),
+    ) -> Result<(), Self::Error> {
+        let bytes = asset.serialize_binary(&self.type_registry.read())?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
@@ -1,18 +1,22 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{
+    reflect_utils::{clone_reflect_value, represented_type_path},
+    DynamicEntity, DynamicEntityPatch, DynamicScene, DynamicScenePatch,
+};
+use alloc::collections::BTreeMap;
 use bevy_ecs::entity::Entity;
-use bevy_platform::collections::HashSet;
+use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        MigrationProcessor, ReflectDeserializer, ReflectMigrate, TypeRegistrationDeserializer,
+        TypedReflectDeserializer, TypedReflectSerializer,
     },
-    PartialReflect, ReflectFromReflect, TypeRegistry,
+    PartialReflect, ReflectFromReflect, ReflectPath, ReflectRef, TypeRegistration, TypeRegistry,
 };
 use core::fmt::Formatter;
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    de::{DeserializeSeed, Error, IgnoredAny, IntoDeserializer, MapAccess, SeqAccess, Visitor},
     ser::{SerializeMap, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
@@ -23,12 +27,34 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized type versions field in a scene struct.
+///
+/// Formats that aren't self-describing store the versions as the first entry of the resources,
+/// under the same name, so that scenes without versioned types keep their original layout.
+pub const SCENE_VERSIONS: &str = "versions";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized parent scene field in a scene variant struct.
+pub const SCENE_PARENT: &str = "parent";
+/// Name of the serialized overrides field in a scene variant struct.
+pub const SCENE_OVERRIDES: &str = "overrides";
+
+/// Name of the serialized scene patch struct type.
+pub const SCENE_PATCH_STRUCT: &str = "ScenePatch";
+/// Name of the serialized removed resources field in a scene patch struct.
+pub const SCENE_PATCH_REMOVED_RESOURCES: &str = "removed_resources";
+/// Name of the serialized removed entities field in a scene patch struct.
+pub const SCENE_PATCH_REMOVED_ENTITIES: &str = "removed_entities";
+
+/// Name of the serialized entity patch struct type.
+pub const ENTITY_PATCH_STRUCT: &str = "EntityPatch";
+/// Name of the serialized removed components field in an entity patch struct.
+pub const ENTITY_PATCH_FIELD_REMOVED_COMPONENTS: &str = "removed_components";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -78,11 +104,30 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        let versions = collect_versions(
+            self.scene.resources.iter().chain(
+                self.scene
+                    .entities
+                    .iter()
+                    .flat_map(|entity| entity.components.iter()),
+            ),
+            self.registry,
+        );
+        // Formats that aren't self-describing have a fixed layout, which must stay readable when
+        // there are no versions, so they store the versions as the first entry of the resources.
+        let human_readable = serializer.is_human_readable();
+        let serialize_versions = human_readable && !versions.is_empty();
+
+        let mut state =
+            serializer.serialize_struct(SCENE_STRUCT, 2 + usize::from(serialize_versions))?;
+        if serialize_versions {
+            state.serialize_field(SCENE_VERSIONS, &versions)?;
+        }
         state.serialize_field(
             SCENE_RESOURCES,
-            &SceneMapSerializer {
+            &SceneResourcesSerializer {
                 entries: &self.scene.resources,
+                versions: (!human_readable && !versions.is_empty()).then_some(&versions),
                 registry: self.registry,
             },
         )?;
@@ -97,6 +142,66 @@ impl<'a> Serialize for SceneSerializer<'a> {
     }
 }
 
+/// Collects the current versions of the types registering [`ReflectMigrate`] used by the given
+/// values, including nested ones, keyed by type path.
+fn collect_versions<'a>(
+    values: impl IntoIterator<Item = &'a Box<dyn PartialReflect>>,
+    registry: &TypeRegistry,
+) -> BTreeMap<&'static str, u32> {
+    fn visit(
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        versions: &mut BTreeMap<&'static str, u32>,
+    ) {
+        if let Some(registration) = value
+            .get_represented_type_info()
+            .and_then(|info| registry.get(info.type_id()))
+        {
+            if let Some(reflect_migrate) = registration.data::<ReflectMigrate>() {
+                versions.insert(
+                    registration.type_info().type_path(),
+                    reflect_migrate.version(),
+                );
+            }
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => value
+                .iter_fields()
+                .for_each(|field| visit(field, registry, versions)),
+            ReflectRef::TupleStruct(value) => value
+                .iter_fields()
+                .for_each(|field| visit(field, registry, versions)),
+            ReflectRef::Tuple(value) => value
+                .iter_fields()
+                .for_each(|field| visit(field, registry, versions)),
+            ReflectRef::List(value) => value
+                .iter()
+                .for_each(|item| visit(item, registry, versions)),
+            ReflectRef::Array(value) => value
+                .iter()
+                .for_each(|item| visit(item, registry, versions)),
+            ReflectRef::Map(value) => value.iter().for_each(|(key, value)| {
+                visit(key, registry, versions);
+                visit(value, registry, versions);
+            }),
+            ReflectRef::Set(value) => value
+                .iter()
+                .for_each(|item| visit(item, registry, versions)),
+            ReflectRef::Enum(value) => value
+                .iter_fields()
+                .for_each(|field| visit(field.value(), registry, versions)),
+            _ => {}
+        }
+    }
+
+    let mut versions = BTreeMap::new();
+    for value in values {
+        visit(value.as_ref(), registry, &mut versions);
+    }
+    versions
+}
+
 /// Handles serialization of multiple entities as a map of entity id to serialized entity.
 pub struct EntitiesSerializer<'a> {
     /// The entities to serialize.
@@ -168,35 +273,188 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
-        let sorted_entries = {
-            let mut entries = self
-                .entries
-                .iter()
-                .map(|entry| {
-                    (
-                        entry.get_represented_type_info().unwrap().type_path(),
-                        entry.as_partial_reflect(),
-                    )
-                })
-                .collect::<Vec<_>>();
-            entries.sort_by_key(|(type_path, _)| *type_path);
-            entries
-        };
+        serialize_scene_map(self.entries, None, self.registry, serializer)
+    }
+}
+
+/// Serializes the resources of a scene, preceded by the type versions of the scene when they can't
+/// be stored in their own field.
+struct SceneResourcesSerializer<'a> {
+    entries: &'a [Box<dyn PartialReflect>],
+    versions: Option<&'a BTreeMap<&'static str, u32>>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SceneResourcesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_scene_map(self.entries, self.versions, self.registry, serializer)
+    }
+}
+
+fn serialize_scene_map<S: Serializer>(
+    entries: &[Box<dyn PartialReflect>],
+    versions: Option<&BTreeMap<&'static str, u32>>,
+    registry: &TypeRegistry,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state =
+        serializer.serialize_map(Some(entries.len() + usize::from(versions.is_some())))?;
+    if let Some(versions) = versions {
+        state.serialize_entry(SCENE_VERSIONS, versions)?;
+    }
+
+    let mut sorted_entries = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.get_represented_type_info().unwrap().type_path(),
+                entry.as_partial_reflect(),
+            )
+        })
+        .collect::<Vec<_>>();
+    sorted_entries.sort_by_key(|(type_path, _)| *type_path);
+
+    for (type_path, partial_reflect) in sorted_entries {
+        state.serialize_entry(
+            type_path,
+            &TypedReflectSerializer::new(partial_reflect, registry),
+        )?;
+    }
+    state.end()
+}
+
+/// Serializer for a [`DynamicScenePatch`].
+///
+/// Helper object defining Bevy's serialize format for a [`DynamicScenePatch`] and implementing
+/// the [`Serialize`] trait for use with Serde. The format extends the one of [`SceneSerializer`]
+/// with the removed resources, entities and components.
+pub struct ScenePatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a DynamicScenePatch,
+    /// The type registry containing the types present in the patch.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ScenePatchSerializer<'a> {
+    /// Create a new serializer from a [`DynamicScenePatch`] and an associated [`TypeRegistry`].
+    ///
+    /// The type registry must contain all types present in the patch.
+    pub fn new(patch: &'a DynamicScenePatch, registry: &'a TypeRegistry) -> Self {
+        ScenePatchSerializer { patch, registry }
+    }
+}
+
+impl<'a> Serialize for ScenePatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let versions = collect_versions(
+            self.patch.resources.iter().chain(
+                self.patch
+                    .entities
+                    .iter()
+                    .flat_map(|entity| entity.components.iter()),
+            ),
+            self.registry,
+        );
+        // Formats that aren't self-describing have a fixed layout, which must stay readable when
+        // there are no versions, so they store the versions as the first entry of the resources.
+        let human_readable = serializer.is_human_readable();
+        let serialize_versions = human_readable && !versions.is_empty();
+
+        let mut state =
+            serializer.serialize_struct(SCENE_PATCH_STRUCT, 4 + usize::from(serialize_versions))?;
+        if serialize_versions {
+            state.serialize_field(SCENE_VERSIONS, &versions)?;
+        }
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &SceneResourcesSerializer {
+                entries: &self.patch.resources,
+                versions: (!human_readable && !versions.is_empty()).then_some(&versions),
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(SCENE_PATCH_REMOVED_RESOURCES, &self.patch.removed_resources)?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &EntityPatchesSerializer {
+                entities: &self.patch.entities,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(SCENE_PATCH_REMOVED_ENTITIES, &self.patch.removed_entities)?;
+        state.end()
+    }
+}
+
+/// Handles serialization of multiple entity patches as a map of entity id to serialized entity patch.
+pub struct EntityPatchesSerializer<'a> {
+    /// The entity patches to serialize.
+    pub entities: &'a [DynamicEntityPatch],
+    /// Type registry in which the component types used by the entity patches are registered.
+    pub registry: &'a TypeRegistry,
+}
 
-        for (type_path, partial_reflect) in sorted_entries {
+impl<'a> Serialize for EntityPatchesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for entity in self.entities {
             state.serialize_entry(
-                type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
+                &entity.entity,
+                &EntityPatchSerializer {
+                    entity,
+                    registry: self.registry,
+                },
             )?;
         }
         state.end()
     }
 }
 
+/// Handles entity patch serialization as a map of component type to component value, and a
+/// list of removed component types.
+pub struct EntityPatchSerializer<'a> {
+    /// The entity patch to serialize.
+    pub entity: &'a DynamicEntityPatch,
+    /// Type registry in which the component types used by the entity patch are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityPatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(ENTITY_PATCH_STRUCT, 2)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &SceneMapSerializer {
+                entries: &self.entity.components,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            ENTITY_PATCH_FIELD_REMOVED_COMPONENTS,
+            &self.entity.removed_components,
+        )?;
+        state.end()
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
+    Versions,
+    Parent,
+    Overrides,
     Resources,
     Entities,
 }
@@ -207,6 +465,33 @@ enum EntityField {
     Components,
 }
 
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SceneVariantField {
+    Versions,
+    Parent,
+    Overrides,
+    Resources,
+    Entities,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ScenePatchField {
+    Versions,
+    Resources,
+    RemovedResources,
+    Entities,
+    RemovedEntities,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum EntityPatchField {
+    Components,
+    RemovedComponents,
+}
+
 /// Handles scene deserialization.
 pub struct SceneDeserializer<'a> {
     /// Type registry in which the components and resources types used in the scene to deserialize are registered.
@@ -216,15 +501,59 @@ pub struct SceneDeserializer<'a> {
 impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     type Value = DynamicScene;
 
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let scene = deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            &[SCENE_VERSIONS, SCENE_RESOURCES, SCENE_ENTITIES],
+            SceneVisitor {
+                type_registry: self.type_registry,
+                variants: false,
+            },
+        )?;
+        match scene {
+            SceneOrVariant::Scene(scene) => Ok(scene),
+            SceneOrVariant::Variant { .. } => unreachable!("scene variants are rejected"),
+        }
+    }
+}
+
+/// A scene, or the parent of a scene variant, see [`SceneOrVariantDeserializer`].
+pub(crate) enum SceneOrVariant {
+    Scene(DynamicScene),
+    Variant { parent: String },
+}
+
+/// Deserializes a scene, or finds the parent of a [scene variant](SceneVariantDeserializer)
+/// without deserializing the rest of the variant.
+///
+/// This lets the [`SceneLoader`](crate::SceneLoader) parse regular scenes in a single pass. The
+/// variant itself can only be deserialized once its parent is loaded.
+pub(crate) struct SceneOrVariantDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneOrVariantDeserializer<'a> {
+    type Value = SceneOrVariant;
+
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
+            &[
+                SCENE_VERSIONS,
+                SCENE_PARENT,
+                SCENE_OVERRIDES,
+                SCENE_RESOURCES,
+                SCENE_ENTITIES,
+            ],
             SceneVisitor {
                 type_registry: self.type_registry,
+                variants: true,
             },
         )
     }
@@ -232,10 +561,12 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
 
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    /// Whether the scene may be a variant.
+    pub variants: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
-    type Value = DynamicScene;
+    type Value = SceneOrVariant;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("scene struct")
@@ -245,58 +576,101 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
+        let mut processor = MigrationProcessor::default();
         let resources = seq
-            .next_element_seed(SceneMapDeserializer {
+            .next_element_seed(SceneResourcesDeserializer {
                 registry: self.type_registry,
+                processor: &mut processor,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
-            .next_element_seed(SceneEntitiesDeserializer {
+            .next_element_seed(SceneEntitiesVisitor {
                 type_registry: self.type_registry,
+                processor: &mut processor,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene {
+        Ok(SceneOrVariant::Scene(DynamicScene {
             resources,
             entities,
-        })
+        }))
     }
 
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
+        let mut processor = None;
         let mut resources = None;
         let mut entities = None;
+        let mut overrides = false;
         while let Some(key) = map.next_key()? {
             match key {
+                SceneField::Parent | SceneField::Overrides if !self.variants => {
+                    let field = match key {
+                        SceneField::Parent => SCENE_PARENT,
+                        _ => SCENE_OVERRIDES,
+                    };
+                    return Err(Error::unknown_field(
+                        field,
+                        &[SCENE_VERSIONS, SCENE_RESOURCES, SCENE_ENTITIES],
+                    ));
+                }
+                SceneField::Parent => {
+                    let parent = map.next_value::<String>()?;
+                    while map.next_key::<IgnoredAny>()?.is_some() {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                    return Ok(SceneOrVariant::Variant { parent });
+                }
+                SceneField::Overrides => {
+                    // The overrides can only be deserialized once the parent is loaded.
+                    map.next_value::<IgnoredAny>()?;
+                    overrides = true;
+                }
+                SceneField::Versions => {
+                    if resources.is_some() || entities.is_some() {
+                        return Err(misplaced_versions());
+                    }
+                    if processor.is_some() {
+                        return Err(Error::duplicate_field(SCENE_VERSIONS));
+                    }
+                    processor = Some(MigrationProcessor::new(
+                        map.next_value::<HashMap<String, u32>>()?,
+                    ));
+                }
                 SceneField::Resources => {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
                     }
-                    resources = Some(map.next_value_seed(SceneMapDeserializer {
+                    resources = Some(map.next_value_seed(SceneResourcesDeserializer {
                         registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
                     })?);
                 }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
                     }
-                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                    entities = Some(map.next_value_seed(SceneEntitiesVisitor {
                         type_registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
                     })?);
                 }
             }
         }
 
+        if overrides {
+            return Err(Error::missing_field(SCENE_PARENT));
+        }
         let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene {
+        Ok(SceneOrVariant::Scene(DynamicScene {
             resources,
             entities,
-        })
+        }))
     }
 }
 
@@ -313,14 +687,28 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneEntitiesVisitor {
+        SceneEntitiesVisitor {
             type_registry: self.type_registry,
-        })
+            processor: &mut MigrationProcessor::default(),
+        }
+        .deserialize(deserializer)
     }
 }
 
 struct SceneEntitiesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
@@ -336,9 +724,10 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
     {
         let mut entities = Vec::new();
         while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(SceneEntityDeserializer {
+            let entity = map.next_value_seed(SceneEntityVisitor {
                 entity,
-                type_registry: self.type_registry,
+                registry: self.type_registry,
+                processor: &mut *self.processor,
             })?;
             entities.push(entity);
         }
@@ -362,27 +751,906 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_struct(
-            ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
-            SceneEntityVisitor {
-                entity: self.entity,
-                registry: self.type_registry,
-            },
+        SceneEntityVisitor {
+            entity: self.entity,
+            registry: self.type_registry,
+            processor: &mut MigrationProcessor::default(),
+        }
+        .deserialize(deserializer)
+    }
+}
+
+struct SceneEntityVisitor<'a> {
+    pub entity: Entity,
+    pub registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(ENTITY_STRUCT, &[ENTITY_FIELD_COMPONENTS], self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let components = seq
+            .next_element_seed(SceneMapVisitor {
+                registry: self.registry,
+                processor: self.processor,
+                versions: false,
+            })?
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+
+        Ok(DynamicEntity {
+            entity: self.entity,
+            components,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                EntityField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+
+                    components = Some(map.next_value_seed(SceneMapVisitor {
+                        registry: self.registry,
+                        processor: &mut *self.processor,
+                        versions: false,
+                    })?);
+                }
+            }
+        }
+
+        let components = components
+            .take()
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        Ok(DynamicEntity {
+            entity: self.entity,
+            components,
+        })
+    }
+}
+
+/// Handles deserialization of a sequence of values with unique types.
+///
+/// The type versions of a scene are stored at its root, so the values are expected to be
+/// serialized with the current version of their types, and are not migrated.
+pub struct SceneMapDeserializer<'a> {
+    /// Type registry in which the types of the values to deserialize are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        SceneMapVisitor {
+            registry: self.registry,
+            processor: &mut MigrationProcessor::default(),
+            versions: false,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+/// Handles deserialization of the resources of a scene, which may start with the type versions of
+/// the scene, see [`SceneResourcesSerializer`].
+struct SceneResourcesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneResourcesDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneMapVisitor {
+            registry: self.registry,
+            processor: self.processor,
+            versions: true,
+        })
+    }
+}
+
+struct SceneMapVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+    /// Whether the first entry may hold the type versions of the scene.
+    pub versions: bool,
+}
+
+/// A key of a [`SceneMapVisitor`].
+enum SceneMapKey<'a> {
+    Versions,
+    Type(&'a TypeRegistration),
+}
+
+/// Deserializes a [`SceneMapKey`], which is the type path of the value unless versions are expected.
+struct SceneMapKeyDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    versions: bool,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapKeyDeserializer<'a> {
+    type Value = SceneMapKey<'a>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !self.versions {
+            return TypeRegistrationDeserializer::new(self.registry)
+                .deserialize(deserializer)
+                .map(SceneMapKey::Type);
+        }
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SceneMapKeyDeserializer<'a> {
+    type Value = SceneMapKey<'a>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("type path or `versions`")
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if key == SCENE_VERSIONS {
+            return Ok(SceneMapKey::Versions);
+        }
+        TypeRegistrationDeserializer::new(self.registry)
+            .deserialize(key.into_deserializer())
+            .map(SceneMapKey::Type)
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of reflect types")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut dynamic_properties = Vec::new();
+        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::with_processor(
+            self.registry,
+            &mut *self.processor,
+        ))? {
+            dynamic_properties.push(entity);
+        }
+
+        Ok(dynamic_properties)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = <HashSet<_>>::default();
+        let mut entries = Vec::new();
+        let mut versions = self.versions;
+        while let Some(key) = map.next_key_seed(SceneMapKeyDeserializer {
+            registry: self.registry,
+            versions: core::mem::take(&mut versions),
+        })? {
+            let registration = match key {
+                SceneMapKey::Versions => {
+                    *self.processor =
+                        MigrationProcessor::new(map.next_value::<HashMap<String, u32>>()?);
+                    continue;
+                }
+                SceneMapKey::Type(registration) => registration,
+            };
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    registration.type_info().type_path(),
+                )));
+            }
+
+            let value = map.next_value_seed(TypedReflectDeserializer::with_processor(
+                registration,
+                self.registry,
+                &mut *self.processor,
+            ))?;
+
+            // Attempt to convert using FromReflect.
+            let value = self
+                .registry
+                .get(registration.type_id())
+                .and_then(|tr| tr.data::<ReflectFromReflect>())
+                .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
+                .map(PartialReflect::into_partial_reflect)
+                .unwrap_or(value);
+
+            entries.push(value);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Handles deserialization of a scene variant, a scene that derives from a parent scene.
+///
+/// A scene variant references its parent scene in its `parent` field, and only contains
+/// `overrides` of fields of the components of the parent's entities, keyed by entity, component
+/// type path and [reflect path](bevy_reflect::ReflectPath). It may also contain additional
+/// `resources` and `entities`, which replace the resources and components of the same type in
+/// the parent scene.
+///
+/// ```ron
+/// (
+///   parent: "base.scn.ron",
+///   overrides: {
+///     4294967296: {
+///       "bevy_transform::components::transform::Transform": {
+///         "translation.x": 3.0,
+///       },
+///     },
+///   },
+/// )
+/// ```
+///
+/// Deserializing a variant produces the resolved [`DynamicScene`]: a copy of the `parent` scene
+/// with the overrides and additions applied. Loading the parent scene is left to the caller,
+/// which is what the [`SceneLoader`](crate::SceneLoader) does.
+pub struct SceneVariantDeserializer<'a> {
+    /// The already loaded parent scene of the variant.
+    pub parent: &'a DynamicScene,
+    /// Type registry in which the components and resources types used in the scenes are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneVariantDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            &[
+                SCENE_VERSIONS,
+                SCENE_PARENT,
+                SCENE_OVERRIDES,
+                SCENE_RESOURCES,
+                SCENE_ENTITIES,
+            ],
+            SceneVariantVisitor {
+                parent: self.parent,
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneVariantVisitor<'a> {
+    pub parent: &'a DynamicScene,
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneVariantVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene variant struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut scene = clone_scene(self.parent, self.type_registry);
+        let mut processor = None;
+        let mut parent = None;
+        let mut overrides = false;
+        let mut resources = None;
+        let mut entities = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneVariantField::Versions => {
+                    if overrides || resources.is_some() || entities.is_some() {
+                        return Err(misplaced_versions());
+                    }
+                    if processor.is_some() {
+                        return Err(Error::duplicate_field(SCENE_VERSIONS));
+                    }
+                    processor = Some(MigrationProcessor::new(
+                        map.next_value::<HashMap<String, u32>>()?,
+                    ));
+                }
+                SceneVariantField::Parent => {
+                    if parent.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PARENT));
+                    }
+                    parent = Some(map.next_value::<String>()?);
+                }
+                SceneVariantField::Overrides => {
+                    if overrides {
+                        return Err(Error::duplicate_field(SCENE_OVERRIDES));
+                    }
+                    map.next_value_seed(SceneOverridesDeserializer {
+                        scene: &mut scene,
+                        registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
+                    })?;
+                    overrides = true;
+                }
+                SceneVariantField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneMapVisitor {
+                        registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
+                        versions: false,
+                    })?);
+                }
+                SceneVariantField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(SceneEntitiesVisitor {
+                        type_registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
+                    })?);
+                }
+            }
+        }
+
+        if parent.is_none() {
+            return Err(Error::missing_field(SCENE_PARENT));
+        }
+
+        merge_values(&mut scene.resources, resources.unwrap_or_default());
+        for entity in entities.unwrap_or_default() {
+            match scene
+                .entities
+                .iter_mut()
+                .find(|scene_entity| scene_entity.entity == entity.entity)
+            {
+                Some(scene_entity) => merge_values(&mut scene_entity.components, entity.components),
+                None => scene.entities.push(entity),
+            }
+        }
+
+        Ok(scene)
+    }
+}
+
+/// Handles deserialization of the overrides of a scene variant, applying them to the entities
+/// of the given scene.
+struct SceneOverridesDeserializer<'a> {
+    pub scene: &'a mut DynamicScene,
+    pub registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneOverridesDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SceneOverridesDeserializer<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entity overrides")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let scene_entity = self
+                .scene
+                .entities
+                .iter_mut()
+                .find(|scene_entity| scene_entity.entity == entity)
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "entity {entity} is not part of the parent scene"
+                    ))
+                })?;
+            map.next_value_seed(EntityOverridesDeserializer {
+                entity: scene_entity,
+                registry: self.registry,
+                processor: &mut *self.processor,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Handles deserialization of the overrides of a single entity, keyed by component type path.
+struct EntityOverridesDeserializer<'a> {
+    pub entity: &'a mut DynamicEntity,
+    pub registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityOverridesDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for EntityOverridesDeserializer<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of component overrides")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(registration) =
+            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        {
+            let component = self
+                .entity
+                .components
+                .iter_mut()
+                .find(|component| {
+                    component
+                        .get_represented_type_info()
+                        .is_some_and(|info| info.type_id() == registration.type_id())
+                })
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "component `{}` is not part of entity {} in the parent scene",
+                        registration.type_info().type_path(),
+                        self.entity.entity,
+                    ))
+                })?;
+            map.next_value_seed(ComponentOverridesDeserializer {
+                component: component.as_mut(),
+                registry: self.registry,
+                processor: &mut *self.processor,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Handles deserialization of the overrides of a single component, keyed by reflect path.
+///
+/// Each value is deserialized as the type of the field it overrides, and applied to it.
+struct ComponentOverridesDeserializer<'a> {
+    pub component: &'a mut dyn PartialReflect,
+    pub registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ComponentOverridesDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ComponentOverridesDeserializer<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of reflect paths to values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(path) = map.next_key::<String>()? {
+            let field = path
+                .as_str()
+                .reflect_element_mut(&mut *self.component)
+                .map_err(|error| Error::custom(format_args!("invalid override path: {error}")))?;
+            let registration = field
+                .get_represented_type_info()
+                .and_then(|info| self.registry.get(info.type_id()))
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "no registration found for the type of the field at `{path}`"
+                    ))
+                })?;
+            let value = map.next_value_seed(TypedReflectDeserializer::with_processor(
+                registration,
+                self.registry,
+                &mut *self.processor,
+            ))?;
+            field.try_apply(value.as_ref()).map_err(|error| {
+                Error::custom(format_args!("failed to override `{path}`: {error}"))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies a scene, so that the variants of a scene can be resolved without altering it.
+fn clone_scene(scene: &DynamicScene, registry: &TypeRegistry) -> DynamicScene {
+    let clone_values = |values: &[Box<dyn PartialReflect>]| {
+        values
+            .iter()
+            .map(|value| {
+                match value
+                    .get_represented_type_info()
+                    .and_then(|info| registry.get(info.type_id()))
+                {
+                    Some(registration) => clone_reflect_value(value.as_ref(), registration),
+                    None => value.to_dynamic(),
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    DynamicScene {
+        resources: clone_values(&scene.resources),
+        entities: scene
+            .entities
+            .iter()
+            .map(|entity| DynamicEntity {
+                entity: entity.entity,
+                components: clone_values(&entity.components),
+            })
+            .collect(),
+    }
+}
+
+/// Adds `new_values` to `values`, replacing the values of the same type.
+fn merge_values(
+    values: &mut Vec<Box<dyn PartialReflect>>,
+    new_values: Vec<Box<dyn PartialReflect>>,
+) {
+    for new_value in new_values {
+        let type_path = represented_type_path(new_value.as_ref());
+        match values
+            .iter_mut()
+            .find(|value| represented_type_path(value.as_ref()) == type_path)
+        {
+            Some(value) => *value = new_value,
+            None => values.push(new_value),
+        }
+    }
+}
+
+/// The error returned when the type versions of a scene don't come before its values, which
+/// can't be deserialized without them.
+fn misplaced_versions<E: Error>() -> E {
+    Error::custom(format_args!(
+        "`{SCENE_VERSIONS}` must come before the resources and entities of the scene"
+    ))
+}
+
+/// Handles scene patch deserialization.
+pub struct ScenePatchDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePatchDeserializer<'a> {
+    type Value = DynamicScenePatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_PATCH_STRUCT,
+            &[
+                SCENE_VERSIONS,
+                SCENE_RESOURCES,
+                SCENE_PATCH_REMOVED_RESOURCES,
+                SCENE_ENTITIES,
+                SCENE_PATCH_REMOVED_ENTITIES,
+            ],
+            ScenePatchVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct ScenePatchVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePatchVisitor<'a> {
+    type Value = DynamicScenePatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut processor = MigrationProcessor::default();
+        let resources = seq
+            .next_element_seed(SceneResourcesDeserializer {
+                registry: self.type_registry,
+                processor: &mut processor,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+
+        let removed_resources = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_RESOURCES))?;
+
+        let entities = seq
+            .next_element_seed(EntityPatchesVisitor {
+                type_registry: self.type_registry,
+                processor: &mut processor,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+
+        let removed_entities = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_ENTITIES))?;
+
+        Ok(DynamicScenePatch {
+            resources,
+            removed_resources,
+            entities,
+            removed_entities,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut processor = None;
+        let mut resources = None;
+        let mut removed_resources = None;
+        let mut entities = None;
+        let mut removed_entities = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ScenePatchField::Versions => {
+                    if resources.is_some() || entities.is_some() {
+                        return Err(misplaced_versions());
+                    }
+                    if processor.is_some() {
+                        return Err(Error::duplicate_field(SCENE_VERSIONS));
+                    }
+                    processor = Some(MigrationProcessor::new(
+                        map.next_value::<HashMap<String, u32>>()?,
+                    ));
+                }
+                ScenePatchField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneResourcesDeserializer {
+                        registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
+                    })?);
+                }
+                ScenePatchField::RemovedResources => {
+                    if removed_resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_REMOVED_RESOURCES));
+                    }
+                    removed_resources = Some(map.next_value()?);
+                }
+                ScenePatchField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(EntityPatchesVisitor {
+                        type_registry: self.type_registry,
+                        processor: processor.get_or_insert_default(),
+                    })?);
+                }
+                ScenePatchField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value()?);
+                }
+            }
+        }
+
+        let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let removed_resources =
+            removed_resources.ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_RESOURCES))?;
+        let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let removed_entities =
+            removed_entities.ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_ENTITIES))?;
+
+        Ok(DynamicScenePatch {
+            resources,
+            removed_resources,
+            entities,
+            removed_entities,
+        })
+    }
+}
+
+/// Handles deserialization for a collection of entity patches.
+pub struct EntityPatchesDeserializer<'a> {
+    /// Type registry in which the component types used by the entity patches to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchesDeserializer<'a> {
+    type Value = Vec<DynamicEntityPatch>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        EntityPatchesVisitor {
+            type_registry: self.type_registry,
+            processor: &mut MigrationProcessor::default(),
+        }
+        .deserialize(deserializer)
+    }
+}
+
+struct EntityPatchesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchesVisitor<'a> {
+    type Value = Vec<DynamicEntityPatch>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for EntityPatchesVisitor<'a> {
+    type Value = Vec<DynamicEntityPatch>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entity patches")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let entity = map.next_value_seed(EntityPatchVisitor {
+                entity,
+                registry: self.type_registry,
+                processor: &mut *self.processor,
+            })?;
+            entities.push(entity);
+        }
+
+        Ok(entities)
+    }
+}
+
+/// Handle deserialization of an entity patch, with its added or changed components and the
+/// types of its removed components.
+pub struct EntityPatchDeserializer<'a> {
+    /// Id of the deserialized entity.
+    pub entity: Entity,
+    /// Type registry in which the component types used by the entity patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchDeserializer<'a> {
+    type Value = DynamicEntityPatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        EntityPatchVisitor {
+            entity: self.entity,
+            registry: self.type_registry,
+            processor: &mut MigrationProcessor::default(),
+        }
+        .deserialize(deserializer)
+    }
+}
+
+struct EntityPatchVisitor<'a> {
+    pub entity: Entity,
+    pub registry: &'a TypeRegistry,
+    pub processor: &'a mut MigrationProcessor,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchVisitor<'a> {
+    type Value = DynamicEntityPatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_PATCH_STRUCT,
+            &[
+                ENTITY_FIELD_COMPONENTS,
+                ENTITY_PATCH_FIELD_REMOVED_COMPONENTS,
+            ],
+            self,
         )
     }
 }
 
-struct SceneEntityVisitor<'a> {
-    pub entity: Entity,
-    pub registry: &'a TypeRegistry,
-}
-
-impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
-    type Value = DynamicEntity;
+impl<'a, 'de> Visitor<'de> for EntityPatchVisitor<'a> {
+    type Value = DynamicEntityPatch;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("entities")
+        formatter.write_str("entity patch")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -390,14 +1658,21 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let components = seq
-            .next_element_seed(SceneMapDeserializer {
+            .next_element_seed(SceneMapVisitor {
                 registry: self.registry,
+                processor: self.processor,
+                versions: false,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
-        Ok(DynamicEntity {
+        let removed_components = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(ENTITY_PATCH_FIELD_REMOVED_COMPONENTS))?;
+
+        Ok(DynamicEntityPatch {
             entity: self.entity,
             components,
+            removed_components,
         })
     }
 
@@ -406,112 +1681,49 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut components = None;
+        let mut removed_components = None;
         while let Some(key) = map.next_key()? {
             match key {
-                EntityField::Components => {
+                EntityPatchField::Components => {
                     if components.is_some() {
                         return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
                     }
-
-                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                    components = Some(map.next_value_seed(SceneMapVisitor {
                         registry: self.registry,
+                        processor: &mut *self.processor,
+                        versions: false,
                     })?);
                 }
+                EntityPatchField::RemovedComponents => {
+                    if removed_components.is_some() {
+                        return Err(Error::duplicate_field(
+                            ENTITY_PATCH_FIELD_REMOVED_COMPONENTS,
+                        ));
+                    }
+                    removed_components = Some(map.next_value()?);
+                }
             }
         }
 
-        let components = components
-            .take()
-            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
-        Ok(DynamicEntity {
+        let components = components.ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        let removed_components = removed_components
+            .ok_or_else(|| Error::missing_field(ENTITY_PATCH_FIELD_REMOVED_COMPONENTS))?;
+        Ok(DynamicEntityPatch {
             entity: self.entity,
             components,
+            removed_components,
         })
     }
 }
 
-/// Handles deserialization of a sequence of values with unique types.
-pub struct SceneMapDeserializer<'a> {
-    /// Type registry in which the types of the values to deserialize are registered.
-    pub registry: &'a TypeRegistry,
-}
-
-impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
-    type Value = Vec<Box<dyn PartialReflect>>;
-
-    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        deserializer.deserialize_map(SceneMapVisitor {
-            registry: self.registry,
-        })
-    }
-}
-
-struct SceneMapVisitor<'a> {
-    pub registry: &'a TypeRegistry,
-}
-
-impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
-    type Value = Vec<Box<dyn PartialReflect>>;
-
-    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("map of reflect types")
-    }
-
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-    where
-        A: SeqAccess<'de>,
-    {
-        let mut dynamic_properties = Vec::new();
-        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
-            dynamic_properties.push(entity);
-        }
-
-        Ok(dynamic_properties)
-    }
-
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-    where
-        A: MapAccess<'de>,
-    {
-        let mut added = <HashSet<_>>::default();
-        let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
-            if !added.insert(registration.type_id()) {
-                return Err(Error::custom(format_args!(
-                    "duplicate reflect type: `{}`",
-                    registration.type_info().type_path(),
-                )));
-            }
-
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
-
-            // Attempt to convert using FromReflect.
-            let value = self
-                .registry
-                .get(registration.type_id())
-                .and_then(|tr| tr.data::<ReflectFromReflect>())
-                .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
-                .map(PartialReflect::into_partial_reflect)
-                .unwrap_or(value);
-
-            entries.push(value);
-        }
-
-        Ok(entries)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{
         ron,
-        serde::{SceneDeserializer, SceneSerializer},
+        serde::{
+            SceneDeserializer, SceneOrVariant, SceneOrVariantDeserializer, ScenePatchDeserializer,
+            SceneSerializer, SceneVariantDeserializer,
+        },
         DynamicScene, DynamicSceneBuilder,
     };
     use bevy_ecs::{
@@ -521,7 +1733,10 @@ mod tests {
         reflect::AppTypeRegistry,
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{
+        serde::{Migrate, Migration, ReflectMigrate},
+        Reflect, ReflectDeserialize, ReflectSerialize,
+    };
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
 
@@ -921,6 +2136,388 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_binary_like_ron() {
+        let mut world = create_world();
+
+        let a = world.spawn((Foo(123), Qux(42))).id();
+        world.spawn((
+            MyEntityRef(a),
+            MyComponent {
+                foo: [1, 2, 3],
+                bar: (1.3, 3.7),
+                baz: MyEnum::Struct { value: 7 },
+            },
+        ));
+        world.insert_resource(MyResource { foo: 123 });
+
+        let (scene, ron_scene) = roundtrip_ron(&world);
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let serialized_scene = scene.serialize_binary(&registry).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+        };
+        let binary_scene = scene_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+            .unwrap();
+
+        assert_eq!(1, binary_scene.resources.len());
+        assert_scene_eq(&scene, &binary_scene);
+        assert_scene_eq(&ron_scene, &binary_scene);
+        assert!(serialized_scene.len() < scene.serialize(&registry).unwrap().len());
+
+        let mut dst_world = create_world();
+        binary_scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        assert_eq!(123, dst_world.resource::<MyResource>().foo);
+        assert_eq!(
+            &Qux(42),
+            dst_world.query::<&Qux>().single(&dst_world).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_migrate_old_versions() {
+        #[derive(Reflect)]
+        struct PlayerV0 {
+            hp: u32,
+        }
+
+        #[derive(Component, Reflect, Default, Debug, PartialEq)]
+        #[reflect(Component, Migrate)]
+        struct Player {
+            health: u32,
+        }
+
+        impl Migrate for Player {
+            const VERSION: u32 = 1;
+
+            fn migrations() -> Vec<Migration> {
+                vec![Migration::new(0, |old: PlayerV0| Player { health: old.hp })]
+            }
+        }
+
+        let mut world = create_world();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<PlayerV0>();
+            registry.register::<Player>();
+        }
+        world.spawn(Player { health: 7 });
+        let scene = DynamicScene::from_world(&world);
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let expected = r#"(
+  versions: {
+    "bevy_scene::serde::tests::Player": 1,
+  },
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Player": (
+          health: 7,
+        ),
+      },
+    ),
+  },
+)"#;
+        let output = scene.serialize(&registry).unwrap();
+        assert_eq!(expected, output);
+
+        let serialized_scene = scene.serialize_binary(&registry).unwrap();
+        let binary_scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+        .unwrap();
+
+        let config = bincode::config::standard().with_fixed_int_encoding();
+        let serialized_scene =
+            bincode::serde::encode_to_vec(SceneSerializer::new(&scene, &registry), config).unwrap();
+        let (bincode_scene, _) = bincode::serde::seed_decode_from_slice(
+            SceneDeserializer {
+                type_registry: &registry,
+            },
+            &serialized_scene,
+            config,
+        )
+        .unwrap();
+
+        // Scenes saved before `Player` was versioned don't record its version.
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Player": (
+          hp: 7,
+        ),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let old_scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        for deserialized_scene in [binary_scene, bincode_scene, old_scene] {
+            let player = deserialized_scene.entities[0].components[0]
+                .try_downcast_ref::<Player>()
+                .unwrap();
+            assert_eq!(&Player { health: 7 }, player);
+        }
+
+        let input = r#"(
+  resources: {},
+  versions: {},
+  entities: {},
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .err()
+        .unwrap();
+        assert!(error.to_string().contains("`versions` must come before"));
+    }
+
+    #[test]
+    fn should_roundtrip_patch() {
+        let mut world = create_world();
+        let a = world.spawn((Foo(123), Bar(345))).id();
+        let b = world.spawn(Baz(789)).id();
+        world.insert_resource(MyResource { foo: 123 });
+        let baseline = DynamicScene::from_world(&world);
+
+        world.entity_mut(a).insert(Foo(321)).remove::<Bar>();
+        world.despawn(b);
+        world.remove_resource::<MyResource>();
+        let patch = DynamicScene::from_world(&world).diff(&baseline);
+
+        let expected = r#"(
+  resources: {},
+  removed_resources: [
+    "bevy_scene::serde::tests::MyResource",
+  ],
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (321),
+      },
+      removed_components: [
+        "bevy_scene::serde::tests::Bar",
+      ],
+    ),
+  },
+  removed_entities: [
+    4294967297,
+  ],
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let output = patch.serialize(&registry).unwrap();
+        assert_eq!(expected, output);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let patch_deserializer = ScenePatchDeserializer {
+            type_registry: &registry,
+        };
+        let ron_patch = patch_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let serialized_patch = patch.serialize_binary(&registry).unwrap();
+        let patch_deserializer = ScenePatchDeserializer {
+            type_registry: &registry,
+        };
+        let binary_patch = patch_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_patch))
+            .unwrap();
+
+        for deserialized_patch in [ron_patch, binary_patch] {
+            assert_eq!(
+                patch.removed_resources,
+                deserialized_patch.removed_resources
+            );
+            assert_eq!(patch.removed_entities, deserialized_patch.removed_entities);
+            assert_eq!(1, deserialized_patch.entities.len());
+            assert_eq!(a, deserialized_patch.entities[0].entity);
+            assert_eq!(
+                patch.entities[0].removed_components,
+                deserialized_patch.entities[0].removed_components
+            );
+            assert!(patch.entities[0].components[0]
+                .reflect_partial_eq(deserialized_patch.entities[0].components[0].as_ref())
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn should_deserialize_variant() {
+        let mut world = create_world();
+        let a = world
+            .spawn((
+                MyComponent {
+                    foo: [1, 2, 3],
+                    bar: (1.0, 2.0),
+                    baz: MyEnum::Unit,
+                },
+                Foo(1),
+            ))
+            .id();
+        let b = world.spawn(Bar(2)).id();
+        world.insert_resource(MyResource { foo: 1 });
+        let parent = DynamicScene::from_world(&world);
+
+        let input = r#"(
+  parent: "base.scn.ron",
+  overrides: {
+    4294967296: {
+      "bevy_scene::serde::tests::MyComponent": {
+        "foo[1]": 20,
+        "bar.1": 4.0,
+        "baz": Struct(value: 7),
+      },
+    },
+  },
+  resources: {
+    "bevy_scene::serde::tests::MyResource": (foo: 5),
+  },
+  entities: {
+    4294967297: (
+      components: {
+        "bevy_scene::serde::tests::Baz": (3),
+      },
+    ),
+    4294967298: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (4),
+      },
+    ),
+  },
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene = SceneVariantDeserializer {
+            parent: &parent,
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        drop(registry);
+
+        // The parent scene is left untouched.
+        assert_eq!(2, parent.entities.len());
+
+        let mut dst_world = create_world();
+        let mut map = EntityHashMap::default();
+        scene.write_to_world(&mut dst_world, &mut map).unwrap();
+
+        assert_eq!(3, map.len());
+        let component = dst_world.get::<MyComponent>(map[&a]).unwrap();
+        assert_eq!([1, 20, 3], component.foo);
+        assert_eq!((1.0, 4.0), component.bar);
+        assert!(matches!(component.baz, MyEnum::Struct { value: 7 }));
+        assert_eq!(1, dst_world.get::<Foo>(map[&a]).unwrap().0);
+        assert_eq!(2, dst_world.get::<Bar>(map[&b]).unwrap().0);
+        assert_eq!(3, dst_world.get::<Baz>(map[&b]).unwrap().0);
+        assert_eq!(5, dst_world.resource::<MyResource>().foo);
+        let c = Entity::from_bits(4294967298);
+        assert_eq!(4, dst_world.get::<Foo>(map[&c]).unwrap().0);
+    }
+
+    #[test]
+    fn should_fail_variant_with_invalid_override() {
+        let mut world = create_world();
+        world.spawn(Foo(1));
+        let parent = DynamicScene::from_world(&world);
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        for (input, expected) in [
+            (
+                r#"(parent: "base.scn.ron", overrides: { 4294967297: {} })"#,
+                "entity 1v1 is not part of the parent scene",
+            ),
+            (
+                r#"(parent: "base.scn.ron", overrides: { 4294967296: { "bevy_scene::serde::tests::Bar": {} } })"#,
+                "component `bevy_scene::serde::tests::Bar` is not part of entity 0v1 in the parent scene",
+            ),
+            (
+                r#"(parent: "base.scn.ron", overrides: { 4294967296: { "bevy_scene::serde::tests::Foo": { ".1": 2 } } })"#,
+                "invalid override path",
+            ),
+            (r#"(overrides: {})"#, "missing field `parent`"),
+        ] {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let error = SceneVariantDeserializer {
+                parent: &parent,
+                type_registry: &registry,
+            }
+            .deserialize(&mut deserializer)
+            .err()
+            .unwrap();
+            assert!(
+                error.to_string().contains(expected),
+                "`{error}` doesn't contain `{expected}`"
+            );
+        }
+    }
+
+    #[test]
+    fn should_find_parent_of_variant() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        for (input, expected) in [
+            (r#"(resources: {}, entities: {})"#, None),
+            (
+                r#"(parent: "base.scn.ron", overrides: { 4294967296: { "bevy_scene::serde::tests::Bar": {} } })"#,
+                Some("base.scn.ron"),
+            ),
+            (
+                r#"(overrides: { 4294967296: {} }, entities: {}, parent: "base.scn.ron")"#,
+                Some("base.scn.ron"),
+            ),
+        ] {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let parent = match (SceneOrVariantDeserializer {
+                type_registry: &registry,
+            })
+            .deserialize(&mut deserializer)
+            .unwrap()
+            {
+                SceneOrVariant::Scene(_) => None,
+                SceneOrVariant::Variant { parent } => Some(parent),
+            };
+            assert_eq!(expected, parent.as_deref());
+        }
+
+        let mut deserializer = ron::de::Deserializer::from_str(r#"(overrides: {})"#).unwrap();
+        let error = SceneOrVariantDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .err()
+        .unwrap();
+        assert!(error.to_string().contains("missing field `parent`"));
+
+        let mut deserializer =
+            ron::de::Deserializer::from_str(r#"(parent: "base.scn.ron")"#).unwrap();
+        let error = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .err()
+        .unwrap();
+        assert!(error
+            .to_string()
+            .contains("Unexpected field named `parent`"));
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
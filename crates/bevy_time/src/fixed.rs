@@ -1,5 +1,8 @@
 use bevy_app::FixedMain;
-use bevy_ecs::world::World;
+use bevy_ecs::{
+    snapshot::{SnapshotError, SnapshotRegistry, Snapshots},
+    world::World,
+};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use core::time::Duration;
@@ -64,11 +67,21 @@ use crate::{time::Time, virt::Virtual};
 /// [`FixedUpdate`](bevy_app::FixedUpdate), even if it is still during the same
 /// frame. Any [`overstep()`](Time::overstep) present in the accumulator will be
 /// processed according to the new [`timestep()`](Time::timestep) value.
+///
+/// When the [`Snapshots`] resource exists, a snapshot of the
+/// [registered](SnapshotRegistry) components and resources is saved after each
+/// run of the [`FixedMain`] schedule, keyed by [`ticks()`](Time::ticks), and
+/// before running it while [`Snapshots`] is empty, so that the history starts
+/// from the current tick. These snapshots include `Time<Fixed>` itself.
+/// [`resimulate_fixed_main`] then rolls the world back to an earlier tick and
+/// runs the schedule again up to the current tick, for example after receiving
+/// late inputs in rollback networking.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Clone))]
 pub struct Fixed {
     timestep: Duration,
     overstep: Duration,
+    ticks: u64,
 }
 
 impl Time<Fixed> {
@@ -182,6 +195,13 @@ impl Time<Fixed> {
         self.context().overstep
     }
 
+    /// Returns the number of timesteps expended, i.e. how many times the
+    /// [`FixedMain`] schedule has run.
+    #[inline]
+    pub fn ticks(&self) -> u64 {
+        self.context().ticks
+    }
+
     /// Discard a part of the overstep amount.
     ///
     /// If `discard` is higher than overstep, the overstep becomes zero.
@@ -214,13 +234,19 @@ impl Time<Fixed> {
         if let Some(new_value) = self.context_mut().overstep.checked_sub(timestep) {
             // reduce accumulated and increase elapsed by period
             self.context_mut().overstep = new_value;
-            self.advance_by(timestep);
+            self.step();
             true
         } else {
             // no more periods left in accumulated
             false
         }
     }
+
+    fn step(&mut self) {
+        let timestep = self.timestep();
+        self.context_mut().ticks += 1;
+        self.advance_by(timestep);
+    }
 }
 
 impl Default for Fixed {
@@ -228,6 +254,7 @@ impl Default for Fixed {
         Self {
             timestep: Time::<Fixed>::DEFAULT_TIMESTEP,
             overstep: Duration::ZERO,
+            ticks: 0,
         }
     }
 }
@@ -242,15 +269,72 @@ pub(super) fn run_fixed_main_schedule(world: &mut World) {
 
     // Run the schedule until we run out of accumulated time
     let _ = world.try_schedule_scope(FixedMain, |world, schedule| {
+        // Start the history from the current tick, so that the next runs can be resimulated.
+        if world
+            .get_resource::<Snapshots>()
+            .is_some_and(Snapshots::is_empty)
+        {
+            save_fixed_snapshot(world);
+        }
         while world.resource_mut::<Time<Fixed>>().expend() {
             *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
             schedule.run(world);
+            save_fixed_snapshot(world);
         }
     });
 
     *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
 }
 
+/// Saves a snapshot of the current tick of [`Time<Fixed>`], if the
+/// [`Snapshots`] resource exists.
+fn save_fixed_snapshot(world: &mut World) {
+    if !world.contains_resource::<Snapshots>() {
+        return;
+    }
+    world
+        .get_resource_or_init::<SnapshotRegistry>()
+        .register_resource::<Time<Fixed>>();
+    let ticks = world.resource::<Time<Fixed>>().ticks();
+    world.save_snapshot(ticks);
+}
+
+/// Rolls the world back by `ticks` runs of the [`FixedMain`] schedule, and runs
+/// them again.
+///
+/// The world is restored from the snapshot saved after the run of
+/// [`Time::<Fixed>::ticks()`](Time::ticks)` - ticks`, which must still be kept
+/// in [`Snapshots`]. `ticks` is clamped to the number of runs so far. The
+/// schedule then runs `ticks` times, saving a snapshot after each run like
+/// [`RunFixedMainLoop`](bevy_app::RunFixedMainLoop) does, so that the
+/// snapshots of the resimulated ticks are replaced.
+///
+/// The [`overstep()`](Time::overstep) of [`Time<Fixed>`] is left untouched.
+///
+/// # Panics
+///
+/// Panics if called while the [`FixedMain`] schedule is running, or if it does
+/// not exist.
+pub fn resimulate_fixed_main(world: &mut World, ticks: u64) -> Result<(), SnapshotError> {
+    let fixed = *world.resource::<Time<Fixed>>();
+    let generic = *world.resource::<Time>();
+    let ticks = ticks.min(fixed.ticks());
+    world.restore_snapshot(fixed.ticks() - ticks)?;
+    world.resource_mut::<Time<Fixed>>().context_mut().overstep = fixed.overstep();
+
+    world.schedule_scope(FixedMain, |world, schedule| {
+        for _ in 0..ticks {
+            world.resource_mut::<Time<Fixed>>().step();
+            *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+            schedule.run(world);
+            save_fixed_snapshot(world);
+        }
+    });
+
+    *world.resource_mut::<Time>() = generic;
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -358,4 +442,63 @@ mod test {
         assert_eq!(time.elapsed(), Duration::from_secs(6));
         assert_eq!(time.overstep(), Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_resimulate() {
+        use bevy_ecs::prelude::*;
+
+        #[derive(Component, Clone)]
+        struct Counter(u32);
+
+        #[derive(Resource)]
+        struct Input(u32);
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Virtual>::default());
+        world.insert_resource(Time::<Fixed>::from_seconds(1.0));
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(Input(1));
+        world.insert_resource(Snapshots::new(8));
+        world
+            .get_resource_or_init::<SnapshotRegistry>()
+            .register_component::<Counter>();
+        let entity = world.spawn(Counter(0)).id();
+
+        let mut schedule = Schedule::new(FixedMain);
+        schedule.add_systems(|input: Res<Input>, mut query: Query<&mut Counter>| {
+            for mut counter in &mut query {
+                counter.0 += input.0;
+            }
+        });
+        world.add_schedule(schedule);
+
+        world
+            .resource_mut::<Time<Virtual>>()
+            .advance_by(Duration::from_millis(3500));
+        run_fixed_main_schedule(&mut world);
+        assert_eq!(world.resource::<Time<Fixed>>().ticks(), 3);
+        assert_eq!(world.get::<Counter>(entity).unwrap().0, 3);
+
+        // The input of the last two ticks turned out to be different.
+        world.resource_mut::<Input>().0 = 10;
+        resimulate_fixed_main(&mut world, 2).unwrap();
+
+        let fixed = world.resource::<Time<Fixed>>();
+        assert_eq!(fixed.ticks(), 3);
+        assert_eq!(fixed.elapsed(), Duration::from_secs(3));
+        assert_eq!(fixed.overstep(), Duration::from_millis(500));
+        assert_eq!(world.get::<Counter>(entity).unwrap().0, 21);
+
+        // The whole history can be resimulated, from the snapshot saved before the first run.
+        world.resource_mut::<Input>().0 = 2;
+        resimulate_fixed_main(&mut world, 3).unwrap();
+        assert_eq!(world.resource::<Time<Fixed>>().ticks(), 3);
+        assert_eq!(world.get::<Counter>(entity).unwrap().0, 6);
+
+        world.resource_mut::<Snapshots>().clear();
+        assert_eq!(
+            resimulate_fixed_main(&mut world, 1),
+            Err(SnapshotError::MissingSnapshot(2))
+        );
+    }
 }
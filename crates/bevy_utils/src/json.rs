@@ -0,0 +1,30 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Appends `value` to `out` as a quoted JSON string, escaping it as needed.
+///
+/// This is meant for crates writing small JSON documents by hand, without depending on a JSON library.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_utils::write_json_string;
+/// let mut json = String::new();
+/// write_json_string(&mut json, "say \"hi\"\n");
+/// assert_eq!(json, r#""say \"hi\"\n""#);
+/// ```
+pub fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
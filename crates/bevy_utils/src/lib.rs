@@ -26,6 +26,8 @@ pub mod synccell;
 pub mod syncunsafecell;
 
 mod default;
+#[cfg(feature = "alloc")]
+mod json;
 mod once;
 #[cfg(feature = "std")]
 mod parallel_queue;
@@ -35,6 +37,9 @@ pub use once::OnceFlag;
 
 pub use default::default;
 
+#[cfg(feature = "alloc")]
+pub use json::write_json_string;
+
 #[cfg(feature = "std")]
 pub use parallel_queue::*;
 